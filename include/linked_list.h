@@ -0,0 +1,33 @@
+/* C interface of the linked-list crate, built with the `ffi` feature. */
+#ifndef LINKED_LIST_H
+#define LINKED_LIST_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct LlList LlList;
+typedef struct LlIter LlIter;
+
+LlList *ll_new(void);
+void ll_free(LlList *list);
+size_t ll_len(const LlList *list);
+void ll_push_front(LlList *list, int64_t value);
+void ll_push_back(LlList *list, int64_t value);
+bool ll_pop_front(LlList *list, int64_t *out);
+bool ll_pop_back(LlList *list, int64_t *out);
+
+/* The list must not be changed or freed while an iterator over it is in use. */
+LlIter *ll_iter_new(const LlList *list);
+bool ll_iter_next(LlIter *iter, int64_t *out);
+void ll_iter_free(LlIter *iter);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
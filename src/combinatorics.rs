@@ -1,9 +1,45 @@
-use crate::to_mut_ptr;
 use crate::Link;
 use crate::LinkedList;
+use crate::ListSlice;
+use crate::Node;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
 use std::iter::FusedIterator;
 use std::marker::PhantomData;
-use std::ptr;
+use std::mem;
+use std::ops::RangeBounds;
+
+// Splits the range of `size` nodes from `head` to `tail` into the first `index` nodes and the rest.
+// Returns the (head, tail) pair of both ranges, an empty range is a pair of null pointers.
+// Walks from whichever end of the range is nearer to the split point.
+#[cfg(feature = "rayon")]
+fn split_range<T>(
+    head: Link<T>,
+    tail: Link<T>,
+    size: usize,
+    index: usize,
+) -> [(Link<T>, Link<T>); 2] {
+    assert!(index <= size, "split index out of range");
+    if index == 0 {
+        return [(Link::null(), Link::null()), (head, tail)];
+    }
+    if index == size {
+        return [(head, tail), (Link::null(), Link::null())];
+    }
+    unsafe {
+        let right_head = if index <= size / 2 {
+            let mut curr = head;
+            (0..index).for_each(|_| curr = curr.next());
+            curr
+        } else {
+            let mut curr = tail;
+            (index..size - 1).for_each(|_| curr = curr.prev());
+            curr
+        };
+        [(head, right_head.prev()), (right_head, tail)]
+    }
+}
 
 pub struct Iter<'a, T> {
     head: Link<T>,
@@ -12,6 +48,50 @@ pub struct Iter<'a, T> {
     _phantom: &'a PhantomData<T>,
 }
 
+// Iter only hands out shared references to the values,
+// so it behaves like `&LinkedList<T>` across threads.
+unsafe impl<'a, T: Sync> Send for Iter<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for Iter<'a, T> {}
+
+// Implement Clone for Iter
+// A copy of the iterator walks over the remaining elements independently,
+// no `T: Clone` is needed since only the pointers are copied.
+impl<'a, T> Clone for Iter<'a, T> {
+    /// Returns a copy of the iterator at its current position.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// let mut iter = list.iter();
+    /// iter.next();
+    /// let copy = iter.clone();
+    /// assert!(iter.eq(copy));
+    /// assert!(list.iter().cycle().take(5).eq([1, 2, 3, 1, 2].iter()));
+    /// ```
+    fn clone(&self) -> Self {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            size: self.size,
+            _phantom: self._phantom,
+        }
+    }
+}
+
+// Implement Debug for Iter
+// Only the number of remaining elements is shown, not the node pointers.
+impl<'a, T> Debug for Iter<'a, T> {
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// let mut iter = list.iter();
+    /// iter.next();
+    /// assert_eq!(format!("{:?}", iter), "Iter { len: 2 }");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Iter").field("len", &self.size).finish()
+    }
+}
+
 impl<'a, T> Iterator for Iter<'a, T> {
     type Item = &'a T;
 
@@ -27,9 +107,9 @@ impl<'a, T> Iterator for Iter<'a, T> {
     /// assert_eq!(iter.next(), None);
     /// ```
     fn next(&mut self) -> Option<Self::Item> {
-        // if head is null then no more items left in the list
+        // if size is zero then no more items left in the range
         // return None
-        if self.head.is_null() {
+        if self.size == 0 {
             return None;
         }
 
@@ -37,22 +117,43 @@ impl<'a, T> Iterator for Iter<'a, T> {
             // copy the current head
             let curr = self.head;
             // set head as the `next` of the current head
-            self.head = (*self.head).next;
-            // if head is becoming null then reset tail as null too
-            if self.head.is_null() {
-                self.tail = ptr::null_mut();
+            self.head = self.head.next();
+            self.size -= 1;
+            // if range is exhausted then reset head and tail as null
+            if self.size == 0 {
+                self.head = Link::null();
+                self.tail = Link::null();
             }
             // return the reference of `val` from the current head
-            Some(&(*curr).val)
+            Some(curr.val())
         }
     }
 
     // Returns a tuple where the first element is the lower bound,
     // and the second element is the upper bound.
-    // It provides an estimate for the length of the iterator
+    // `size` is the exact number of items left in the range
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.size, Some(self.size))
     }
+
+    // Skips `n` nodes by following the links, without touching their values.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.size {
+            // the range is exhausted
+            self.size = 0;
+            self.head = Link::null();
+            self.tail = Link::null();
+            return None;
+        }
+        unsafe { (0..n).for_each(|_| self.head = self.head.next()) };
+        self.size -= n;
+        self.next()
+    }
+
+    // The last item is the one under the tail pointer.
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
 }
 
 impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
@@ -69,9 +170,9 @@ impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
     /// assert_eq!(iter.next(), None);
     /// ```
     fn next_back(&mut self) -> Option<Self::Item> {
-        // if tail is null then no more items left in the list
+        // if size is zero then no more items left in the range
         // return None
-        if self.tail.is_null() {
+        if self.size == 0 {
             return None;
         }
 
@@ -79,15 +180,62 @@ impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
             // copy the current tail
             let curr = self.tail;
             // set tail as the `prev` of the current tail
-            self.tail = (*self.tail).prev;
-            // if tail is becoming null then reset head as null too
-            if self.tail.is_null() {
-                self.head = ptr::null_mut();
+            self.tail = self.tail.prev();
+            self.size -= 1;
+            // if range is exhausted then reset head and tail as null
+            if self.size == 0 {
+                self.head = Link::null();
+                self.tail = Link::null();
             }
             // return the reference of `val` from the current tail
-            Some(&(*curr).val)
+            Some(curr.val())
         }
     }
+
+    // Skips `n` nodes from the back by following the links, without touching their values.
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.size {
+            // the range is exhausted
+            self.size = 0;
+            self.head = Link::null();
+            self.tail = Link::null();
+            return None;
+        }
+        unsafe { (0..n).for_each(|_| self.tail = self.tail.prev()) };
+        self.size -= n;
+        self.next_back()
+    }
+}
+
+impl<'a, T> Iter<'a, T> {
+    // Creates an iterator over the `size` nodes from `head` to `tail`.
+    pub(crate) fn from_range(head: Link<T>, tail: Link<T>, size: usize) -> Self {
+        Iter {
+            head,
+            tail,
+            size,
+            _phantom: &PhantomData,
+        }
+    }
+
+    // Splits the iterator into one over the first `index` items and one over the rest.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn split_at(self, index: usize) -> (Self, Self) {
+        let [(lh, lt), (rh, rt)] = split_range(self.head, self.tail, self.size, index);
+        let left = Iter {
+            head: lh,
+            tail: lt,
+            size: index,
+            _phantom: &PhantomData,
+        };
+        let right = Iter {
+            head: rh,
+            tail: rt,
+            size: self.size - index,
+            _phantom: &PhantomData,
+        };
+        (left, right)
+    }
 }
 
 impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
@@ -100,6 +248,23 @@ pub struct IterMut<'a, T> {
     _phantom: &'a PhantomData<T>,
 }
 
+// IterMut hands out mutable references to the values,
+// so it behaves like `&mut LinkedList<T>` across threads.
+unsafe impl<'a, T: Send> Send for IterMut<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for IterMut<'a, T> {}
+
+// Implement Debug for IterMut
+impl<'a, T> Debug for IterMut<'a, T> {
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// assert_eq!(format!("{:?}", list.iter_mut()), "IterMut { len: 3 }");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("IterMut").field("len", &self.size).finish()
+    }
+}
+
 impl<'a, T> Iterator for IterMut<'a, T> {
     type Item = &'a mut T;
 
@@ -115,32 +280,53 @@ impl<'a, T> Iterator for IterMut<'a, T> {
     /// assert_eq!(iter.next(), None);
     /// ```
     fn next(&mut self) -> Option<Self::Item> {
-        // if head is null then no more items left in the list
+        // if size is zero then no more items left in the range
         // return None
-        if self.head.is_null() {
+        if self.size == 0 {
             return None;
         }
 
         unsafe {
             // copy the current head
-            let curr = to_mut_ptr(self.head);
+            let curr = self.head;
             // set head as the `next` of the current head
-            self.head = (*self.head).next;
-            // if head is becoming null then reset tail as null too
-            if self.head.is_null() {
-                self.tail = ptr::null_mut();
+            self.head = self.head.next();
+            self.size -= 1;
+            // if range is exhausted then reset head and tail as null
+            if self.size == 0 {
+                self.head = Link::null();
+                self.tail = Link::null();
             }
             // return the reference of `val` from the current head
-            Some(&mut (*curr).val)
+            Some(curr.val_mut())
         }
     }
 
     // Returns a tuple where the first element is the lower bound,
     // and the second element is the upper bound.
-    // It provides an estimate for the length of the iterator
+    // `size` is the exact number of items left in the range
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.size, Some(self.size))
     }
+
+    // Skips `n` nodes by following the links, without touching their values.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.size {
+            // the range is exhausted
+            self.size = 0;
+            self.head = Link::null();
+            self.tail = Link::null();
+            return None;
+        }
+        unsafe { (0..n).for_each(|_| self.head = self.head.next()) };
+        self.size -= n;
+        self.next()
+    }
+
+    // The last item is the one under the tail pointer.
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
 }
 
 impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
@@ -157,25 +343,73 @@ impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
     /// assert_eq!(iter.next(), None);
     /// ```
     fn next_back(&mut self) -> Option<Self::Item> {
-        // if tail is null then no more items left in the list
+        // if size is zero then no more items left in the range
         // return None
-        if self.tail.is_null() {
+        if self.size == 0 {
             return None;
         }
 
         unsafe {
             // copy the current tail
-            let curr = to_mut_ptr(self.tail);
+            let curr = self.tail;
             // set tail as the `prev` of the current tail
-            self.tail = (*self.tail).prev;
-            // if tail is becoming null then reset head as null too
-            if self.tail.is_null() {
-                self.head = ptr::null_mut();
+            self.tail = self.tail.prev();
+            self.size -= 1;
+            // if range is exhausted then reset head and tail as null
+            if self.size == 0 {
+                self.head = Link::null();
+                self.tail = Link::null();
             }
             // return the reference of `val` from the current tail
-            Some(&mut (*curr).val)
+            Some(curr.val_mut())
         }
     }
+
+    // Skips `n` nodes from the back by following the links, without touching their values.
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.size {
+            // the range is exhausted
+            self.size = 0;
+            self.head = Link::null();
+            self.tail = Link::null();
+            return None;
+        }
+        unsafe { (0..n).for_each(|_| self.tail = self.tail.prev()) };
+        self.size -= n;
+        self.next_back()
+    }
+}
+
+impl<'a, T> IterMut<'a, T> {
+    // Creates an iterator over the `size` nodes from `head` to `tail`.
+    // The caller must make sure no other reference to those nodes is alive for `'a`.
+    pub(crate) fn from_range(head: Link<T>, tail: Link<T>, size: usize) -> Self {
+        IterMut {
+            head,
+            tail,
+            size,
+            _phantom: &PhantomData,
+        }
+    }
+
+    // Splits the iterator into one over the first `index` items and one over the rest.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn split_at(self, index: usize) -> (Self, Self) {
+        let [(lh, lt), (rh, rt)] = split_range(self.head, self.tail, self.size, index);
+        let left = IterMut {
+            head: lh,
+            tail: lt,
+            size: index,
+            _phantom: &PhantomData,
+        };
+        let right = IterMut {
+            head: rh,
+            tail: rt,
+            size: self.size - index,
+            _phantom: &PhantomData,
+        };
+        (left, right)
+    }
 }
 
 impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
@@ -185,6 +419,36 @@ impl<'a, T> FusedIterator for IterMut<'a, T> {}
 /// This struct can be instantiated by calling `into_iter` method in the LinkedList.
 pub struct IntoIter<T>(LinkedList<T>);
 
+// Implement Clone for IntoIter
+impl<T: Clone> Clone for IntoIter<T> {
+    /// Returns a copy of the iterator with the remaining elements cloned.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut iter = LinkedList::from([1, 2, 3]).into_iter();
+    /// iter.next();
+    /// let copy = iter.clone();
+    /// assert!(iter.eq(copy));
+    /// ```
+    fn clone(&self) -> Self {
+        IntoIter(self.0.clone())
+    }
+}
+
+// Implement Debug for IntoIter
+impl<T> Debug for IntoIter<T> {
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut iter = LinkedList::from([1, 2, 3]).into_iter();
+    /// iter.next_back();
+    /// assert_eq!(format!("{:?}", iter), "IntoIter { len: 2 }");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("IntoIter")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
+
 impl<T> Iterator for IntoIter<T> {
     type Item = T;
     /// Implement `Iterator` trait for IntoIter.
@@ -211,6 +475,11 @@ impl<T> Iterator for IntoIter<T> {
         let size = self.0.len();
         (size, Some(size))
     }
+
+    // The last item is the back of the list, the rest is dropped with the iterator.
+    fn last(mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
 }
 
 impl<T> DoubleEndedIterator for IntoIter<T> {
@@ -234,6 +503,517 @@ impl<T> DoubleEndedIterator for IntoIter<T> {
 impl<T> ExactSizeIterator for IntoIter<T> {}
 impl<T> FusedIterator for IntoIter<T> {}
 
+/// An iterator over overlapping windows of `n` consecutive elements.
+/// Every window is yielded as an `Iter` over its `n` elements.
+/// This struct can be instantiated by calling `windows` method in the LinkedList.
+pub struct Windows<'a, T> {
+    // first and last node of the next window
+    head: Link<T>,
+    tail: Link<T>,
+    n: usize,
+    remaining: usize,
+    _phantom: &'a PhantomData<T>,
+}
+
+// Windows only hands out shared references to the values, like Iter.
+unsafe impl<'a, T: Sync> Send for Windows<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for Windows<'a, T> {}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = Iter<'a, T>;
+
+    /// Implement `Iterator` trait for Windows.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// let mut windows = list.windows(2);
+    /// assert!(windows.next().unwrap().eq([1, 2].iter()));
+    /// assert!(windows.next().unwrap().eq([2, 3].iter()));
+    /// assert!(windows.next().is_none());
+    /// ```
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let window = Iter {
+            head: self.head,
+            tail: self.tail,
+            size: self.n,
+            _phantom: &PhantomData,
+        };
+        self.remaining -= 1;
+        // slide both ends of the window one node towards back
+        unsafe {
+            self.head = self.head.next();
+            self.tail = self.tail.next();
+        }
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Windows<'a, T> {}
+impl<'a, T> FusedIterator for Windows<'a, T> {}
+
+/// A lending iterator over mutable references to every pair of adjacent elements.
+/// Consecutive pairs share an element, so a pair borrows the iterator itself and must be
+/// dropped before the next one is requested. That is why it does not implement `Iterator`.
+/// This struct can be instantiated by calling `iter_mut_pairs` method in the LinkedList.
+pub struct IterMutPairs<'a, T> {
+    // first node of the next pair
+    curr: Link<T>,
+    _phantom: PhantomData<&'a mut T>,
+}
+
+unsafe impl<'a, T: Send> Send for IterMutPairs<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for IterMutPairs<'a, T> {}
+
+impl<'a, T> IterMutPairs<'a, T> {
+    /// Returns the next pair of adjacent elements, or None after the last pair.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// let mut pairs = list.iter_mut_pairs();
+    /// while let Some((a, b)) = pairs.next() {
+    ///     *b += *a;
+    /// }
+    /// assert!(list.iter().eq([1, 3, 6].iter()));
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<(&mut T, &mut T)> {
+        if self.curr.is_null() {
+            return None;
+        }
+        unsafe {
+            let next = self.curr.next();
+            if next.is_null() {
+                self.curr = next;
+                return None;
+            }
+            let first = self.curr;
+            self.curr = next;
+            // first and next are distinct nodes, so the two references never alias
+            Some((first.val_mut(), next.val_mut()))
+        }
+    }
+}
+
+/// An iterator over non overlapping chunks of at most `n` elements.
+/// Every chunk is yielded as an `Iter` over its elements.
+/// This struct can be instantiated by calling `chunks` method in the LinkedList.
+pub struct Chunks<'a, T> {
+    // first node of the next chunk
+    head: Link<T>,
+    n: usize,
+    remaining: usize,
+    _phantom: &'a PhantomData<T>,
+}
+
+// Chunks only hands out shared references to the values, like Iter.
+unsafe impl<'a, T: Sync> Send for Chunks<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for Chunks<'a, T> {}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = Iter<'a, T>;
+
+    /// Implement `Iterator` trait for Chunks.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// let mut chunks = list.chunks(2);
+    /// assert!(chunks.next().unwrap().eq([1, 2].iter()));
+    /// assert!(chunks.next().unwrap().eq([3].iter()));
+    /// assert!(chunks.next().is_none());
+    /// ```
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        unsafe {
+            // walk to the last node of the chunk
+            let mut tail = self.head;
+            let mut size = 1;
+            while size < self.n && !tail.next().is_null() {
+                tail = tail.next();
+                size += 1;
+            }
+            let chunk = Iter {
+                head: self.head,
+                tail,
+                size,
+                _phantom: &PhantomData,
+            };
+            self.head = tail.next();
+            self.remaining -= 1;
+            Some(chunk)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Chunks<'a, T> {}
+impl<'a, T> FusedIterator for Chunks<'a, T> {}
+
+/// An iterator that owns the LinkedList and yields owned chunks of at most `n` elements.
+/// This struct can be instantiated by calling `into_chunks` method in the LinkedList.
+pub struct IntoChunks<T> {
+    list: LinkedList<T>,
+    n: usize,
+}
+
+impl<T> Iterator for IntoChunks<T> {
+    type Item = LinkedList<T>;
+
+    /// Implement `Iterator` trait for IntoChunks.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// let mut chunks = list.into_chunks(2);
+    /// assert_eq!(chunks.next(), Some(LinkedList::from([1, 2])));
+    /// assert_eq!(chunks.next(), Some(LinkedList::from([3])));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.list.is_empty() {
+            return None;
+        }
+        unsafe {
+            // walk to the last node of the chunk
+            let head = self.list.head;
+            let mut tail = head;
+            let mut size = 1;
+            while size < self.n && !tail.next().is_null() {
+                tail = tail.next();
+                size += 1;
+            }
+            // detach the chunk from the front of the list
+            self.list.unlink_nodes(head, tail);
+            Some(LinkedList {
+                head,
+                tail,
+                _phantom: PhantomData,
+            })
+        }
+    }
+}
+
+impl<T> FusedIterator for IntoChunks<T> {}
+
+// Returns the last node of the run starting at `head` and the number of nodes in it.
+// A run continues as long as `pred` holds for each pair of neighbouring elements.
+// SAFETY: `head` must point to a live node.
+unsafe fn run_end<T, F>(head: Link<T>, pred: &mut F) -> (Link<T>, usize)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut tail = head;
+    let mut size = 1;
+    while !tail.next().is_null() && pred(tail.val(), tail.next().val()) {
+        tail = tail.next();
+        size += 1;
+    }
+    (tail, size)
+}
+
+/// An iterator over maximal runs of consecutive elements for which a predicate holds.
+/// Every run is yielded as an `Iter` over its elements.
+/// This struct can be instantiated by calling `chunk_by` method in the LinkedList.
+pub struct ChunkBy<'a, T, F> {
+    // first node of the next run
+    head: Link<T>,
+    pred: F,
+    _phantom: &'a PhantomData<T>,
+}
+
+// ChunkBy only hands out shared references to the values, like Iter.
+unsafe impl<'a, T: Sync, F: Send> Send for ChunkBy<'a, T, F> {}
+unsafe impl<'a, T: Sync, F: Sync> Sync for ChunkBy<'a, T, F> {}
+
+impl<'a, T, F> Iterator for ChunkBy<'a, T, F>
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    type Item = Iter<'a, T>;
+
+    /// Implement `Iterator` trait for ChunkBy.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 1, 2]);
+    /// let mut runs = list.chunk_by(|a, b| a == b);
+    /// assert!(runs.next().unwrap().eq([1, 1].iter()));
+    /// assert!(runs.next().unwrap().eq([2].iter()));
+    /// assert!(runs.next().is_none());
+    /// ```
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.head.is_null() {
+            return None;
+        }
+        unsafe {
+            let (tail, size) = run_end(self.head, &mut self.pred);
+            let run = Iter {
+                head: self.head,
+                tail,
+                size,
+                _phantom: &PhantomData,
+            };
+            self.head = tail.next();
+            Some(run)
+        }
+    }
+}
+
+impl<'a, T, F> FusedIterator for ChunkBy<'a, T, F> where F: FnMut(&T, &T) -> bool {}
+
+/// An iterator that owns the LinkedList and yields maximal runs of consecutive elements
+/// for which a predicate holds as owned lists.
+/// This struct can be instantiated by calling `into_chunk_by` method in the LinkedList.
+pub struct IntoChunkBy<T, F> {
+    list: LinkedList<T>,
+    pred: F,
+}
+
+impl<T, F> Iterator for IntoChunkBy<T, F>
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    type Item = LinkedList<T>;
+
+    /// Implement `Iterator` trait for IntoChunkBy.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 1, 2]);
+    /// let mut runs = list.into_chunk_by(|a, b| a == b);
+    /// assert_eq!(runs.next(), Some(LinkedList::from([1, 1])));
+    /// assert_eq!(runs.next(), Some(LinkedList::from([2])));
+    /// assert_eq!(runs.next(), None);
+    /// ```
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.list.is_empty() {
+            return None;
+        }
+        unsafe {
+            let head = self.list.head;
+            let (tail, _) = run_end(head, &mut self.pred);
+            // detach the run from the front of the list
+            self.list.unlink_nodes(head, tail);
+            Some(LinkedList {
+                head,
+                tail,
+                _phantom: PhantomData,
+            })
+        }
+    }
+}
+
+impl<T, F> FusedIterator for IntoChunkBy<T, F> where F: FnMut(&T, &T) -> bool {}
+
+/// An iterator that removes a range of elements from the LinkedList and yields them owned.
+/// This struct can be instantiated by calling `drain` method in the LinkedList.
+pub struct Drain<'a, T> {
+    // drained nodes are unlinked from the list up front,
+    // so the list stays consistent however far the iterator is consumed
+    iter: IntoIter<T>,
+    _list: PhantomData<&'a mut LinkedList<T>>,
+}
+
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+
+    /// Implement `Iterator` trait for Drain.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3, 4]);
+    /// let mut drain = list.drain(1..3);
+    /// assert_eq!(drain.next(), Some(2));
+    /// assert_eq!(drain.next(), Some(3));
+    /// assert_eq!(drain.next(), None);
+    /// ```
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Drain<'a, T> {
+    /// Implement `DoubleEndedIterator` trait for Drain.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3, 4]);
+    /// let mut drain = list.drain(1..3);
+    /// assert_eq!(drain.next_back(), Some(3));
+    /// assert_eq!(drain.next_back(), Some(2));
+    /// assert_eq!(drain.next_back(), None);
+    /// ```
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Drain<'a, T> {}
+impl<'a, T> FusedIterator for Drain<'a, T> {}
+
+/// An iterator that owns the LinkedList and yields owned sub lists
+/// delimited by the elements matching a predicate.
+/// This struct can be instantiated by calling `into_split` method in the LinkedList.
+pub struct IntoSplit<T, F> {
+    list: LinkedList<T>,
+    pred: F,
+    keep_separators: bool,
+    finished: bool,
+}
+
+impl<T, F> Iterator for IntoSplit<T, F>
+where
+    F: FnMut(&T) -> bool,
+{
+    type Item = LinkedList<T>;
+
+    /// Implement `Iterator` trait for IntoSplit.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 0, 2, 3, 0]);
+    /// let mut pieces = list.into_split(|x| *x == 0, false);
+    /// assert_eq!(pieces.next(), Some(LinkedList::from([1])));
+    /// assert_eq!(pieces.next(), Some(LinkedList::from([2, 3])));
+    /// assert_eq!(pieces.next(), Some(LinkedList::new()));
+    /// assert_eq!(pieces.next(), None);
+    /// ```
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        unsafe {
+            // find the next separator node
+            let mut sep = self.list.head;
+            while !sep.is_null() && !(self.pred)(sep.val()) {
+                sep = sep.next();
+            }
+            // no separator left, the rest of the list is the last piece.
+            // when separators are kept an empty rest is not a piece of its own
+            if sep.is_null() {
+                self.finished = true;
+                let rest = std::mem::take(&mut self.list);
+                if self.keep_separators && rest.is_empty() {
+                    return None;
+                }
+                return Some(rest);
+            }
+            // the piece runs from head up to the separator, including it if kept
+            let piece_tail = if self.keep_separators {
+                sep
+            } else {
+                sep.prev()
+            };
+            let piece_head = if piece_tail.is_null() {
+                Link::null()
+            } else {
+                self.list.head
+            };
+            let rest_head = sep.next();
+            // take the separator out while it is still linked,
+            // its value is dropped once the list is consistent again
+            let dropped = (!self.keep_separators).then(|| Node::free(sep));
+            if !piece_tail.is_null() {
+                piece_tail.set_next(Link::null());
+            }
+            // the rest of the list starts after the separator
+            if rest_head.is_null() {
+                self.list.head = Link::null();
+                self.list.tail = Link::null();
+            } else {
+                rest_head.set_prev(Link::null());
+                self.list.head = rest_head;
+            }
+            let piece = LinkedList {
+                head: piece_head,
+                tail: piece_tail,
+                _phantom: PhantomData,
+            };
+            drop(dropped);
+            Some(piece)
+        }
+    }
+}
+
+impl<T, F> FusedIterator for IntoSplit<T, F> where F: FnMut(&T) -> bool {}
+
+/// An iterator over the segments of a list between the elements matching a predicate.
+/// Every segment is yielded as a `ListSlice`, the separators are not part of any segment.
+/// This struct can be instantiated by calling `split` method in the LinkedList.
+pub struct Split<'a, T, F> {
+    // first node of the next segment, null when the next segment is empty and last
+    head: Link<T>,
+    pred: F,
+    finished: bool,
+    _phantom: &'a PhantomData<T>,
+}
+
+// Split only hands out shared references to the values, like Iter.
+unsafe impl<'a, T: Sync, F: Send> Send for Split<'a, T, F> {}
+unsafe impl<'a, T: Sync, F: Sync> Sync for Split<'a, T, F> {}
+
+impl<'a, T, F> Iterator for Split<'a, T, F>
+where
+    F: FnMut(&T) -> bool,
+{
+    type Item = ListSlice<'a, T>;
+
+    /// Implement `Iterator` trait for Split.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 0, 2, 3, 0]);
+    /// let mut segments = list.split(|x| *x == 0);
+    /// assert!(segments.next().unwrap().iter().eq([1].iter()));
+    /// assert!(segments.next().unwrap().iter().eq([2, 3].iter()));
+    /// assert!(segments.next().unwrap().is_empty());
+    /// assert!(segments.next().is_none());
+    /// ```
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        unsafe {
+            // walk up to the next separator, counting the nodes of the segment
+            let mut sep = self.head;
+            let mut len = 0;
+            while !sep.is_null() && !(self.pred)(sep.val()) {
+                sep = sep.next();
+                len += 1;
+            }
+            let tail = if len == 0 {
+                Link::null()
+            } else if sep.is_null() {
+                // no separator left, the segment runs to the end of the list
+                let mut tail = self.head;
+                while !tail.next().is_null() {
+                    tail = tail.next();
+                }
+                tail
+            } else {
+                sep.prev()
+            };
+            let head = if len == 0 { Link::null() } else { self.head };
+            if sep.is_null() {
+                self.finished = true;
+            } else {
+                self.head = sep.next();
+            }
+            Some(ListSlice::from_range(head, tail, len))
+        }
+    }
+}
+
+impl<'a, T, F> FusedIterator for Split<'a, T, F> where F: FnMut(&T) -> bool {}
+
 impl<T> LinkedList<T> {
     /// Returns a new instance of `Iter` struct.
     /// Returns &T when `next` method is called on the iterator.
@@ -268,7 +1048,7 @@ impl<T> LinkedList<T> {
     /// assert_eq!(iter.next(), Some(&mut 1));
     /// assert_eq!(iter.next(), None);
     /// ```
-    pub fn iter_mut(&self) -> IterMut<'_, T> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         IterMut {
             head: self.head,
             tail: self.tail,
@@ -294,4 +1074,268 @@ impl<T> LinkedList<T> {
     pub fn into_iter(self) -> IntoIter<T> {
         IntoIter(self)
     }
+
+    /// Consumes the list and moves its elements into a `Vec`, from front to back.
+    /// The `Vec` is allocated once with the exact length of the list.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// assert_eq!(list.into_vec(), vec![1, 2, 3]);
+    /// ```
+    pub fn into_vec(mut self) -> Vec<T> {
+        let mut vec = Vec::with_capacity(self.len());
+        while let Some(elem) = self.pop_front() {
+            vec.push(elem);
+        }
+        vec
+    }
+
+    /// Consumes the list and moves its elements into a boxed slice, from front to back.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from(['a', 'b']);
+    /// let slice: Box<[char]> = list.into_boxed_slice();
+    /// assert_eq!(&*slice, ['a', 'b']);
+    /// ```
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        // the capacity of the Vec is exact, so no reallocation happens here
+        self.into_vec().into_boxed_slice()
+    }
+
+    /// Returns an iterator over all overlapping windows of `n` consecutive elements.
+    /// Every window is an iterator over its elements. No window is yielded if the list is shorter than `n`.
+    /// Panics if `n` is zero.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 4, 2, 8]);
+    /// let sums = list.windows(3).map(|w| w.sum::<i32>()).collect::<Vec<_>>();
+    /// assert_eq!(sums, [7, 14]);
+    /// assert_eq!(list.windows(5).count(), 0);
+    /// ```
+    pub fn windows(&self, n: usize) -> Windows<'_, T> {
+        assert!(n > 0, "window size must be greater than zero");
+        let len = self.len();
+        let mut tail = self.head;
+        if n <= len {
+            // last node of the first window
+            unsafe { (1..n).for_each(|_| tail = tail.next()) };
+        }
+        Windows {
+            head: self.head,
+            tail,
+            n,
+            remaining: (len + 1).saturating_sub(n),
+            _phantom: &PhantomData,
+        }
+    }
+
+    /// Consumes and leaks the list, returning an iterator over mutable references
+    /// to its elements which live for the rest of the program, like `Vec::leak`.
+    /// The nodes are never freed, dropping the iterator only drops the references.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([String::from("a"), String::from("b")]);
+    /// let mut names: Vec<&'static mut String> = list.leak().collect();
+    /// names[1].push('!');
+    /// assert_eq!(*names[1], "b!");
+    /// ```
+    pub fn leak<'a>(self) -> IterMut<'a, T>
+    where
+        T: 'a,
+    {
+        let iter = IterMut::from_range(self.head, self.tail, self.len());
+        // forgetting the list keeps its nodes allocated forever
+        mem::forget(self);
+        iter
+    }
+
+    /// Returns a lending iterator over mutable references to every pair of adjacent elements.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([5, 1, 4, 2]);
+    /// // propagate the running maximum towards the back
+    /// let mut pairs = list.iter_mut_pairs();
+    /// while let Some((a, b)) = pairs.next() {
+    ///     *b = (*b).max(*a);
+    /// }
+    /// assert!(list.iter().eq([5, 5, 5, 5].iter()));
+    /// ```
+    pub fn iter_mut_pairs(&mut self) -> IterMutPairs<'_, T> {
+        IterMutPairs {
+            curr: self.head,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over non overlapping chunks of at most `n` elements.
+    /// Every chunk is an iterator over its elements, the last chunk may be shorter than `n`.
+    /// Panics if `n` is zero.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// let sums = list.chunks(2).map(|c| c.sum::<i32>()).collect::<Vec<_>>();
+    /// assert_eq!(sums, [3, 7, 5]);
+    /// ```
+    pub fn chunks(&self, n: usize) -> Chunks<'_, T> {
+        assert!(n > 0, "chunk size must be greater than zero");
+        Chunks {
+            head: self.head,
+            n,
+            remaining: self.len().div_ceil(n),
+            _phantom: &PhantomData,
+        }
+    }
+
+    /// Consumes the list and returns an iterator of owned chunks of at most `n` elements.
+    /// The nodes are relinked into the chunks, no element is moved or cloned.
+    /// Panics if `n` is zero.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// let chunks = list.into_chunks(2).collect::<Vec<_>>();
+    /// assert_eq!(chunks, [LinkedList::from([1, 2]), LinkedList::from([3, 4]), LinkedList::from([5])]);
+    /// ```
+    pub fn into_chunks(self, n: usize) -> IntoChunks<T> {
+        assert!(n > 0, "chunk size must be greater than zero");
+        IntoChunks { list: self, n }
+    }
+
+    /// Returns an iterator over maximal runs of consecutive elements
+    /// for which `pred` holds on every pair of neighbours.
+    /// Every run is an iterator over its elements.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3, 2, 3, 1]);
+    /// let runs = list.chunk_by(|a, b| a < b).map(|r| r.count()).collect::<Vec<_>>();
+    /// assert_eq!(runs, [3, 2, 1]);
+    /// ```
+    pub fn chunk_by<F>(&self, pred: F) -> ChunkBy<'_, T, F>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        ChunkBy {
+            head: self.head,
+            pred,
+            _phantom: &PhantomData,
+        }
+    }
+
+    /// Consumes the list and returns an iterator of owned lists, one for every maximal run
+    /// of consecutive elements for which `pred` holds on every pair of neighbours.
+    /// The nodes are relinked into the runs, no element is moved or cloned.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from(['a', 'a', 'b', 'a']);
+    /// let runs = list.into_chunk_by(|a, b| a == b).collect::<Vec<_>>();
+    /// assert_eq!(runs, [LinkedList::from(['a', 'a']), LinkedList::from(['b']), LinkedList::from(['a'])]);
+    /// ```
+    pub fn into_chunk_by<F>(self, pred: F) -> IntoChunkBy<T, F>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        IntoChunkBy { list: self, pred }
+    }
+
+    /// Removes the elements in the given range of indices and returns them as an iterator.
+    /// Elements not consumed by the iterator are dropped together with it,
+    /// the rest of the list is relinked as soon as the iterator is created.
+    /// Panics if the range is out of bounds or its start is greater than its end.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// let drained = list.drain(1..4).collect::<Vec<_>>();
+    /// assert_eq!(drained, [2, 3, 4]);
+    /// assert_eq!(list, LinkedList::from([1, 5]));
+    /// list.drain(..);
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        Drain {
+            iter: IntoIter(self.remove_range(range)),
+            _list: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the segments between the elements matching `pred`, like `slice::split`.
+    /// Every segment is a `ListSlice` borrowing the list, the separators are skipped.
+    /// Every separator ends a segment, so empty segments are yielded as well.
+    /// See `into_split` to get owned lists instead.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from(['a', ',', 'b', 'c', ',', ',', 'd']);
+    /// let lens = list.split(|c| *c == ',').map(|s| s.len()).collect::<Vec<_>>();
+    /// assert_eq!(lens, [1, 2, 0, 1]);
+    /// ```
+    pub fn split<F>(&self, pred: F) -> Split<'_, T, F>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        Split {
+            head: self.head,
+            pred,
+            finished: false,
+            _phantom: &PhantomData,
+        }
+    }
+
+    /// Returns an iterator of owned sub lists delimited by the elements matching `pred`.
+    /// The nodes are relinked into the pieces, no element is moved or cloned.
+    /// When `keep_separators` is false the separators are dropped
+    /// and every separator ends a piece, so empty pieces are yielded as well.
+    /// When it is true every separator is kept as the last element of its piece.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from(['a', ',', 'b', ',', ',', 'c']);
+    /// let pieces = list.clone().into_split(|c| *c == ',', false).collect::<Vec<_>>();
+    /// assert_eq!(pieces, [LinkedList::from(['a']), LinkedList::from(['b']), LinkedList::new(), LinkedList::from(['c'])]);
+    /// let pieces = list.into_split(|c| *c == ',', true).collect::<Vec<_>>();
+    /// assert_eq!(pieces, [LinkedList::from(['a', ',']), LinkedList::from(['b', ',']), LinkedList::from([',']), LinkedList::from(['c'])]);
+    /// ```
+    pub fn into_split<F>(self, pred: F, keep_separators: bool) -> IntoSplit<T, F>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        IntoSplit {
+            list: self,
+            pred,
+            keep_separators,
+            finished: false,
+        }
+    }
+
+    /// Returns an iterator of owned sub lists delimited by the elements matching `pred`.
+    /// Same as `into_split`, named after `slice::split` taking a predicate.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 0, 2, 3, 0, 4]);
+    /// let pieces = list.split_by(|x| *x == 0, false).collect::<Vec<_>>();
+    /// assert_eq!(pieces, [LinkedList::from([1]), LinkedList::from([2, 3]), LinkedList::from([4])]);
+    /// ```
+    pub fn split_by<F>(self, pred: F, keep_separators: bool) -> IntoSplit<T, F>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.into_split(pred, keep_separators)
+    }
+
+    /// Returns the owning iterator as a boxed trait object.
+    /// The iterator can still be consumed from both ends.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// let mut iter: Box<dyn DoubleEndedIterator<Item = i32> + Send> = list.into_dyn_iter();
+    /// assert_eq!(iter.next_back(), Some(3));
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn into_dyn_iter<'a>(self) -> Box<dyn DoubleEndedIterator<Item = T> + Send + 'a>
+    where
+        T: Send + 'a,
+    {
+        Box::new(IntoIter(self))
+    }
 }
@@ -0,0 +1,277 @@
+//! Thread safe linked list.
+//! `ConcurrentLinkedList` can be shared across threads (for example inside an `Arc`)
+//! and mutated through `&self`. Instead of one lock around the whole list,
+//! every node carries its own lock, plus one lock for the `head` pointer and one for the `tail` pointer.
+//! - `head` always points to a dummy node, elements live in the nodes after it.
+//! - `tail` points to the last node, which is the dummy node when the list is empty.
+//! - `pop_front` takes the value out of the first node and turns that node into the new dummy,
+//!   so it never has to touch `tail`. This lets producers at the back
+//!   and consumers at the front run in parallel.
+//!
+//! To stay deadlock free, locks are always acquired in the order
+//! `head` -> `tail` -> nodes, and nodes are always locked from front to back.
+//!
+//! The number of elements is mirrored in an atomic counter, so `approx_len` can be
+//! sampled without taking any lock.
+//!
+//! With `RUSTFLAGS="--cfg loom"` the list is built on loom's primitives so that
+//! `cargo test --test loom --release` can explore the interleavings.
+
+use crate::LinkedList;
+
+#[cfg(loom)]
+use loom::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(loom)]
+use loom::sync::{Arc, Mutex};
+#[cfg(not(loom))]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(not(loom))]
+use std::sync::{Arc, Mutex};
+
+type NodeRef<T> = Arc<Mutex<LockedNode<T>>>;
+
+// Node of the concurrent list, always accessed through its own lock.
+// `val` is None only for the dummy node.
+struct LockedNode<T> {
+    val: Option<T>,
+    next: Option<NodeRef<T>>,
+}
+
+impl<T> LockedNode<T> {
+    // creates a new shared node with no next node
+    fn new_ref(val: Option<T>) -> NodeRef<T> {
+        Arc::new(Mutex::new(Self { val, next: None }))
+    }
+}
+
+/// Linked list that can be shared and mutated across threads.
+///
+/// Example:
+/// ```
+/// use std::sync::Arc;
+/// use std::thread;
+/// use linked_list::ConcurrentLinkedList;
+/// let list = Arc::new(ConcurrentLinkedList::new());
+/// let handles = (0..4)
+///     .map(|n| {
+///         let list = Arc::clone(&list);
+///         thread::spawn(move || list.push_back(n))
+///     })
+///     .collect::<Vec<_>>();
+/// handles.into_iter().for_each(|h| h.join().unwrap());
+/// assert_eq!(list.len(), 4);
+/// ```
+pub struct ConcurrentLinkedList<T> {
+    head: Mutex<NodeRef<T>>,
+    tail: Mutex<NodeRef<T>>,
+    len: AtomicUsize,
+}
+
+impl<T> ConcurrentLinkedList<T> {
+    /// Creates a new empty ConcurrentLinkedList.
+    /// Both `head` and `tail` point to the same dummy node.
+    /// ```
+    /// use linked_list::ConcurrentLinkedList;
+    /// let list: ConcurrentLinkedList<i32> = ConcurrentLinkedList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        let dummy = LockedNode::new_ref(None);
+        Self {
+            head: Mutex::new(Arc::clone(&dummy)),
+            tail: Mutex::new(dummy),
+            len: AtomicUsize::new(0),
+        }
+    }
+
+    /// Adds an element onto the back of the list.
+    /// Only the `tail` lock and the lock of the last node are taken.
+    /// ```
+    /// use linked_list::ConcurrentLinkedList;
+    /// let list = ConcurrentLinkedList::new();
+    /// list.push_back(1); list.push_back(2);
+    /// assert_eq!(list.pop_front(), Some(1));
+    /// ```
+    pub fn push_back(&self, elem: T) {
+        let new_node = LockedNode::new_ref(Some(elem));
+        // count the element before it can be seen, so a concurrent pop never underflows the counter
+        self.len.fetch_add(1, Ordering::Relaxed);
+        let mut tail = self.tail.lock().unwrap();
+        // link the new node after the current last node and move tail to it
+        tail.lock().unwrap().next = Some(Arc::clone(&new_node));
+        *tail = new_node;
+    }
+
+    /// Adds an element onto the front of the list.
+    /// Only when the list is empty the `tail` lock is taken as well.
+    /// ```
+    /// use linked_list::ConcurrentLinkedList;
+    /// let list = ConcurrentLinkedList::new();
+    /// list.push_front(1); list.push_front(2);
+    /// assert_eq!(list.pop_front(), Some(2));
+    /// ```
+    pub fn push_front(&self, elem: T) {
+        let new_node = LockedNode::new_ref(Some(elem));
+        self.len.fetch_add(1, Ordering::Relaxed);
+        let head = self.head.lock().unwrap();
+        {
+            let mut dummy = head.lock().unwrap();
+            // list is not empty, tail is not pointing to the dummy node
+            // and it can not become empty while the head lock is held
+            if dummy.next.is_some() {
+                new_node.lock().unwrap().next = dummy.next.take();
+                dummy.next = Some(new_node);
+                return;
+            }
+        }
+        // list looks empty, tail may have to move to the new node as well.
+        // dummy node lock was released so that tail can be locked before it.
+        let mut tail = self.tail.lock().unwrap();
+        let mut dummy = head.lock().unwrap();
+        // a concurrent push_back might have added a node in between
+        let next = dummy.next.take();
+        if next.is_none() {
+            *tail = Arc::clone(&new_node);
+        }
+        new_node.lock().unwrap().next = next;
+        dummy.next = Some(new_node);
+    }
+
+    /// Removes the first element from the list and returns it.
+    /// Returns `None` if the list is empty.
+    /// ```
+    /// use linked_list::ConcurrentLinkedList;
+    /// let list = ConcurrentLinkedList::new();
+    /// list.push_back(1);
+    /// assert_eq!(list.pop_front(), Some(1));
+    /// assert_eq!(list.pop_front(), None);
+    /// ```
+    pub fn pop_front(&self) -> Option<T> {
+        let mut head = self.head.lock().unwrap();
+        // node after the dummy node holds the first element
+        let first = head.lock().unwrap().next.clone()?;
+        let val = first.lock().unwrap().val.take();
+        self.len.fetch_sub(1, Ordering::Relaxed);
+        // first node becomes the new dummy node,
+        // the old dummy node is dropped once nobody refers to it
+        *head = first;
+        val
+    }
+
+    /// Returns true if the list is empty.
+    /// ```
+    /// use linked_list::ConcurrentLinkedList;
+    /// let list = ConcurrentLinkedList::new();
+    /// assert!(list.is_empty());
+    /// list.push_back('a');
+    /// assert!(!list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        let head = self.head.lock().unwrap();
+        let is_empty = head.lock().unwrap().next.is_none();
+        is_empty
+    }
+
+    /// Returns the number of elements in the list.
+    /// The result may be outdated as soon as it is returned when other threads are mutating the list.
+    /// ```
+    /// use linked_list::ConcurrentLinkedList;
+    /// let list = ConcurrentLinkedList::new();
+    /// list.push_back(1); list.push_front(0);
+    /// assert_eq!(list.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        let mut count = 0;
+        self.for_each(|_| count += 1);
+        count
+    }
+
+    /// Returns the number of elements without taking any lock.
+    /// Elements are counted as soon as a push starts, so while pushes are in progress
+    /// the result may be ahead of what `len` would return.
+    /// ```
+    /// use linked_list::ConcurrentLinkedList;
+    /// let list = ConcurrentLinkedList::new();
+    /// list.push_back(1); list.push_front(0);
+    /// assert_eq!(list.approx_len(), 2);
+    /// list.pop_front();
+    /// assert_eq!(list.approx_len(), 1);
+    /// ```
+    pub fn approx_len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    /// Calls the closure on each element from front to back.
+    /// Only one node is locked at a time, other threads can keep pushing and popping
+    /// while the walk is in progress. Elements popped concurrently are skipped.
+    /// The closure runs while the lock of the node holding the element is held,
+    /// so it must not call back into the same list: a `push_back` on the last element,
+    /// a `pop_front` on the first one or a nested `for_each` would deadlock.
+    /// Only `approx_len` is safe to call from inside the closure.
+    /// ```
+    /// use linked_list::ConcurrentLinkedList;
+    /// let list = ConcurrentLinkedList::new();
+    /// list.push_back(1); list.push_back(2); list.push_back(3);
+    /// let mut sum = 0;
+    /// list.for_each(|x| sum += x);
+    /// assert_eq!(sum, 6);
+    /// ```
+    pub fn for_each<F>(&self, mut f: F)
+    where
+        F: FnMut(&T),
+    {
+        // the Arc keeps the node alive even if it is popped after its lock is released
+        let mut curr = Arc::clone(&self.head.lock().unwrap());
+        loop {
+            let next = {
+                let node = curr.lock().unwrap();
+                if let Some(val) = &node.val {
+                    f(val);
+                }
+                match &node.next {
+                    Some(next) => Arc::clone(next),
+                    None => break,
+                }
+            };
+            curr = next;
+        }
+    }
+
+    /// Returns a `LinkedList` with a copy of every element from front to back.
+    /// ```
+    /// use linked_list::{ConcurrentLinkedList, LinkedList};
+    /// let list = ConcurrentLinkedList::new();
+    /// list.push_back(1); list.push_back(2);
+    /// assert_eq!(list.to_list(), LinkedList::from([1, 2]));
+    /// ```
+    pub fn to_list(&self) -> LinkedList<T>
+    where
+        T: Clone,
+    {
+        let mut list = LinkedList::new();
+        self.for_each(|elem| list.push_back(elem.clone()));
+        list
+    }
+}
+
+impl<T> Default for ConcurrentLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Drop the nodes one by one, otherwise dropping the first Arc
+// would recursively drop the whole chain and could overflow the stack.
+impl<T> Drop for ConcurrentLinkedList<T> {
+    fn drop(&mut self) {
+        let head = self.head.get_mut().unwrap();
+        let mut next = head.lock().unwrap().next.take();
+        while let Some(node) = next {
+            next = match Arc::try_unwrap(node) {
+                Ok(node) => node.into_inner().unwrap().next,
+                // last node is still referred to by `tail`, it has no next node
+                Err(_) => None,
+            };
+        }
+    }
+}
@@ -0,0 +1,416 @@
+//! Copy-on-write list with O(1) snapshots.
+//! Nodes are reference counted and linked in one direction only, so a node can be
+//! shared by any number of lists. `snapshot` copies one pointer, and a change
+//! through any of the lists copies only the shared nodes in front of the changed position.
+//! Nodes behind it keep being shared, and nodes owned by a single list are changed in place.
+
+use crate::IndexOutOfBounds;
+use crate::RemoveUnderCursorError;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::iter::FusedIterator;
+use std::sync::Arc;
+
+type CowLink<T> = Option<Arc<CowNode<T>>>;
+
+// CowNode struct represents each node in the copy-on-write list
+// contains value owned by the node and the pointer to the next node
+// cloning a node copies the value and shares the rest of the list
+#[derive(Clone)]
+struct CowNode<T> {
+    val: T,
+    next: CowLink<T>,
+}
+
+/// Singly linked list whose nodes are shared between snapshots.
+/// Taking a snapshot is O(1), a change copies the shared nodes from the front up to the changed one.
+/// Working at the front is O(1), working at index `i` is O(i).
+///
+/// Example:
+/// ```
+/// use linked_list::CowList;
+/// let mut list: CowList<u32> = (1..=5).collect();
+/// let before = list.snapshot();
+/// list.push_front(0);
+/// *list.get_mut(1).unwrap() = 10;
+/// assert_eq!(before, CowList::from_iter([1, 2, 3, 4, 5]));
+/// assert_eq!(list, CowList::from_iter([0, 10, 2, 3, 4, 5]));
+/// ```
+pub struct CowList<T> {
+    head: CowLink<T>,
+    len: usize,
+}
+
+impl<T> CowList<T> {
+    /// Creates a new empty list.
+    /// ```
+    /// use linked_list::CowList;
+    /// let list: CowList<u32> = CowList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self { head: None, len: 0 }
+    }
+
+    /// Returns the number of elements in the list.
+    /// ```
+    /// use linked_list::CowList;
+    /// let list: CowList<u32> = (0..3).collect();
+    /// assert_eq!(list.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the list is empty.
+    /// ```
+    /// use linked_list::CowList;
+    /// let mut list = CowList::new();
+    /// list.push_front(1);
+    /// assert!(!list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Returns a list sharing all the nodes of this one in O(1).
+    /// Changes to either list are not visible in the other.
+    /// ```
+    /// use linked_list::CowList;
+    /// let mut list: CowList<u32> = (0..3).collect();
+    /// let snapshot = list.snapshot();
+    /// list.push_front(10);
+    /// assert_eq!(snapshot.len(), 3);
+    /// assert_eq!(list.len(), 4);
+    /// ```
+    pub fn snapshot(&self) -> Self {
+        Self {
+            head: self.head.clone(),
+            len: self.len,
+        }
+    }
+
+    /// Returns true if both lists share their first node, as a list and its untouched snapshot do.
+    /// ```
+    /// use linked_list::CowList;
+    /// let mut list: CowList<u32> = (0..3).collect();
+    /// let snapshot = list.snapshot();
+    /// assert!(list.ptr_eq(&snapshot));
+    /// list.push_front(10);
+    /// assert!(!list.ptr_eq(&snapshot));
+    /// ```
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (&self.head, &other.head) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    /// Removes all the elements of the list.
+    /// Nodes still shared with another list are left to it.
+    /// ```
+    /// use linked_list::CowList;
+    /// let mut list: CowList<u32> = (0..3).collect();
+    /// let snapshot = list.snapshot();
+    /// list.clear();
+    /// assert!(list.is_empty());
+    /// assert_eq!(snapshot.len(), 3);
+    /// ```
+    pub fn clear(&mut self) {
+        self.len = 0;
+        // free the nodes one by one, a recursive drop of a long list would overflow the stack
+        let mut next = self.head.take();
+        while let Some(node) = next {
+            next = match Arc::try_unwrap(node) {
+                Ok(mut node) => node.next.take(),
+                // the rest of the list is owned by another list as well
+                Err(_) => None,
+            };
+        }
+    }
+
+    /// Adds an element onto the front of the list in O(1).
+    /// The rest of the list stays shared with its snapshots.
+    /// ```
+    /// use linked_list::CowList;
+    /// let mut list = CowList::new();
+    /// list.push_front(2);
+    /// list.push_front(1);
+    /// assert_eq!(list.peek_front(), Some(&1));
+    /// ```
+    pub fn push_front(&mut self, elem: T) {
+        let next = self.head.take();
+        self.head = Some(Arc::new(CowNode { val: elem, next }));
+        self.len += 1;
+    }
+
+    /// Returns the reference to the first element of the list.
+    /// ```
+    /// use linked_list::CowList;
+    /// let list: CowList<u32> = (1..3).collect();
+    /// assert_eq!(list.peek_front(), Some(&1));
+    /// ```
+    pub fn peek_front(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.val)
+    }
+
+    /// Returns the reference to the element at the index.
+    /// Returns `None` if the index is out of bounds.
+    /// ```
+    /// use linked_list::CowList;
+    /// let list: CowList<u32> = (0..3).collect();
+    /// assert_eq!(list.get(2), Some(&2));
+    /// assert_eq!(list.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+
+    /// Returns an iterator yielding &T from front to back.
+    /// ```
+    /// use linked_list::CowList;
+    /// let list: CowList<u32> = (0..3).collect();
+    /// assert!(list.iter().eq([0, 1, 2].iter()));
+    /// ```
+    pub fn iter(&self) -> CowIter<'_, T> {
+        CowIter {
+            next: self.head.as_deref(),
+            size: self.len,
+        }
+    }
+}
+
+impl<T: Clone> CowList<T> {
+    /// Removes the first element of the list and returns it in O(1).
+    /// The element is cloned if the node is shared with another list.
+    /// ```
+    /// use linked_list::CowList;
+    /// let mut list: CowList<u32> = (1..3).collect();
+    /// assert_eq!(list.pop_front(), Some(1));
+    /// assert_eq!(list.pop_front(), Some(2));
+    /// assert_eq!(list.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.remove_at(0).ok()
+    }
+
+    /// Adds an element onto the back of the list.
+    /// Note: it walks the whole list and copies every node shared with another list.
+    /// ```
+    /// use linked_list::CowList;
+    /// let mut list = CowList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// assert_eq!(list, CowList::from_iter([1, 2]));
+    /// ```
+    pub fn push_back(&mut self, elem: T) {
+        self.link_at(self.len, elem);
+    }
+
+    /// Returns the mutable reference to the first element of the list.
+    /// The node is copied if it is shared with another list.
+    /// ```
+    /// use linked_list::CowList;
+    /// let mut list: CowList<u32> = (1..3).collect();
+    /// *list.peek_front_mut().unwrap() = 10;
+    /// assert_eq!(list, CowList::from_iter([10, 2]));
+    /// ```
+    pub fn peek_front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Returns the mutable reference to the element at the index.
+    /// The shared nodes up to the index are copied, the nodes behind it stay shared.
+    /// Returns `None` if the index is out of bounds.
+    /// ```
+    /// use linked_list::CowList;
+    /// let mut list: CowList<u32> = (0..3).collect();
+    /// let snapshot = list.snapshot();
+    /// *list.get_mut(1).unwrap() = 10;
+    /// assert_eq!(list, CowList::from_iter([0, 10, 2]));
+    /// assert_eq!(snapshot, CowList::from_iter([0, 1, 2]));
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        let link = self.link_mut(index);
+        link.as_mut().map(|node| &mut Arc::make_mut(node).val)
+    }
+
+    /// Insert an element at a given index.
+    /// The shared nodes in front of the index are copied, the nodes behind it stay shared.
+    /// Note: Final index at the list will wrap around when length of the list is lesser.
+    /// ```
+    /// use linked_list::CowList;
+    /// let mut list: CowList<u32> = CowList::from_iter([0, 2]);
+    /// list.insert_at(1, 1);
+    /// list.insert_at(3, 5);
+    /// assert_eq!(list, CowList::from_iter([0, 1, 3, 2]));
+    /// ```
+    pub fn insert_at(&mut self, elem: T, index: usize) {
+        // the element goes after the element at `index - 1`, wrapping around like `LinkedList::insert_at`
+        let index = if self.is_empty() || index == 0 {
+            0
+        } else {
+            (index - 1) % self.len + 1
+        };
+        self.link_at(index, elem);
+    }
+
+    /// Insert an element at a given index.
+    /// Unlike `insert_at` the index does not wrap around,
+    /// an index equal to the length of the list pushes the element to the back.
+    /// Returns error if the index is greater than the length of the list.
+    /// ```
+    /// use linked_list::CowList;
+    /// let mut list: CowList<u32> = CowList::from_iter([1, 2, 4]);
+    /// assert!(list.try_insert_at(3, 2).is_ok());
+    /// assert!(list.try_insert_at(5, 4).is_ok());
+    /// assert!(list.try_insert_at(7, 6).is_err());
+    /// assert_eq!(list, CowList::from_iter([1, 2, 3, 4, 5]));
+    /// ```
+    pub fn try_insert_at(&mut self, elem: T, index: usize) -> Result<(), IndexOutOfBounds> {
+        if index > self.len {
+            return Err(IndexOutOfBounds);
+        }
+        self.link_at(index, elem);
+        Ok(())
+    }
+
+    /// Remove an element at a given index.
+    /// The shared nodes in front of the index are copied, the nodes behind it stay shared.
+    /// The element is cloned if its node is shared with another list.
+    /// Note: Final index at the list will wrap around when length of the list is lesser.
+    /// If the list is empty then it throws error
+    /// ```
+    /// use linked_list::CowList;
+    /// let mut list: CowList<u32> = (0..4).collect();
+    /// let snapshot = list.snapshot();
+    /// assert_eq!(list.remove_at(1), Ok(1));
+    /// assert_eq!(list.remove_at(4), Ok(2));
+    /// assert_eq!(list, CowList::from_iter([0, 3]));
+    /// assert_eq!(snapshot, CowList::from_iter([0, 1, 2, 3]));
+    /// ```
+    pub fn remove_at(&mut self, index: usize) -> Result<T, RemoveUnderCursorError> {
+        if self.is_empty() {
+            return Err(RemoveUnderCursorError);
+        }
+        Ok(self.unlink_at(index % self.len))
+    }
+
+    // Links a new node in at the index, `index` must not be greater than the length.
+    fn link_at(&mut self, index: usize, elem: T) {
+        let link = self.link_mut(index);
+        let next = link.take();
+        *link = Some(Arc::new(CowNode { val: elem, next }));
+        self.len += 1;
+    }
+
+    // Unlinks the node at the index and returns its element, cloned if the node is shared.
+    // `index` must be less than the length.
+    fn unlink_at(&mut self, index: usize) -> T {
+        let link = self.link_mut(index);
+        let node = link.take().expect("index is in bounds");
+        let (val, next) = match Arc::try_unwrap(node) {
+            Ok(node) => (node.val, node.next),
+            Err(shared) => (shared.val.clone(), shared.next.clone()),
+        };
+        *link = next;
+        self.len -= 1;
+        val
+    }
+
+    // Returns the link pointing to the node at the index, or the empty link after the last node.
+    // Every shared node in front of the index is copied, so the link can be changed
+    // without affecting other lists. `index` must not be greater than the length.
+    fn link_mut(&mut self, index: usize) -> &mut CowLink<T> {
+        let mut link = &mut self.head;
+        for _ in 0..index {
+            // the index is in bounds, so every node on the way exists
+            link = &mut Arc::make_mut(link.as_mut().unwrap()).next;
+        }
+        link
+    }
+}
+
+/// Iterator over the elements of a `CowList`.
+/// This struct can be instantiated by calling `iter` method in the CowList.
+pub struct CowIter<'a, T> {
+    next: Option<&'a CowNode<T>>,
+    size: usize,
+}
+
+impl<'a, T> Iterator for CowIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.next?;
+        self.next = node.next.as_deref();
+        self.size -= 1;
+        Some(&node.val)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.size, Some(self.size))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for CowIter<'a, T> {}
+impl<'a, T> FusedIterator for CowIter<'a, T> {}
+
+impl<'a, T> IntoIterator for &'a CowList<T> {
+    type Item = &'a T;
+    type IntoIter = CowIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Default for CowList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for CowList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+// Cloning a CowList is a snapshot, no element is cloned
+impl<T> Clone for CowList<T> {
+    fn clone(&self) -> Self {
+        self.snapshot()
+    }
+}
+
+impl<T: Debug> Debug for CowList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for CowList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for CowList<T> {}
+
+impl<T> FromIterator<T> for CowList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        // the list is built from the back, so collect the elements first
+        let elems: Vec<T> = iter.into_iter().collect();
+        let mut new_list = Self::new();
+        for elem in elems.into_iter().rev() {
+            new_list.push_front(elem);
+        }
+        new_list
+    }
+}
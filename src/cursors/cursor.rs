@@ -1,5 +1,15 @@
+use super::seek_node;
+use crate::combinatorics::Iter;
+use crate::IndexOutOfBounds;
 use crate::Link;
 use crate::LinkedList;
+use crate::ListSlice;
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::iter::Chain;
+use std::ptr;
 
 pub struct Cursor<'a, T> {
     pub(super) curr: Link<T>,
@@ -8,6 +18,29 @@ pub struct Cursor<'a, T> {
     pub(super) length: usize,
 }
 
+// Cursor only hands out shared references to the values,
+// so it behaves like `&LinkedList<T>` across threads.
+unsafe impl<'a, T: Sync> Send for Cursor<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for Cursor<'a, T> {}
+
+// Implement Debug for Cursor
+// Shows the position of the cursor instead of the node pointers.
+impl<'a, T> Debug for Cursor<'a, T> {
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// let mut cursor = list.cursor_front().unwrap();
+    /// cursor.move_next();
+    /// assert_eq!(format!("{:?}", cursor), "Cursor { index: 1, len: 3 }");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Cursor")
+            .field("index", &self.index)
+            .field("len", &self.length)
+            .finish()
+    }
+}
+
 impl<'a, T> Cursor<'a, T> {
     /// Returns the reference to the value under the cursor and its index
     /// ```
@@ -36,7 +69,7 @@ impl<'a, T> Cursor<'a, T> {
         }
 
         // return the reference to the value under curr pointer
-        unsafe { (&(*self.curr).val, self.index) }
+        unsafe { (self.curr.val(), self.index) }
     }
 
     /// Returns the reference to the value previous to the node under the cursor and its index
@@ -66,10 +99,10 @@ impl<'a, T> Cursor<'a, T> {
         unsafe {
             if self.index == 0 {
                 // when on the first element return the value from tail of the list
-                (&(*self.list.tail).val, self.length - 1)
+                (self.list.tail.val(), self.length - 1)
             } else {
                 // otherwise return the value from prev of curr
-                (&(*(*self.curr).prev).val, self.index - 1)
+                (self.curr.prev().val(), self.index - 1)
             }
         }
     }
@@ -100,10 +133,10 @@ impl<'a, T> Cursor<'a, T> {
         unsafe {
             if self.index == self.length - 1 {
                 // when on the last element return the value from head of the list
-                (&(*self.list.head).val, 0)
+                (self.list.head.val(), 0)
             } else {
                 // otherwise return the value from next of curr
-                (&(*(*self.curr).next).val, self.index + 1)
+                (self.curr.next().val(), self.index + 1)
             }
         }
     }
@@ -128,7 +161,7 @@ impl<'a, T> Cursor<'a, T> {
         }
         unsafe {
             // move the cursor to the next node
-            self.curr = (*self.curr).next;
+            self.curr = self.curr.next();
             self.index += 1;
         }
     }
@@ -153,11 +186,113 @@ impl<'a, T> Cursor<'a, T> {
         }
         unsafe {
             // move the cursor to the prev node
-            self.curr = (*self.curr).prev;
+            self.curr = self.curr.prev();
             self.index -= 1;
         }
     }
 
+    /// Moves the cursor one node towards back and returns true.
+    /// Unlike `move_next` it does not wrap around, on the last node it returns false and stays there.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// let mut cursor = list.cursor_front().unwrap();
+    /// let mut seen = vec![*cursor.current().0];
+    /// while cursor.try_move_next() {
+    ///     seen.push(*cursor.current().0);
+    /// }
+    /// assert_eq!(seen, [1, 2, 3]);
+    /// assert_eq!(cursor.current(), (&3, 2));
+    /// ```
+    pub fn try_move_next(&mut self) -> bool {
+        if self.index == self.length - 1 {
+            return false;
+        }
+        unsafe {
+            self.curr = self.curr.next();
+        }
+        self.index += 1;
+        true
+    }
+
+    /// Moves the cursor one node towards front and returns true.
+    /// Unlike `move_prev` it does not wrap around, on the first node it returns false and stays there.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// let mut cursor = list.cursor_front().unwrap();
+    /// assert!(!cursor.try_move_prev());
+    /// assert_eq!(cursor.current(), (&1, 0));
+    /// ```
+    pub fn try_move_prev(&mut self) -> bool {
+        if self.index == 0 {
+            return false;
+        }
+        unsafe {
+            self.curr = self.curr.prev();
+        }
+        self.index -= 1;
+        true
+    }
+
+    /// Returns the number of steps between this cursor and the other one.
+    /// Returns `None` if the cursors are over different lists.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3, 4]);
+    /// let front = list.cursor_front().unwrap();
+    /// let back = list.cursor_back().unwrap();
+    /// assert_eq!(front.distance(&back), Some(3));
+    /// assert_eq!(back.distance(&front), Some(3));
+    /// let other = LinkedList::from([1, 2, 3, 4]);
+    /// assert_eq!(front.distance(&other.cursor_back().unwrap()), None);
+    /// ```
+    pub fn distance(&self, other: &Cursor<'_, T>) -> Option<usize> {
+        self.cmp_position(other)?;
+        Some(self.index.abs_diff(other.index))
+    }
+
+    /// Compares the positions of this cursor and the other one.
+    /// `Less` means this cursor comes before the other one towards the front.
+    /// Returns `None` if the cursors are over different lists.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// use std::cmp::Ordering;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// let mut start = list.cursor_front().unwrap();
+    /// let end = list.cursor_back().unwrap();
+    /// assert_eq!(start.cmp_position(&end), Some(Ordering::Less));
+    /// start.step_by(2);
+    /// assert_eq!(start.cmp_position(&end), Some(Ordering::Equal));
+    /// ```
+    pub fn cmp_position(&self, other: &Cursor<'_, T>) -> Option<Ordering> {
+        if !ptr::eq(self.list, other.list) {
+            return None;
+        }
+        Some(self.index.cmp(&other.index))
+    }
+
+    /// Moves the cursor to the node at the absolute index.
+    /// It walks from the head, the tail or the current node, whichever is the closest.
+    /// Returns an error and stays in place if the index is out of bounds.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// let mut cursor = list.cursor_front().unwrap();
+    /// assert!(cursor.seek_to(3).is_ok());
+    /// assert_eq!(cursor.current(), (&4, 3));
+    /// assert!(cursor.seek_to(5).is_err());
+    /// assert_eq!(cursor.current(), (&4, 3));
+    /// ```
+    pub fn seek_to(&mut self, index: usize) -> Result<(), IndexOutOfBounds> {
+        if index >= self.length {
+            return Err(IndexOutOfBounds);
+        }
+        self.curr = unsafe { seek_node(self.list, self.curr, self.index, self.length, index) };
+        self.index = index;
+        Ok(())
+    }
+
     /// Move the cursor no of steps at once.
     /// index will wrap around according to the no of steps given.
     /// ```
@@ -220,4 +355,55 @@ impl<'a, T> Cursor<'a, T> {
     pub fn step_by_backward(&mut self, steps: usize) {
         self.step_by(self.length - (steps % self.length));
     }
+
+    /// Returns an iterator from the node under the cursor to the back of the list.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3, 4]);
+    /// let mut cursor = list.cursor_front().unwrap();
+    /// // find the first even number, then scan the rest of the list from there
+    /// while cursor.current().0 % 2 != 0 {
+    ///     cursor.move_next();
+    /// }
+    /// assert!(cursor.iter().eq([2, 3, 4].iter()));
+    /// ```
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter::from_range(self.curr, self.list.tail, self.length - self.index)
+    }
+
+    /// Returns an iterator doing one full lap around the list,
+    /// from the node under the cursor to the back and then from the front up to the cursor.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3, 4]);
+    /// let mut cursor = list.cursor_front().unwrap();
+    /// cursor.step_by(2);
+    /// assert!(cursor.iter_wrapping().eq([3, 4, 1, 2].iter()));
+    /// ```
+    pub fn iter_wrapping(&self) -> Chain<Iter<'a, T>, Iter<'a, T>> {
+        // nodes before the cursor, the range is empty when the cursor is at the front
+        let before = unsafe { self.curr.prev() };
+        let front = Iter::from_range(self.list.head, before, self.index);
+        self.iter().chain(front)
+    }
+
+    /// Returns a borrowed view over up to `n` nodes starting from the node under the cursor.
+    /// The slice does not wrap around, it ends at the back of the list at the latest.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3, 4]);
+    /// let mut cursor = list.cursor_front().unwrap();
+    /// cursor.move_next();
+    /// assert!(cursor.slice_next(2).iter().eq([2, 3].iter()));
+    /// assert!(cursor.slice_next(10).iter().eq([2, 3, 4].iter()));
+    /// ```
+    pub fn slice_next(&self, n: usize) -> ListSlice<'a, T> {
+        let n = n.min(self.length - self.index);
+        if n == 0 {
+            return ListSlice::from_range(Link::null(), Link::null(), 0);
+        }
+        let mut tail = self.curr;
+        unsafe { (1..n).for_each(|_| tail = tail.next()) };
+        ListSlice::from_range(self.curr, tail, n)
+    }
 }
@@ -1,7 +1,13 @@
-use std::ptr;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
 
+use super::seek_node;
+use crate::combinatorics::IterMut;
+#[cfg(feature = "fallible-alloc")]
+use crate::AllocError;
+use crate::IndexOutOfBounds;
 use crate::Link;
-use crate::LinkMut;
 use crate::LinkedList;
 use crate::Node;
 use crate::RemoveUnderCursorError;
@@ -13,27 +19,45 @@ pub struct CursorMut<'a, T> {
     pub(super) length: usize,
 }
 
+// CursorMut hands out mutable references to the values only through `&mut self`,
+// so it behaves like `&mut LinkedList<T>` across threads.
+unsafe impl<'a, T: Send> Send for CursorMut<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for CursorMut<'a, T> {}
+
+// Implement Debug for CursorMut
+impl<'a, T> Debug for CursorMut<'a, T> {
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// let cursor = list.cursor_back_mut().unwrap();
+    /// assert_eq!(format!("{:?}", cursor), "CursorMut { index: 2, len: 3 }");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("CursorMut")
+            .field("index", &self.index)
+            .field("len", &self.length)
+            .finish()
+    }
+}
+
 impl<'a, T> CursorMut<'a, T> {
     /// Returns the mutable reference to the value under the cursor and its index
     /// ```
     /// use linked_list::LinkedList;
     /// let mut list = [1, 2, 3].into_iter().collect::<LinkedList<i32>>();
-    /// let cursor = list.cursor_front_mut().unwrap();
+    /// let mut cursor = list.cursor_front_mut().unwrap();
     /// assert_eq!(cursor.current_mut(), (&mut 1, 0));
-    /// let cursor = list.cursor_back_mut().unwrap();
+    /// let mut cursor = list.cursor_back_mut().unwrap();
     /// assert_eq!(cursor.current_mut(), (&mut 3, 2));
     /// ```
-    pub fn current_mut(&self) -> (&mut T, usize) {
+    pub fn current_mut(&mut self) -> (&mut T, usize) {
         // if `curr` contains null then panic
         if self.curr.is_null() {
             unreachable!("Cursor cannot contain null pointer");
         }
 
         // return the reference to the value under curr pointer
-        unsafe {
-            let curr = self.curr as LinkMut<T>;
-            (&mut (*curr).val, self.index)
-        }
+        unsafe { (self.curr.val_mut(), self.index) }
     }
 
     /// Returns the mutable reference to the value previous to the node under the cursor and its index
@@ -44,18 +68,18 @@ impl<'a, T> CursorMut<'a, T> {
     /// ```
     /// use linked_list::LinkedList;
     /// let mut list = [1].into_iter().collect::<LinkedList<i32>>();
-    /// let cursor = list.cursor_front_mut().unwrap();
+    /// let mut cursor = list.cursor_front_mut().unwrap();
     /// assert_eq!(cursor.prev_mut(), (&mut 1, 0));
-    /// let cursor = list.cursor_back_mut().unwrap();
+    /// let mut cursor = list.cursor_back_mut().unwrap();
     /// assert_eq!(cursor.prev_mut(), (&mut 1, 0));
     /// let mut list = [1, 2, 3].into_iter().collect::<LinkedList<i32>>();
-    /// let cursor = list.cursor_front_mut().unwrap();
+    /// let mut cursor = list.cursor_front_mut().unwrap();
     /// assert_eq!(cursor.prev_mut(), (&mut 3, 2));
     /// let (x, _) = cursor.prev_mut();
     /// *x += 1;
     /// assert_eq!(cursor.prev_mut(), (&mut 4, 2));
     /// ```
-    pub fn prev_mut(&self) -> (&mut T, usize) {
+    pub fn prev_mut(&mut self) -> (&mut T, usize) {
         // if `curr` contains null then panic
         if self.curr.is_null() {
             unreachable!("Cursor cannot contain null pointer");
@@ -66,12 +90,10 @@ impl<'a, T> CursorMut<'a, T> {
         unsafe {
             if self.index == 0 {
                 // when on the first element return the value from tail of the list
-                let tail = self.list.tail as LinkMut<T>;
-                (&mut (*tail).val, self.length - 1)
+                (self.list.tail.val_mut(), self.length - 1)
             } else {
                 // otherwise return the value from prev of curr
-                let curr_prev = (*self.curr).prev as LinkMut<T>;
-                (&mut (*curr_prev).val, self.index - 1)
+                (self.curr.prev().val_mut(), self.index - 1)
             }
         }
     }
@@ -83,15 +105,15 @@ impl<'a, T> CursorMut<'a, T> {
     /// ```
     /// use linked_list::LinkedList;
     /// let mut list = [1].into_iter().collect::<LinkedList<i32>>();
-    /// let cursor = list.cursor_front_mut().unwrap();
+    /// let mut cursor = list.cursor_front_mut().unwrap();
     /// assert_eq!(cursor.next_mut(), (&mut 1, 0));
-    /// let cursor = list.cursor_back_mut().unwrap();
+    /// let mut cursor = list.cursor_back_mut().unwrap();
     /// assert_eq!(cursor.next_mut(), (&mut 1, 0));
     /// let mut list = [1, 2, 3].into_iter().collect::<LinkedList<i32>>();
-    /// let cursor = list.cursor_back_mut().unwrap();
+    /// let mut cursor = list.cursor_back_mut().unwrap();
     /// assert_eq!(cursor.next_mut(), (&mut 1, 0));
     /// ```
-    pub fn next_mut(&self) -> (&mut T, usize) {
+    pub fn next_mut(&mut self) -> (&mut T, usize) {
         // if `curr` contains null then panic
         if self.curr.is_null() {
             unreachable!("Cursor cannot contain null pointer");
@@ -102,16 +124,59 @@ impl<'a, T> CursorMut<'a, T> {
         unsafe {
             if self.index == self.length - 1 {
                 // when on the last element return the value from head of the list
-                let head = self.list.head as LinkMut<T>;
-                (&mut (*head).val, 0)
+                (self.list.head.val_mut(), 0)
             } else {
                 // otherwise return the value from next of curr
-                let curr_next = (*self.curr).next as LinkMut<T>;
-                (&mut (*curr_next).val, self.index + 1)
+                (self.curr.next().val_mut(), self.index + 1)
             }
         }
     }
 
+    /// Splits the list at the cursor into two mutable views, like `slice::split_at_mut`.
+    /// The first one yields the elements before the cursor, the second one the element
+    /// under the cursor and the ones after it. Both can be used at the same time.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3, 4, 5, 6]);
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// cursor.step_by(3);
+    /// let (front, back) = cursor.split_at_mut();
+    /// // add the mirrored element of the back half to the front half
+    /// for (a, b) in front.zip(back.rev()) {
+    ///     *a += *b;
+    /// }
+    /// assert_eq!(list, [7, 7, 7, 4, 5, 6]);
+    /// ```
+    #[doc(alias = "split_at_cursor_mut")]
+    pub fn split_at_mut(&mut self) -> (IterMut<'_, T>, IterMut<'_, T>) {
+        // the front part is empty when the cursor is on the first node
+        let (front_head, front_tail) = if self.index == 0 {
+            (Link::null(), Link::null())
+        } else {
+            unsafe { (self.list.head, self.curr.prev()) }
+        };
+        // the two ranges share no node, so their mutable references never alias
+        let front = IterMut::from_range(front_head, front_tail, self.index);
+        let back = IterMut::from_range(self.curr, self.list.tail, self.length - self.index);
+        (front, back)
+    }
+
+    /// Replaces the value under the cursor with the given one and returns the old value.
+    /// The node stays linked in place, so no allocation happens.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// cursor.move_next();
+    /// assert_eq!(cursor.replace(20), 2);
+    /// assert_eq!(cursor.current_mut(), (&mut 20, 1));
+    /// assert_eq!(list, [1, 20, 3]);
+    /// ```
+    pub fn replace(&mut self, elem: T) -> T {
+        // swap the new value into the node under the cursor
+        unsafe { std::mem::replace(self.curr.val_mut(), elem) }
+    }
+
     /// Move the cursor one node towards front.
     /// When the cursor is on the first node then this method moves the cursor to the last node.
     /// ```
@@ -132,11 +197,55 @@ impl<'a, T> CursorMut<'a, T> {
         }
         unsafe {
             // move the cursor to the prev node
-            self.curr = (*self.curr).prev;
+            self.curr = self.curr.prev();
             self.index -= 1;
         }
     }
 
+    /// Moves the cursor one node towards back and returns true.
+    /// Unlike `move_next` it does not wrap around, on the last node it returns false and stays there.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// let mut seen = vec![*cursor.current_mut().0];
+    /// while cursor.try_move_next() {
+    ///     seen.push(*cursor.current_mut().0);
+    /// }
+    /// assert_eq!(seen, [1, 2, 3]);
+    /// assert_eq!(cursor.current_mut(), (&mut 3, 2));
+    /// ```
+    pub fn try_move_next(&mut self) -> bool {
+        if self.index == self.length - 1 {
+            return false;
+        }
+        unsafe {
+            self.curr = self.curr.next();
+        }
+        self.index += 1;
+        true
+    }
+
+    /// Moves the cursor one node towards front and returns true.
+    /// Unlike `move_prev` it does not wrap around, on the first node it returns false and stays there.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// assert!(!cursor.try_move_prev());
+    /// assert_eq!(cursor.current_mut(), (&mut 1, 0));
+    /// ```
+    pub fn try_move_prev(&mut self) -> bool {
+        if self.index == 0 {
+            return false;
+        }
+        unsafe {
+            self.curr = self.curr.prev();
+        }
+        self.index -= 1;
+        true
+    }
+
     /// Move the cursor one node towards back.
     /// When the cursor is on the last node then this method moves the cursor to the first node.
     /// ```
@@ -157,11 +266,31 @@ impl<'a, T> CursorMut<'a, T> {
         }
         unsafe {
             // move the cursor to the next node
-            self.curr = (*self.curr).next;
+            self.curr = self.curr.next();
             self.index += 1;
         }
     }
 
+    /// Moves the cursor to the node at the absolute index.
+    /// It walks from the head, the tail or the current node, whichever is the closest.
+    /// Returns an error and stays in place if the index is out of bounds.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// let mut cursor = list.cursor_back_mut().unwrap();
+    /// assert!(cursor.seek_to(1).is_ok());
+    /// assert_eq!(cursor.current_mut(), (&mut 2, 1));
+    /// assert!(cursor.seek_to(9).is_err());
+    /// ```
+    pub fn seek_to(&mut self, index: usize) -> Result<(), IndexOutOfBounds> {
+        if index >= self.length {
+            return Err(IndexOutOfBounds);
+        }
+        self.curr = unsafe { seek_node(self.list, self.curr, self.index, self.length, index) };
+        self.index = index;
+        Ok(())
+    }
+
     /// Move the cursor no of steps at once.
     /// index will wrap around according to the no of steps given.
     /// ```
@@ -217,19 +346,45 @@ impl<'a, T> CursorMut<'a, T> {
     /// ```
     pub fn insert(&mut self, elem: T) {
         // create a new_node
-        let mut new_node = Box::into_raw(Box::new(Node::new(elem)));
+        let new_node = Node::new_raw(elem);
+        self.link_after(new_node);
+    }
+
+    /// Insert a new node after the node cursor currently pointing
+    /// Cursor also moves one node towards back.
+    /// Returns the element back inside `AllocError` if the node could not be allocated.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2]);
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// assert!(cursor.try_insert(3).is_ok());
+    /// assert_eq!(cursor.current_mut(), (&mut 3, 1));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_insert(&mut self, elem: T) -> Result<(), AllocError<T>> {
+        let new_node = Node::try_new_raw(elem)?;
+        self.link_after(new_node);
+        Ok(())
+    }
+
+    // links an already allocated node after the node under the cursor
+    // and moves the cursor to it
+    fn link_after(&mut self, new_node: Link<T>) {
         unsafe {
             // set next of curr as the next of new_node
-            (*new_node).next = (*self.curr).next;
+            new_node.set_next(self.curr.next());
             // set the current node as the prev of new_node
-            (*new_node).prev = self.curr;
+            new_node.set_prev(self.curr);
+            // set new_node as the prev of the node next to curr
+            if !self.curr.next().is_null() {
+                self.curr.next().set_prev(new_node);
+            }
             // set next of curr as the new_node
-            let curr = self.curr as LinkMut<T>;
-            (*curr).next = new_node as Link<T>;
+            self.curr.set_next(new_node);
         }
         // if at last element then adjust tail pointer of the list
         if self.index == self.length - 1 {
-            self.list.tail = new_node as Link<T>;
+            self.list.tail = new_node;
         }
         // increase length of the cursor
         self.length += 1;
@@ -237,6 +392,35 @@ impl<'a, T> CursorMut<'a, T> {
         self.move_next();
     }
 
+    /// Reverses `n` nodes starting from the node under the cursor in place.
+    /// The cursor stays at the same index, which now holds the last node of the span.
+    /// The span does not wrap around, `n` is clamped to the nodes left until the back.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// cursor.move_next();
+    /// cursor.reverse_next_n(3);
+    /// assert_eq!(cursor.current_mut(), (&mut 4, 1));
+    /// cursor.reverse_next_n(10);
+    /// assert_eq!(cursor.current_mut(), (&mut 5, 1));
+    /// assert!(list.iter().eq([1, 5, 2, 3, 4].iter()));
+    /// ```
+    pub fn reverse_next_n(&mut self, n: usize) {
+        let n = n.min(self.length - self.index);
+        if n < 2 {
+            return;
+        }
+        unsafe {
+            // find the last node of the span
+            let mut last = self.curr;
+            (1..n).for_each(|_| last = last.next());
+            self.list.reverse_nodes(self.curr, last);
+            // last node of the span is now under the cursor
+            self.curr = last;
+        }
+    }
+
     /// Removes the node under the cursor and cursor moves to be node next
     /// Note: Returns error if the list contain only one node
     /// ```
@@ -261,12 +445,11 @@ impl<'a, T> CursorMut<'a, T> {
         }
         unsafe {
             // take out the node currently under the cursor
-            let boxed_node = Box::from_raw(self.curr as LinkMut<T>);
+            let boxed_node = Node::free(self.curr);
             // if the `prev` of `boxed_node` is not null
             // then `next` of `prev` of `boxed_node` will point to `next` of `boxed_node`
             if !boxed_node.prev.is_null() {
-                let node_prev = boxed_node.prev as LinkMut<T>;
-                (*node_prev).next = boxed_node.next;
+                boxed_node.prev.set_next(boxed_node.next);
             } else {
                 // boxed_node is the first node in the list
                 // `head` pointer of the list now point to `next` of `boxed_node`
@@ -276,8 +459,7 @@ impl<'a, T> CursorMut<'a, T> {
             // if the `next` of `boxed_node` is not null
             // then `prev` of `next` of `boxed_node` will point to `prev` of `boxed_node`
             if !boxed_node.next.is_null() {
-                let node_next = boxed_node.next as LinkMut<T>;
-                (*node_next).prev = boxed_node.prev;
+                boxed_node.next.set_prev(boxed_node.prev);
                 // curr will now point to `next` of `boxed_node`
                 self.curr = boxed_node.next;
             } else {
@@ -295,6 +477,65 @@ impl<'a, T> CursorMut<'a, T> {
         }
     }
 
+    /// Removes the node after the node under the cursor and returns its value.
+    /// The cursor stays on its node. Returns `None` if the cursor is on the last node,
+    /// it does not wrap around to the first one.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 1, 2, 2, 2, 3]);
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// // remove consecutive duplicates
+    /// loop {
+    ///     let curr = *cursor.current_mut().0;
+    ///     // index 0 means next_mut wrapped around, the cursor is on the last node
+    ///     while cursor.next_mut().1 != 0 && *cursor.next_mut().0 == curr {
+    ///         cursor.remove_next();
+    ///     }
+    ///     if !cursor.try_move_next() {
+    ///         break;
+    ///     }
+    /// }
+    /// assert_eq!(cursor.remove_next(), None);
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    pub fn remove_next(&mut self) -> Option<T> {
+        let next = unsafe { self.curr.next() };
+        if next.is_null() {
+            return None;
+        }
+        self.length -= 1;
+        unsafe {
+            self.list.unlink_nodes(next, next);
+            Some(Node::free(next).val)
+        }
+    }
+
+    /// Removes the node before the node under the cursor and returns its value.
+    /// The cursor stays on its node, whose index goes down by one. Returns `None`
+    /// if the cursor is on the first node, it does not wrap around to the last one.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// let mut cursor = list.cursor_back_mut().unwrap();
+    /// assert_eq!(cursor.remove_prev(), Some(2));
+    /// assert_eq!(cursor.current_mut(), (&mut 3, 1));
+    /// assert_eq!(cursor.remove_prev(), Some(1));
+    /// assert_eq!(cursor.remove_prev(), None);
+    /// assert_eq!(list, [3]);
+    /// ```
+    pub fn remove_prev(&mut self) -> Option<T> {
+        let prev = unsafe { self.curr.prev() };
+        if prev.is_null() {
+            return None;
+        }
+        self.length -= 1;
+        self.index -= 1;
+        unsafe {
+            self.list.unlink_nodes(prev, prev);
+            Some(Node::free(prev).val)
+        }
+    }
+
     /// Split the list at the node where the cursor is pointing to.
     /// After split the node under the cursor becomes the last node of the list.
     /// A new list is generated and returned with all rest of the elements
@@ -311,10 +552,12 @@ impl<'a, T> CursorMut<'a, T> {
     pub fn split(&mut self) -> LinkedList<T> {
         let mut new_list = LinkedList::new();
         unsafe {
-            if !(*self.curr).next.is_null() {
+            if !self.curr.next().is_null() {
                 new_list.tail = self.list.tail;
-                new_list.head = (*self.curr).next;
-                (*(self.curr as LinkMut<T>)).next = ptr::null();
+                new_list.head = self.curr.next();
+                // first node of the new list must not point back into this list
+                new_list.head.set_prev(Link::null());
+                self.curr.set_next(Link::null());
                 self.list.tail = self.curr;
                 self.length = self.list.len();
             }
@@ -323,6 +566,55 @@ impl<'a, T> CursorMut<'a, T> {
         new_list
     }
 
+    /// Detaches up to `n` nodes following the node under the cursor and returns them as a new list.
+    /// The cursor stays on its node. Finding the end of the segment walks `n` nodes,
+    /// cutting it out only relinks its two ends.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// let cut = cursor.cut_next(2);
+    /// assert_eq!(cursor.next_mut(), (&mut 4, 1));
+    /// assert_eq!(cut, [2, 3]);
+    /// assert_eq!(cursor.cut_next(10), [4, 5]);
+    /// assert_eq!(list, [1]);
+    /// ```
+    pub fn cut_next(&mut self, n: usize) -> LinkedList<T> {
+        let n = n.min(self.length - self.index - 1);
+        let mut cut = LinkedList::new();
+        if n == 0 {
+            return cut;
+        }
+        unsafe {
+            // find the last node of the segment
+            let first = self.curr.next();
+            let mut last = first;
+            (1..n).for_each(|_| last = last.next());
+            self.list.unlink_nodes(first, last);
+            cut.head = first;
+            cut.tail = last;
+        }
+        self.length -= n;
+        cut
+    }
+
+    /// Detaches the nodes between the node under the cursor and the node at index `end`,
+    /// both exclusive, and returns them as a new list. The cursor stays on its node.
+    /// `end` is clamped to the length of the list, so `usize::MAX` cuts everything after the cursor.
+    /// Returns an empty list if `end` is not after the index of the cursor.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// cursor.move_next();
+    /// assert_eq!(cursor.cut_until(4), [3, 4]);
+    /// assert_eq!(cursor.cut_until(0), []);
+    /// assert_eq!(list, [1, 2, 5]);
+    /// ```
+    pub fn cut_until(&mut self, end: usize) -> LinkedList<T> {
+        self.cut_next(end.saturating_sub(self.index + 1))
+    }
+
     /// Insert the given list into the underlying list.
     /// Cursor advances until the last node of the other list.
     /// ```
@@ -342,20 +634,50 @@ impl<'a, T> CursorMut<'a, T> {
         }
         let other_len = other.len();
         unsafe {
-            if !(*self.curr).next.is_null() {
-                let curr_next = (*self.curr).next as LinkMut<T>;
-                let other_tail = other.tail as LinkMut<T>;
-                (*curr_next).prev = other.tail;
-                (*other_tail).next = (*self.curr).next;
+            if !self.curr.next().is_null() {
+                self.curr.next().set_prev(other.tail);
+                other.tail.set_next(self.curr.next());
             } else {
                 self.list.tail = other.tail;
             }
-            (*(self.curr as LinkMut<T>)).next = other.head;
+            self.curr.set_next(other.head);
+            other.head.set_prev(self.curr);
             self.curr = other.tail;
         }
         self.length += other_len;
         self.index += other_len;
-        other.head = ptr::null();
-        other.tail = ptr::null();
+        other.head = Link::null();
+        other.tail = Link::null();
+    }
+
+    /// Rotates the list so that the node under the cursor becomes the head.
+    /// The nodes before the cursor move behind the old tail, keeping their order.
+    /// Only the links at both ends change, the cursor stays on its node which is now at index 0.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// cursor.step_by(3);
+    /// cursor.rotate_to_front();
+    /// assert_eq!(cursor.current_mut(), (&mut 4, 0));
+    /// assert_eq!(list, [4, 5, 1, 2, 3]);
+    /// ```
+    pub fn rotate_to_front(&mut self) {
+        if self.index == 0 {
+            return;
+        }
+        unsafe {
+            let old_head = self.list.head;
+            let old_tail = self.list.tail;
+            let new_tail = self.curr.prev();
+            // close the list into a ring, then cut it open before the cursor
+            old_tail.set_next(old_head);
+            old_head.set_prev(old_tail);
+            new_tail.set_next(Link::null());
+            self.curr.set_prev(Link::null());
+            self.list.head = self.curr;
+            self.list.tail = new_tail;
+        }
+        self.index = 0;
     }
 }
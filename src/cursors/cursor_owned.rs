@@ -0,0 +1,258 @@
+use super::CursorMut;
+#[cfg(feature = "fallible-alloc")]
+use crate::AllocError;
+use crate::IndexOutOfBounds;
+use crate::Link;
+use crate::LinkedList;
+use crate::RemoveUnderCursorError;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+/// Cursor owning the list it walks over.
+/// It has no lifetime, so it can be stored in a struct and kept across calls.
+/// The list is given back by `into_list`.
+/// This struct can be instantiated by calling `into_cursor_front` or `into_cursor_back` method in the LinkedList.
+pub struct CursorOwned<T> {
+    pub(super) curr: Link<T>,
+    pub(super) list: LinkedList<T>,
+    pub(super) index: usize,
+    pub(super) length: usize,
+}
+
+// CursorOwned owns the list, so it is as thread safe as the list.
+unsafe impl<T: Send> Send for CursorOwned<T> {}
+unsafe impl<T: Sync> Sync for CursorOwned<T> {}
+
+// Implement Debug for CursorOwned
+impl<T> Debug for CursorOwned<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("CursorOwned")
+            .field("index", &self.index)
+            .field("len", &self.length)
+            .finish()
+    }
+}
+
+impl<T> CursorOwned<T> {
+    /// Returns the reference to the value under the cursor and its index
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let cursor = LinkedList::from([1, 2, 3]).into_cursor_back().unwrap();
+    /// assert_eq!(cursor.current(), (&3, 2));
+    /// ```
+    pub fn current(&self) -> (&T, usize) {
+        unsafe { (self.curr.val(), self.index) }
+    }
+
+    /// Returns the mutable reference to the value under the cursor and its index
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut cursor = LinkedList::from([1, 2, 3]).into_cursor_front().unwrap();
+    /// *cursor.current_mut().0 += 10;
+    /// assert_eq!(cursor.into_list(), [11, 2, 3]);
+    /// ```
+    pub fn current_mut(&mut self) -> (&mut T, usize) {
+        unsafe { (self.curr.val_mut(), self.index) }
+    }
+
+    /// Returns the reference to the list.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let cursor = LinkedList::from([1, 2, 3]).into_cursor_front().unwrap();
+    /// assert_eq!(cursor.list().len(), 3);
+    /// ```
+    pub fn list(&self) -> &LinkedList<T> {
+        &self.list
+    }
+
+    /// Move the cursor one node towards front.
+    /// When the cursor is on the first node then this method moves the cursor to the last node.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut cursor = LinkedList::from([1, 2, 3]).into_cursor_front().unwrap();
+    /// cursor.move_prev();
+    /// assert_eq!(cursor.current(), (&3, 2));
+    /// ```
+    pub fn move_prev(&mut self) {
+        self.with_cursor_mut(|cursor| cursor.move_prev())
+    }
+
+    /// Move the cursor one node towards back.
+    /// When the cursor is on the last node then this method moves the cursor to the first node.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut cursor = LinkedList::from([1, 2, 3]).into_cursor_back().unwrap();
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), (&1, 0));
+    /// ```
+    pub fn move_next(&mut self) {
+        self.with_cursor_mut(|cursor| cursor.move_next())
+    }
+
+    /// Moves the cursor one node towards back and returns true.
+    /// On the last node it returns false and stays there.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut cursor = LinkedList::from([1, 2]).into_cursor_front().unwrap();
+    /// assert!(cursor.try_move_next());
+    /// assert!(!cursor.try_move_next());
+    /// assert_eq!(cursor.current(), (&2, 1));
+    /// ```
+    pub fn try_move_next(&mut self) -> bool {
+        self.with_cursor_mut(|cursor| cursor.try_move_next())
+    }
+
+    /// Moves the cursor one node towards front and returns true.
+    /// On the first node it returns false and stays there.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut cursor = LinkedList::from([1, 2]).into_cursor_back().unwrap();
+    /// assert!(cursor.try_move_prev());
+    /// assert!(!cursor.try_move_prev());
+    /// assert_eq!(cursor.current(), (&1, 0));
+    /// ```
+    pub fn try_move_prev(&mut self) -> bool {
+        self.with_cursor_mut(|cursor| cursor.try_move_prev())
+    }
+
+    /// Moves the cursor to the node at the absolute index,
+    /// walking from the head, the tail or the current node, whichever is the closest.
+    /// Returns an error and stays in place if the index is out of bounds.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut cursor = LinkedList::from([1, 2, 3]).into_cursor_front().unwrap();
+    /// assert!(cursor.seek_to(2).is_ok());
+    /// assert_eq!(cursor.current(), (&3, 2));
+    /// assert!(cursor.seek_to(3).is_err());
+    /// ```
+    pub fn seek_to(&mut self, index: usize) -> Result<(), IndexOutOfBounds> {
+        self.with_cursor_mut(|cursor| cursor.seek_to(index))
+    }
+
+    /// Move the cursor by given number of steps towards back.
+    /// Note: index will wrap around the length of the list.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut cursor = LinkedList::from([1, 2, 3]).into_cursor_front().unwrap();
+    /// cursor.step_by(4);
+    /// assert_eq!(cursor.current(), (&2, 1));
+    /// ```
+    pub fn step_by(&mut self, steps: usize) {
+        self.with_cursor_mut(|cursor| cursor.step_by(steps))
+    }
+
+    /// Move the cursor by given number of steps towards front.
+    /// Note: index will wrap around the length of the list.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut cursor = LinkedList::from([1, 2, 3]).into_cursor_front().unwrap();
+    /// cursor.step_by_backward(2);
+    /// assert_eq!(cursor.current(), (&2, 1));
+    /// ```
+    pub fn step_by_backward(&mut self, steps: usize) {
+        self.with_cursor_mut(|cursor| cursor.step_by_backward(steps))
+    }
+
+    /// Insert a new node after the node cursor currently pointing
+    /// Cursor also moves one node towards back.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut cursor = LinkedList::from([1, 3]).into_cursor_front().unwrap();
+    /// cursor.insert(2);
+    /// assert_eq!(cursor.current(), (&2, 1));
+    /// assert_eq!(cursor.into_list(), [1, 2, 3]);
+    /// ```
+    pub fn insert(&mut self, elem: T) {
+        self.with_cursor_mut(|cursor| cursor.insert(elem))
+    }
+
+    /// Insert a new node after the node cursor currently pointing
+    /// Cursor also moves one node towards back.
+    /// Returns the element back inside `AllocError` if the node could not be allocated.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut cursor = LinkedList::from([1, 3]).into_cursor_front().unwrap();
+    /// assert!(cursor.try_insert(2).is_ok());
+    /// assert_eq!(cursor.into_list(), [1, 2, 3]);
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_insert(&mut self, elem: T) -> Result<(), AllocError<T>> {
+        self.with_cursor_mut(|cursor| cursor.try_insert(elem))
+    }
+
+    /// Removes the node under the cursor and cursor moves to be node next
+    /// Note: Returns error if the list contain only one node
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut cursor = LinkedList::from([1, 2]).into_cursor_front().unwrap();
+    /// assert_eq!(cursor.remove(), Ok(1));
+    /// assert!(cursor.remove().is_err());
+    /// assert_eq!(cursor.into_list(), [2]);
+    /// ```
+    pub fn remove(&mut self) -> Result<T, RemoveUnderCursorError> {
+        self.with_cursor_mut(|cursor| cursor.remove())
+    }
+
+    /// Removes the node after the node under the cursor and returns its value.
+    /// The cursor stays on its node. Returns `None` on the last node.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut cursor = LinkedList::from([1, 2, 3]).into_cursor_front().unwrap();
+    /// assert_eq!(cursor.remove_next(), Some(2));
+    /// assert_eq!(cursor.current(), (&1, 0));
+    /// assert_eq!(cursor.into_list(), [1, 3]);
+    /// ```
+    pub fn remove_next(&mut self) -> Option<T> {
+        self.with_cursor_mut(|cursor| cursor.remove_next())
+    }
+
+    /// Removes the node before the node under the cursor and returns its value.
+    /// The cursor stays on its node. Returns `None` on the first node.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut cursor = LinkedList::from([1, 2, 3]).into_cursor_back().unwrap();
+    /// assert_eq!(cursor.remove_prev(), Some(2));
+    /// assert_eq!(cursor.current(), (&3, 1));
+    /// assert_eq!(cursor.into_list(), [1, 3]);
+    /// ```
+    pub fn remove_prev(&mut self) -> Option<T> {
+        self.with_cursor_mut(|cursor| cursor.remove_prev())
+    }
+
+    /// Replaces the value under the cursor and returns the old value.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut cursor = LinkedList::from([1, 2]).into_cursor_back().unwrap();
+    /// assert_eq!(cursor.replace(20), 2);
+    /// assert_eq!(cursor.into_list(), [1, 20]);
+    /// ```
+    pub fn replace(&mut self, elem: T) -> T {
+        self.with_cursor_mut(|cursor| cursor.replace(elem))
+    }
+
+    /// Consumes the cursor and returns the list.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let cursor = LinkedList::from([1, 2, 3]).into_cursor_front().unwrap();
+    /// assert_eq!(cursor.into_list(), [1, 2, 3]);
+    /// ```
+    pub fn into_list(self) -> LinkedList<T> {
+        self.list
+    }
+
+    // Runs `f` on a borrowing cursor at the same position and keeps the position it ends at.
+    fn with_cursor_mut<R>(&mut self, f: impl FnOnce(&mut CursorMut<'_, T>) -> R) -> R {
+        let mut cursor = CursorMut {
+            curr: self.curr,
+            list: &mut self.list,
+            index: self.index,
+            length: self.length,
+        };
+        let result = f(&mut cursor);
+        self.curr = cursor.curr;
+        self.index = cursor.index;
+        self.length = cursor.length;
+        result
+    }
+}
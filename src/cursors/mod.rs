@@ -1,9 +1,14 @@
-use self::cursor::Cursor;
-use self::cursor_mut::CursorMut;
+pub use self::cursor::Cursor;
+pub use self::cursor_mut::CursorMut;
+pub use self::cursor_owned::CursorOwned;
+pub use self::multi::{CursorId, Cursors};
+use crate::Link;
 use crate::LinkedList;
 
 mod cursor;
 mod cursor_mut;
+mod cursor_owned;
+mod multi;
 
 impl<T> LinkedList<T> {
     /// Returns a new Cursor initialized at the front of the list
@@ -13,7 +18,7 @@ impl<T> LinkedList<T> {
     /// let cursor = list.cursor_front();
     /// assert_eq!(cursor.is_some(), true);
     /// ```
-    pub fn cursor_front(&self) -> Option<Cursor<T>> {
+    pub fn cursor_front(&self) -> Option<Cursor<'_, T>> {
         // if head is null then list is empty, return None
         if self.head.is_null() {
             return None;
@@ -36,7 +41,7 @@ impl<T> LinkedList<T> {
     /// let cursor = list.cursor_back();
     /// assert_eq!(cursor.is_some(), false);
     /// ```
-    pub fn cursor_back(&self) -> Option<Cursor<T>> {
+    pub fn cursor_back(&self) -> Option<Cursor<'_, T>> {
         // if tail is null then list is empty, return None
         if self.tail.is_null() {
             return None;
@@ -56,7 +61,7 @@ impl<T> LinkedList<T> {
     /// let cursor = list.cursor_front_mut();
     /// assert_eq!(cursor.is_some(), true);
     /// ```
-    pub fn cursor_front_mut(&mut self) -> Option<CursorMut<T>> {
+    pub fn cursor_front_mut(&mut self) -> Option<CursorMut<'_, T>> {
         // if head is null then list is empty, return None
         if self.head.is_null() {
             return None;
@@ -80,7 +85,7 @@ impl<T> LinkedList<T> {
     /// let cursor = list.cursor_back_mut();
     /// assert_eq!(cursor.is_some(), false);
     /// ```
-    pub fn cursor_back_mut(&mut self) -> Option<CursorMut<T>> {
+    pub fn cursor_back_mut(&mut self) -> Option<CursorMut<'_, T>> {
         // if tail is null then list is empty, return None
         if self.tail.is_null() {
             return None;
@@ -93,4 +98,162 @@ impl<T> LinkedList<T> {
             length,
         })
     }
+
+    /// Returns a set of cursors over the list which can all edit it, see `Cursors`.
+    /// The set starts without cursors.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// let mut cursors = list.cursors();
+    /// let a = cursors.add_front().unwrap();
+    /// let b = cursors.add_back().unwrap();
+    /// *cursors.current_mut(a).unwrap() += 10;
+    /// *cursors.current_mut(b).unwrap() += 10;
+    /// assert_eq!(list, [11, 2, 13]);
+    /// ```
+    pub fn cursors(&mut self) -> Cursors<'_, T> {
+        Cursors::new(self)
+    }
+
+    /// Consumes the list and returns a cursor owning it, initialized at the front of the list.
+    /// Returns `None` if the list is empty.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut cursor = LinkedList::from([1, 2, 3]).into_cursor_front().unwrap();
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), (&2, 1));
+    /// assert!(LinkedList::<i32>::new().into_cursor_front().is_none());
+    /// ```
+    pub fn into_cursor_front(self) -> Option<CursorOwned<T>> {
+        // if head is null then list is empty, return None
+        if self.head.is_null() {
+            return None;
+        }
+        let length = self.len();
+        Some(CursorOwned {
+            curr: self.head,
+            list: self,
+            index: 0,
+            length,
+        })
+    }
+
+    /// Consumes the list and returns a cursor owning it, initialized at the back of the list.
+    /// Returns `None` if the list is empty.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let cursor = LinkedList::from([1, 2, 3]).into_cursor_back().unwrap();
+    /// assert_eq!(cursor.current(), (&3, 2));
+    /// ```
+    pub fn into_cursor_back(self) -> Option<CursorOwned<T>> {
+        // if tail is null then list is empty, return None
+        if self.tail.is_null() {
+            return None;
+        }
+        let length = self.len();
+        Some(CursorOwned {
+            curr: self.tail,
+            list: self,
+            index: length - 1,
+            length,
+        })
+    }
+
+    /// Returns a new Cursor positioned at the first element matching the predicate.
+    /// Returns `None` if no element matches.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 4, 2, 8]);
+    /// let cursor = list.cursor_find(|x| *x > 3).unwrap();
+    /// assert_eq!(cursor.current(), (&4, 1));
+    /// assert!(list.cursor_find(|x| *x > 10).is_none());
+    /// ```
+    pub fn cursor_find<P>(&self, pred: P) -> Option<Cursor<'_, T>>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let (curr, index, length) = self.find_node(pred)?;
+        Some(Cursor {
+            curr,
+            list: self,
+            index,
+            length,
+        })
+    }
+
+    /// Returns a new Mutable Cursor positioned at the first element matching the predicate.
+    /// Returns `None` if no element matches.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([(3, 'a'), (7, 'b'), (9, 'c')]);
+    /// if let Some(mut cursor) = list.cursor_find_mut(|(id, _)| *id == 7) {
+    ///     cursor.current_mut().0 .1 = 'x';
+    /// }
+    /// assert_eq!(list, [(3, 'a'), (7, 'x'), (9, 'c')]);
+    /// ```
+    pub fn cursor_find_mut<P>(&mut self, pred: P) -> Option<CursorMut<'_, T>>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let (curr, index, length) = self.find_node(pred)?;
+        Some(CursorMut {
+            curr,
+            list: self,
+            index,
+            length,
+        })
+    }
+
+    // Returns the first node matching the predicate, its index and the length of the list.
+    // The list is walked only once, counting on after the match.
+    fn find_node<P>(&self, mut pred: P) -> Option<(Link<T>, usize, usize)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut curr = self.head;
+        let mut index = 0;
+        unsafe {
+            while !curr.is_null() && !pred(curr.val()) {
+                curr = curr.next();
+                index += 1;
+            }
+            if curr.is_null() {
+                return None;
+            }
+            // count the nodes after the match
+            let mut length = index + 1;
+            let mut rest = curr.next();
+            while !rest.is_null() {
+                rest = rest.next();
+                length += 1;
+            }
+            Some((curr, index, length))
+        }
+    }
+}
+
+// Returns the node at `target`, walking from whichever of the head, the tail
+// or the node `curr` at index `index` is the closest to it.
+// SAFETY: `curr` must be the node at `index` of `list`, and `target` must be less than its length.
+unsafe fn seek_node<T>(
+    list: &LinkedList<T>,
+    curr: Link<T>,
+    index: usize,
+    length: usize,
+    target: usize,
+) -> Link<T> {
+    let from_tail = length - 1 - target;
+    let mut node;
+    if target <= index.abs_diff(target) && target <= from_tail {
+        node = list.head;
+        (0..target).for_each(|_| node = node.next());
+    } else if from_tail <= index.abs_diff(target) {
+        node = list.tail;
+        (0..from_tail).for_each(|_| node = node.prev());
+    } else {
+        node = curr;
+        (index..target).for_each(|_| node = node.next());
+        (target..index).for_each(|_| node = node.prev());
+    }
+    node
 }
@@ -0,0 +1,331 @@
+#[cfg(feature = "fallible-alloc")]
+use crate::AllocError;
+use crate::Link;
+use crate::LinkedList;
+use crate::Node;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+/// Identifier of a cursor handed out by `Cursors`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CursorId(usize);
+
+/// A set of cursors over one list, any of them can edit the list.
+/// Every cursor points at a node, so inserting through one cursor never moves the others.
+/// When a node is removed every cursor on it moves on to the next node,
+/// cursors on the removed tail node become invalid and return `None` from then on.
+/// This struct can be instantiated by calling `cursors` method in the LinkedList.
+///
+/// Example:
+/// ```
+/// use linked_list::LinkedList;
+/// let mut list = LinkedList::from([1, 2, 3, 4]);
+/// let mut cursors = list.cursors();
+/// let front = cursors.add_front().unwrap();
+/// let back = cursors.add_back().unwrap();
+/// cursors.insert_after(front, 10);
+/// cursors.insert_before(back, 20);
+/// assert_eq!(cursors.remove(back), Some(4));
+/// assert_eq!(cursors.current(back), None);
+/// assert_eq!(cursors.current(front), Some(&1));
+/// assert_eq!(list, [1, 10, 2, 3, 20]);
+/// ```
+pub struct Cursors<'a, T> {
+    list: &'a mut LinkedList<T>,
+    // node of every cursor, null once the cursor is invalid
+    positions: Vec<Link<T>>,
+}
+
+// Cursors can be moved to another thread like `&mut LinkedList<T>`.
+unsafe impl<'a, T: Send> Send for Cursors<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for Cursors<'a, T> {}
+
+// Implement Debug for Cursors
+impl<'a, T> Debug for Cursors<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Cursors")
+            .field("cursors", &self.positions.len())
+            .finish()
+    }
+}
+
+impl<'a, T> Cursors<'a, T> {
+    pub(super) fn new(list: &'a mut LinkedList<T>) -> Self {
+        Self {
+            list,
+            positions: Vec::new(),
+        }
+    }
+
+    /// Adds a cursor at the front of the list and returns its id.
+    /// Returns `None` if the list is empty.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2]);
+    /// let mut cursors = list.cursors();
+    /// let id = cursors.add_front().unwrap();
+    /// assert_eq!(cursors.current(id), Some(&1));
+    /// ```
+    pub fn add_front(&mut self) -> Option<CursorId> {
+        self.add(self.list.head)
+    }
+
+    /// Adds a cursor at the back of the list and returns its id.
+    /// Returns `None` if the list is empty.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2]);
+    /// let mut cursors = list.cursors();
+    /// let id = cursors.add_back().unwrap();
+    /// assert_eq!(cursors.current(id), Some(&2));
+    /// ```
+    pub fn add_back(&mut self) -> Option<CursorId> {
+        self.add(self.list.tail)
+    }
+
+    /// Adds a cursor at the position of another one and returns its id.
+    /// Returns `None` if the other cursor is invalid.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2]);
+    /// let mut cursors = list.cursors();
+    /// let a = cursors.add_front().unwrap();
+    /// let b = cursors.duplicate(a).unwrap();
+    /// cursors.move_next(b);
+    /// assert_eq!((cursors.current(a), cursors.current(b)), (Some(&1), Some(&2)));
+    /// ```
+    pub fn duplicate(&mut self, id: CursorId) -> Option<CursorId> {
+        self.add(self.node(id))
+    }
+
+    /// Returns true if the cursor still points at an element.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1]);
+    /// let mut cursors = list.cursors();
+    /// let id = cursors.add_front().unwrap();
+    /// assert!(cursors.is_valid(id));
+    /// cursors.remove(id);
+    /// assert!(!cursors.is_valid(id));
+    /// ```
+    pub fn is_valid(&self, id: CursorId) -> bool {
+        !self.node(id).is_null()
+    }
+
+    /// Returns the reference to the element under the cursor.
+    /// Returns `None` if the cursor is invalid.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2]);
+    /// let mut cursors = list.cursors();
+    /// let id = cursors.add_back().unwrap();
+    /// assert_eq!(cursors.current(id), Some(&2));
+    /// ```
+    pub fn current(&self, id: CursorId) -> Option<&T> {
+        let node = self.node(id);
+        if node.is_null() {
+            return None;
+        }
+        unsafe { Some(node.val()) }
+    }
+
+    /// Returns the mutable reference to the element under the cursor.
+    /// Returns `None` if the cursor is invalid.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2]);
+    /// let mut cursors = list.cursors();
+    /// let id = cursors.add_back().unwrap();
+    /// *cursors.current_mut(id).unwrap() = 20;
+    /// assert_eq!(list, [1, 20]);
+    /// ```
+    pub fn current_mut(&mut self, id: CursorId) -> Option<&mut T> {
+        let node = self.node(id);
+        if node.is_null() {
+            return None;
+        }
+        unsafe { Some(node.val_mut()) }
+    }
+
+    /// Moves the cursor one node towards back and returns true.
+    /// Returns false if the cursor is on the last node or invalid, the cursor does not move then.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2]);
+    /// let mut cursors = list.cursors();
+    /// let id = cursors.add_front().unwrap();
+    /// assert!(cursors.move_next(id));
+    /// assert!(!cursors.move_next(id));
+    /// assert_eq!(cursors.current(id), Some(&2));
+    /// ```
+    pub fn move_next(&mut self, id: CursorId) -> bool {
+        let node = self.node(id);
+        if node.is_null() || unsafe { node.next().is_null() } {
+            return false;
+        }
+        self.positions[id.0] = unsafe { node.next() };
+        true
+    }
+
+    /// Moves the cursor one node towards front and returns true.
+    /// Returns false if the cursor is on the first node or invalid, the cursor does not move then.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2]);
+    /// let mut cursors = list.cursors();
+    /// let id = cursors.add_back().unwrap();
+    /// assert!(cursors.move_prev(id));
+    /// assert!(!cursors.move_prev(id));
+    /// assert_eq!(cursors.current(id), Some(&1));
+    /// ```
+    pub fn move_prev(&mut self, id: CursorId) -> bool {
+        let node = self.node(id);
+        if node.is_null() || unsafe { node.prev().is_null() } {
+            return false;
+        }
+        self.positions[id.0] = unsafe { node.prev() };
+        true
+    }
+
+    /// Inserts an element after the node under the cursor, the cursor stays where it is.
+    /// Returns false and drops the element if the cursor is invalid.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 3]);
+    /// let mut cursors = list.cursors();
+    /// let id = cursors.add_front().unwrap();
+    /// assert!(cursors.insert_after(id, 2));
+    /// assert_eq!(cursors.current(id), Some(&1));
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    pub fn insert_after(&mut self, id: CursorId, elem: T) -> bool {
+        let node = self.node(id);
+        if node.is_null() {
+            return false;
+        }
+        unsafe { self.link_before(elem, node.next()) };
+        true
+    }
+
+    /// Inserts an element before the node under the cursor, the cursor stays where it is.
+    /// Returns false and drops the element if the cursor is invalid.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 3]);
+    /// let mut cursors = list.cursors();
+    /// let id = cursors.add_back().unwrap();
+    /// assert!(cursors.insert_before(id, 2));
+    /// assert_eq!(cursors.current(id), Some(&3));
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    pub fn insert_before(&mut self, id: CursorId, elem: T) -> bool {
+        let node = self.node(id);
+        if node.is_null() {
+            return false;
+        }
+        unsafe { self.link_before(elem, node) };
+        true
+    }
+
+    /// Inserts an element after the node under the cursor, like `insert_after`.
+    /// Returns the element back inside `AllocError` if the node could not be allocated.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 3]);
+    /// let mut cursors = list.cursors();
+    /// let id = cursors.add_front().unwrap();
+    /// assert_eq!(cursors.try_insert_after(id, 2), Ok(true));
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_insert_after(&mut self, id: CursorId, elem: T) -> Result<bool, AllocError<T>> {
+        let node = self.node(id);
+        if node.is_null() {
+            return Ok(false);
+        }
+        unsafe { self.try_link_before(elem, node.next())? };
+        Ok(true)
+    }
+
+    /// Inserts an element before the node under the cursor, like `insert_before`.
+    /// Returns the element back inside `AllocError` if the node could not be allocated.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 3]);
+    /// let mut cursors = list.cursors();
+    /// let id = cursors.add_back().unwrap();
+    /// assert_eq!(cursors.try_insert_before(id, 2), Ok(true));
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_insert_before(&mut self, id: CursorId, elem: T) -> Result<bool, AllocError<T>> {
+        let node = self.node(id);
+        if node.is_null() {
+            return Ok(false);
+        }
+        unsafe { self.try_link_before(elem, node)? };
+        Ok(true)
+    }
+
+    /// Removes the element under the cursor and returns it.
+    /// Every cursor on the removed node moves on to the next node,
+    /// or becomes invalid if the removed node was the last one.
+    /// Returns `None` if the cursor is invalid.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// let mut cursors = list.cursors();
+    /// let a = cursors.add_front().unwrap();
+    /// let b = cursors.duplicate(a).unwrap();
+    /// assert_eq!(cursors.remove(a), Some(1));
+    /// assert_eq!(cursors.current(b), Some(&2));
+    /// ```
+    pub fn remove(&mut self, id: CursorId) -> Option<T> {
+        let node = self.node(id);
+        if node.is_null() {
+            return None;
+        }
+        unsafe {
+            let next = node.next();
+            // move every cursor off the node before it is freed
+            for position in self.positions.iter_mut() {
+                if *position == node {
+                    *position = next;
+                }
+            }
+            self.list.unlink_nodes(node, node);
+            Some(Node::free(node).val)
+        }
+    }
+
+    // Registers a new cursor on the node, which must be null or a node of the list.
+    fn add(&mut self, node: Link<T>) -> Option<CursorId> {
+        if node.is_null() {
+            return None;
+        }
+        self.positions.push(node);
+        Some(CursorId(self.positions.len() - 1))
+    }
+
+    // Returns the node of the cursor, or null if the cursor is invalid or unknown.
+    fn node(&self, id: CursorId) -> Link<T> {
+        self.positions.get(id.0).copied().unwrap_or(Link::null())
+    }
+
+    // Links a new node holding the element before `next`, or at the back if `next` is null.
+    unsafe fn link_before(&mut self, elem: T, next: Link<T>) {
+        let mut single = LinkedList::new();
+        single.push_back(elem);
+        self.list.link_list_before(single, next);
+    }
+
+    // Same as `link_before`, the element is handed back if its node could not be allocated.
+    #[cfg(feature = "fallible-alloc")]
+    unsafe fn try_link_before(&mut self, elem: T, next: Link<T>) -> Result<(), AllocError<T>> {
+        let mut single = LinkedList::new();
+        single.try_push_back(elem)?;
+        self.list.link_list_before(single, next);
+        Ok(())
+    }
+}
@@ -0,0 +1,225 @@
+//! Edit scripts between two lists.
+//! `diff` compares two lists and returns the shortest script of `Edit`s
+//! turning the first list into the second, based on their longest common subsequence.
+//! `apply_patch` replays such a script on a list in place.
+
+#[cfg(feature = "fallible-alloc")]
+use crate::AllocError;
+use crate::Link;
+use crate::LinkedList;
+use crate::PatchError;
+use std::marker::PhantomData;
+use std::mem;
+
+/// Single step of an edit script returned by `diff`.
+/// The steps are read front to back, every `Keep` and `Delete` consumes one element of the old list.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Edit<T> {
+    /// Keeps the next element of the old list.
+    Keep,
+    /// Removes the next element of the old list.
+    Delete,
+    /// Inserts the element before the next element of the old list.
+    Insert(T),
+}
+
+impl<T: PartialEq + Clone> LinkedList<T> {
+    /// Returns the edit script turning this list into `other`.
+    /// The script keeps as many elements as possible, inserted elements are cloned from `other`.
+    /// Deletions come before insertions where both are possible.
+    /// Note: it takes O(n * m) time and memory for the part of the lists between the common prefix and suffix.
+    /// ```
+    /// use linked_list::{Edit, LinkedList};
+    /// let old = LinkedList::from([1, 2, 3]);
+    /// let new = LinkedList::from([1, 3, 4]);
+    /// assert_eq!(
+    ///     old.diff(&new),
+    ///     [Edit::Keep, Edit::Delete, Edit::Keep, Edit::Insert(4)]
+    /// );
+    /// ```
+    pub fn diff(&self, other: &Self) -> Vec<Edit<T>> {
+        let old: Vec<&T> = self.iter().collect();
+        let new: Vec<&T> = other.iter().collect();
+
+        // the common prefix and suffix are kept as they are
+        let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+        let suffix = old[prefix..]
+            .iter()
+            .rev()
+            .zip(new[prefix..].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+        let old_mid = &old[prefix..old.len() - suffix];
+        let new_mid = &new[prefix..new.len() - suffix];
+
+        // lcs[i * width + j] is the length of the longest common subsequence of old_mid[i..] and new_mid[j..]
+        let width = new_mid.len() + 1;
+        let mut lcs = vec![0usize; (old_mid.len() + 1) * width];
+        for i in (0..old_mid.len()).rev() {
+            for j in (0..new_mid.len()).rev() {
+                lcs[i * width + j] = if old_mid[i] == new_mid[j] {
+                    lcs[(i + 1) * width + j + 1] + 1
+                } else {
+                    lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+                };
+            }
+        }
+
+        let mut script = Vec::with_capacity(old.len() + new.len() - prefix - suffix);
+        script.extend((0..prefix).map(|_| Edit::Keep));
+        let (mut i, mut j) = (0, 0);
+        while i < old_mid.len() && j < new_mid.len() {
+            if old_mid[i] == new_mid[j] {
+                script.push(Edit::Keep);
+                i += 1;
+                j += 1;
+            } else if lcs[(i + 1) * width + j] >= lcs[i * width + j + 1] {
+                script.push(Edit::Delete);
+                i += 1;
+            } else {
+                script.push(Edit::Insert(new_mid[j].clone()));
+                j += 1;
+            }
+        }
+        script.extend((i..old_mid.len()).map(|_| Edit::Delete));
+        script.extend(new_mid[j..].iter().map(|&elem| Edit::Insert(elem.clone())));
+        script.extend((0..suffix).map(|_| Edit::Keep));
+        script
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Applies an edit script, as returned by `diff`, to the list in place.
+    /// Kept nodes are not touched, runs of deleted nodes are unlinked at once
+    /// and runs of inserted elements are linked in at once.
+    /// Returns an error and leaves the list unchanged if the `Keep` and `Delete` steps
+    /// of the script do not add up to the length of the list.
+    /// ```
+    /// use linked_list::{Edit, LinkedList};
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// let target = LinkedList::from([0, 1, 3, 4]);
+    /// list.apply_patch(list.diff(&target)).unwrap();
+    /// assert_eq!(list, target);
+    /// assert!(list.apply_patch([Edit::Delete]).is_err());
+    /// ```
+    pub fn apply_patch<I>(&mut self, script: I) -> Result<(), PatchError>
+    where
+        I: IntoIterator<Item = Edit<T>>,
+    {
+        let script: Vec<Edit<T>> = script.into_iter().collect();
+        self.check_patch(&script)?;
+        // the inserted elements are moved into nodes first, the steps keep only their position
+        let mut elems = Self::new();
+        let mut steps = Vec::with_capacity(script.len());
+        for edit in script {
+            steps.push(match edit {
+                Edit::Insert(elem) => {
+                    elems.push_back(elem);
+                    Edit::Insert(())
+                }
+                Edit::Keep => Edit::Keep,
+                Edit::Delete => Edit::Delete,
+            });
+        }
+        unsafe { self.patch_nodes(steps, elems) };
+        Ok(())
+    }
+
+    /// Applies an edit script to the list in place, like `apply_patch`.
+    /// The nodes of all inserted elements are allocated before the list is changed.
+    /// Returns the element whose node could not be allocated inside `AllocError`,
+    /// the list is left unchanged and the rest of the script is dropped in that case.
+    /// Otherwise returns the result of `apply_patch`.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// let target = LinkedList::from([0, 1, 3, 4]);
+    /// assert_eq!(list.try_apply_patch(list.diff(&target)), Ok(Ok(())));
+    /// assert_eq!(list, target);
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_apply_patch<I>(&mut self, script: I) -> Result<Result<(), PatchError>, AllocError<T>>
+    where
+        I: IntoIterator<Item = Edit<T>>,
+    {
+        let script: Vec<Edit<T>> = script.into_iter().collect();
+        if let Err(err) = self.check_patch(&script) {
+            return Ok(Err(err));
+        }
+        let mut elems = Self::new();
+        let mut steps = Vec::with_capacity(script.len());
+        for edit in script {
+            steps.push(match edit {
+                Edit::Insert(elem) => {
+                    elems.try_push_back(elem)?;
+                    Edit::Insert(())
+                }
+                Edit::Keep => Edit::Keep,
+                Edit::Delete => Edit::Delete,
+            });
+        }
+        unsafe { self.patch_nodes(steps, elems) };
+        Ok(Ok(()))
+    }
+
+    // Checks that the `Keep` and `Delete` steps of the script consume every element exactly once.
+    fn check_patch(&self, script: &[Edit<T>]) -> Result<(), PatchError> {
+        let consumed = script
+            .iter()
+            .filter(|edit| !matches!(edit, Edit::Insert(_)))
+            .count();
+        if consumed != self.len() {
+            return Err(PatchError);
+        }
+        Ok(())
+    }
+
+    // Replays a checked script, every `Insert` step takes the next node of `elems`.
+    // Allocates nothing, so it cannot fail halfway.
+    unsafe fn patch_nodes(&mut self, steps: Vec<Edit<()>>, mut elems: Self) {
+        // node the next Keep or Delete applies to
+        let mut curr = self.head;
+        // elements waiting to be linked in before the next kept node
+        let mut inserted = Self::new();
+        // run of nodes waiting to be unlinked, null when there is none
+        let mut deleted: (Link<T>, Link<T>) = (Link::null(), Link::null());
+        for step in steps {
+            match step {
+                Edit::Insert(()) => {
+                    let node = elems.head;
+                    elems.unlink_nodes(node, node);
+                    inserted.link_back(node);
+                }
+                Edit::Delete => {
+                    if deleted.0.is_null() {
+                        deleted.0 = curr;
+                    }
+                    deleted.1 = curr;
+                    curr = curr.next();
+                }
+                Edit::Keep => {
+                    self.drop_nodes(mem::replace(&mut deleted.0, Link::null()), deleted.1);
+                    self.link_list_before(mem::take(&mut inserted), curr);
+                    curr = curr.next();
+                }
+            }
+        }
+        // curr is null here, so the rest of the inserted elements go to the back
+        self.drop_nodes(deleted.0, deleted.1);
+        self.link_list_before(inserted, curr);
+    }
+
+    // Unlinks the nodes from `first` to `last`, both inclusive, and drops them.
+    // Does nothing if `first` is null.
+    unsafe fn drop_nodes(&mut self, first: Link<T>, last: Link<T>) {
+        if first.is_null() {
+            return;
+        }
+        self.unlink_nodes(first, last);
+        drop(Self {
+            head: first,
+            tail: last,
+            _phantom: PhantomData,
+        });
+    }
+}
@@ -0,0 +1,127 @@
+//! Graphviz DOT export of the node structure.
+//! Every node is drawn with its value, `next` pointers as solid edges
+//! and `prev` pointers as dashed edges. Links which do not point back at
+//! their node, pointers to nodes outside the list and cycles are drawn in red,
+//! so the output can also be used to find corrupted links.
+
+use crate::Link;
+use crate::LinkedList;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::io;
+use std::io::Write;
+
+impl<T: Debug> LinkedList<T> {
+    /// Returns the node structure of the list in Graphviz DOT format.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2]);
+    /// let dot = list.to_dot();
+    /// assert!(dot.starts_with("digraph LinkedList {"));
+    /// assert!(dot.contains("n0 -> n1 [label=\"next\"];"));
+    /// assert!(dot.contains("n1 -> n0 [label=\"prev\", style=dashed];"));
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let mut out = Vec::new();
+        // writing into a Vec never fails
+        self.write_dot(&mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    /// Writes the node structure of the list in Graphviz DOT format.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from(['a']);
+    /// let mut out = Vec::new();
+    /// list.write_dot(&mut out).unwrap();
+    /// assert!(String::from_utf8(out).unwrap().contains("n0 [label=\"'a'\"];"));
+    /// ```
+    pub fn write_dot<W: Write>(&self, mut w: W) -> io::Result<()> {
+        writeln!(w, "digraph LinkedList {{")?;
+        writeln!(w, "    rankdir=LR;")?;
+        writeln!(w, "    node [shape=box];")?;
+        writeln!(w, "    head [shape=plaintext];")?;
+        writeln!(w, "    tail [shape=plaintext];")?;
+
+        // walk forward from head and number the nodes, stop if a node is seen twice
+        let mut ids = HashMap::new();
+        let mut nodes = Vec::new();
+        let mut curr = self.head;
+        while !curr.is_null() && !ids.contains_key(&curr) {
+            ids.insert(curr, nodes.len());
+            nodes.push(curr);
+            curr = unsafe { curr.next() };
+        }
+
+        for (id, node) in nodes.iter().enumerate() {
+            let label = format!("{:?}", unsafe { node.val() });
+            writeln!(w, "    n{} [label=\"{}\"];", id, escape(&label))?;
+        }
+
+        // returns the target of an edge, pointers outside the list get a node of their own
+        let target = |w: &mut W, link: Link<T>| -> io::Result<String> {
+            match ids.get(&link) {
+                Some(id) => Ok(format!("n{}", id)),
+                None => {
+                    let name = format!("\"{:p}\"", link);
+                    writeln!(w, "    {} [color=red];", name)?;
+                    Ok(name)
+                }
+            }
+        };
+
+        if !self.head.is_null() {
+            let head = target(&mut w, self.head)?;
+            writeln!(w, "    head -> {};", head)?;
+        }
+        if !self.tail.is_null() {
+            let tail = target(&mut w, self.tail)?;
+            // tail must point to the last node reached from head
+            let broken = nodes.last() != Some(&self.tail);
+            writeln!(w, "    tail -> {}{};", tail, red(broken, ""))?;
+        }
+
+        for (id, &node) in nodes.iter().enumerate() {
+            let (prev, next) = unsafe { (node.prev(), node.next()) };
+            if !next.is_null() {
+                let to = target(&mut w, next)?;
+                // next of a node must point back at it, and must not close a cycle
+                let broken = unsafe { next.prev() != node } || ids[&next] <= id;
+                writeln!(
+                    w,
+                    "    n{} -> {} [label=\"next\"{}];",
+                    id,
+                    to,
+                    red(broken, "")
+                )?;
+            }
+            if !prev.is_null() {
+                let to = target(&mut w, prev)?;
+                // prev of a node must point back at it, the first node has no prev
+                let broken = id == 0 || unsafe { prev.next() != node };
+                writeln!(
+                    w,
+                    "    n{} -> {} [label=\"prev\"{}];",
+                    id,
+                    to,
+                    red(broken, ", style=dashed")
+                )?;
+            }
+        }
+        writeln!(w, "}}")
+    }
+}
+
+// returns the extra edge attributes, broken edges are drawn in red
+fn red(broken: bool, attrs: &str) -> String {
+    if broken {
+        format!("{}, color=red", attrs)
+    } else {
+        attrs.to_string()
+    }
+}
+
+// escapes a label for use inside a double quoted DOT string
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
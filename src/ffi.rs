@@ -0,0 +1,137 @@
+//! C interface, enabled with the `ffi` feature.
+//! The list is exposed as an opaque `LlList` handle holding `int64_t` elements,
+//! and iteration goes through an opaque `LlIter` handle.
+//! Every handle returned by a `*_new` function must be released with the matching `*_free` function.
+//! The declarations for C are in `include/linked_list.h`, the library is built with
+//! `cargo rustc --release --features ffi --crate-type staticlib` (or `cdylib`).
+
+use crate::Link;
+use crate::LinkedList;
+
+/// Opaque handle of a list of `i64` for C.
+pub struct LlList {
+    list: LinkedList<i64>,
+}
+
+/// Opaque handle of an iterator over a `LlList` for C.
+pub struct LlIter {
+    // node to be yielded next, null when the iterator is exhausted
+    next: Link<i64>,
+}
+
+/// Creates a new empty list.
+#[no_mangle]
+pub extern "C" fn ll_new() -> *mut LlList {
+    Box::into_raw(Box::new(LlList {
+        list: LinkedList::new(),
+    }))
+}
+
+/// Frees the list and all its elements. Null is ignored.
+///
+/// # Safety
+/// `list` must be null or come from `ll_new` and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn ll_free(list: *mut LlList) {
+    if !list.is_null() {
+        drop(Box::from_raw(list));
+    }
+}
+
+/// Returns the number of elements in the list.
+///
+/// # Safety
+/// `list` must be a live handle from `ll_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ll_len(list: *const LlList) -> usize {
+    (*list).list.len()
+}
+
+/// Adds the value at the front of the list.
+///
+/// # Safety
+/// `list` must be a live handle from `ll_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ll_push_front(list: *mut LlList, value: i64) {
+    (*list).list.push_front(value);
+}
+
+/// Adds the value at the back of the list.
+///
+/// # Safety
+/// `list` must be a live handle from `ll_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ll_push_back(list: *mut LlList, value: i64) {
+    (*list).list.push_back(value);
+}
+
+/// Removes the first value of the list and writes it to `out`.
+/// Returns false and leaves `out` untouched if the list is empty.
+///
+/// # Safety
+/// `list` must be a live handle from `ll_new` and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn ll_pop_front(list: *mut LlList, out: *mut i64) -> bool {
+    match (*list).list.pop_front() {
+        Some(value) => {
+            *out = value;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Removes the last value of the list and writes it to `out`.
+/// Returns false and leaves `out` untouched if the list is empty.
+///
+/// # Safety
+/// `list` must be a live handle from `ll_new` and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn ll_pop_back(list: *mut LlList, out: *mut i64) -> bool {
+    match (*list).list.pop_back() {
+        Some(value) => {
+            *out = value;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Creates an iterator over the list from front to back.
+///
+/// # Safety
+/// `list` must be a live handle from `ll_new`.
+/// The list must not be changed or freed while the iterator is in use.
+#[no_mangle]
+pub unsafe extern "C" fn ll_iter_new(list: *const LlList) -> *mut LlIter {
+    Box::into_raw(Box::new(LlIter {
+        next: (*list).list.head,
+    }))
+}
+
+/// Writes the next value of the iterator to `out` and advances it.
+/// Returns false and leaves `out` untouched if the iterator is exhausted.
+///
+/// # Safety
+/// `iter` must be a live handle from `ll_iter_new` and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn ll_iter_next(iter: *mut LlIter, out: *mut i64) -> bool {
+    let iter = &mut *iter;
+    if iter.next.is_null() {
+        return false;
+    }
+    *out = *iter.next.val();
+    iter.next = iter.next.next();
+    true
+}
+
+/// Frees the iterator. Null is ignored.
+///
+/// # Safety
+/// `iter` must be null or come from `ll_iter_new` and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn ll_iter_free(iter: *mut LlIter) {
+    if !iter.is_null() {
+        drop(Box::from_raw(iter));
+    }
+}
@@ -0,0 +1,100 @@
+//! Fuzzing support, enabled with the `arbitrary` feature.
+//! `LinkedList<T>` implements `arbitrary::Arbitrary`, so a fuzz target can take a list as its input.
+//! `Op` describes a single operation on a list, a fuzz target taking a `Vec<Op<T>>`
+//! runs an arbitrary interleaving of them against one list.
+
+use crate::LinkedList;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+// Implement Arbitrary for LinkedList
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for LinkedList<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_iter()?.collect()
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_take_rest_iter()?.collect()
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+/// Operation on a `LinkedList`, generated by the fuzzer.
+/// Indices are taken modulo the length of the list when the operation is applied,
+/// so every operation is valid on every list.
+///
+/// Example:
+/// ```
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use linked_list::fuzz::Op;
+/// use linked_list::LinkedList;
+/// let mut u = Unstructured::new(&[7, 1, 42, 0, 9, 3, 250, 18, 4, 5, 6, 77]);
+/// let ops = Vec::<Op<u8>>::arbitrary(&mut u).unwrap();
+/// let mut list = LinkedList::new();
+/// for op in ops {
+///     op.apply(&mut list);
+///     list.debug_validate();
+/// }
+/// ```
+#[derive(Arbitrary, Clone, Debug, PartialEq)]
+pub enum Op<T> {
+    /// Adds the element at the front of the list.
+    PushFront(T),
+    /// Adds the element at the back of the list.
+    PushBack(T),
+    /// Removes the first element of the list.
+    PopFront,
+    /// Removes the last element of the list.
+    PopBack,
+    /// Inserts the element at the index, modulo length plus one.
+    InsertAt(usize, T),
+    /// Removes the element at the index, modulo length.
+    RemoveAt(usize),
+    /// Splits the list after the index, modulo length, and drops the second part.
+    SplitAt(usize),
+    /// Splices the elements into the list at the index, modulo length.
+    SpliceAt(usize, Vec<T>),
+    /// Reverses the range between the two indices, modulo length plus one.
+    ReverseRange(usize, usize),
+    /// Removes all the elements of the list.
+    Clear,
+}
+
+impl<T> Op<T> {
+    /// Applies the operation to the list.
+    /// Returns the element taken out of the list by `PopFront`, `PopBack` and `RemoveAt`.
+    /// ```
+    /// use linked_list::fuzz::Op;
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// assert_eq!(Op::RemoveAt(4).apply(&mut list), Some(2));
+    /// assert_eq!(Op::InsertAt(2, 10).apply(&mut list), None);
+    /// assert_eq!(list, [1, 3, 10]);
+    /// ```
+    pub fn apply(self, list: &mut LinkedList<T>) -> Option<T> {
+        let len = list.len();
+        match self {
+            Op::PushFront(elem) => list.push_front(elem),
+            Op::PushBack(elem) => list.push_back(elem),
+            Op::PopFront => return list.pop_front(),
+            Op::PopBack => return list.pop_back(),
+            Op::InsertAt(index, elem) => list.insert_at(elem, index % (len + 1)),
+            Op::RemoveAt(index) if len > 0 => return list.remove_at(index % len).ok(),
+            Op::SplitAt(index) if len > 0 => drop(list.split_at(index % len)),
+            Op::SpliceAt(index, elems) if len > 0 => {
+                list.splice_at(elems.into_iter().collect(), index % len)
+            }
+            Op::SpliceAt(_, elems) => list.extend(elems),
+            Op::ReverseRange(a, b) => {
+                let (a, b) = (a % (len + 1), b % (len + 1));
+                list.reverse_range(a.min(b)..a.max(b));
+            }
+            Op::Clear => list.clear(),
+            // removing from or splitting an empty list does nothing
+            Op::RemoveAt(_) | Op::SplitAt(_) => {}
+        }
+        None
+    }
+}
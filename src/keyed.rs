@@ -0,0 +1,384 @@
+//! Keyed linked list.
+//! `KeyedList` keeps `(key, value)` pairs in a `LinkedList` and a `HashMap`
+//! from every key to the node holding it. Lookups, removals and moves by key
+//! go straight to the node in O(1), while the iteration order is defined by the list,
+//! which is what an LRU cache or an ordered map with reordering needs.
+
+use crate::combinatorics::Iter;
+#[cfg(feature = "fallible-alloc")]
+use crate::AllocError;
+use crate::Link;
+use crate::LinkedList;
+use crate::Node;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::hash::Hash;
+use std::mem;
+
+/// Linked list of key value pairs with a hash index on the keys.
+///
+/// Example:
+/// ```
+/// use linked_list::KeyedList;
+/// let mut lru = KeyedList::new();
+/// lru.push_back("a", 1); lru.push_back("b", 2); lru.push_back("c", 3);
+/// // touching a key moves it to the front, the back is the least recently used
+/// lru.move_key_to_front("b");
+/// assert_eq!(lru.pop_back(), Some(("c", 3)));
+/// assert!(lru.iter().eq([("b", 2), ("a", 1)].iter()));
+/// ```
+pub struct KeyedList<K, V> {
+    list: LinkedList<(K, V)>,
+    // node of every key in the list
+    index: HashMap<K, Link<(K, V)>>,
+}
+
+// The index only points into nodes owned by the list, so it is as thread safe as the list.
+unsafe impl<K: Send, V: Send> Send for KeyedList<K, V> {}
+unsafe impl<K: Sync, V: Sync> Sync for KeyedList<K, V> {}
+
+impl<K: Hash + Eq + Clone, V> KeyedList<K, V> {
+    /// Creates a new empty KeyedList.
+    /// ```
+    /// use linked_list::KeyedList;
+    /// let list: KeyedList<&str, i32> = KeyedList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            list: LinkedList::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    /// Returns the number of elements in the list. Unlike `LinkedList::len` it takes O(1) time.
+    /// ```
+    /// use linked_list::KeyedList;
+    /// let mut list = KeyedList::new();
+    /// list.push_back(1, 'a'); list.push_back(2, 'b');
+    /// assert_eq!(list.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Returns true if the list is empty.
+    /// ```
+    /// use linked_list::KeyedList;
+    /// let mut list = KeyedList::new();
+    /// assert!(list.is_empty());
+    /// list.push_back(1, 'a');
+    /// assert!(!list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// Adds the pair at the front of the list.
+    /// If the key is already present its value is replaced in place and the old value is returned,
+    /// the position of the key does not change.
+    /// ```
+    /// use linked_list::KeyedList;
+    /// let mut list = KeyedList::new();
+    /// assert_eq!(list.push_front(1, 'a'), None);
+    /// list.push_front(2, 'b');
+    /// assert_eq!(list.push_front(1, 'c'), Some('a'));
+    /// assert!(list.iter().eq([(2, 'b'), (1, 'c')].iter()));
+    /// ```
+    pub fn push_front(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&node) = self.index.get(&key) {
+            return Some(unsafe { mem::replace(&mut node.val_mut().1, value) });
+        }
+        self.list.push_front((key.clone(), value));
+        self.index.insert(key, self.list.head);
+        None
+    }
+
+    /// Adds the pair at the back of the list.
+    /// If the key is already present its value is replaced in place and the old value is returned,
+    /// the position of the key does not change.
+    /// ```
+    /// use linked_list::KeyedList;
+    /// let mut list = KeyedList::new();
+    /// assert_eq!(list.push_back(1, 'a'), None);
+    /// list.push_back(2, 'b');
+    /// assert_eq!(list.push_back(1, 'c'), Some('a'));
+    /// assert!(list.iter().eq([(1, 'c'), (2, 'b')].iter()));
+    /// ```
+    pub fn push_back(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&node) = self.index.get(&key) {
+            return Some(unsafe { mem::replace(&mut node.val_mut().1, value) });
+        }
+        self.list.push_back((key.clone(), value));
+        self.index.insert(key, self.list.tail);
+        None
+    }
+
+    /// Adds the pair at the front of the list, like `push_front`.
+    /// Returns the pair back inside `AllocError` if the node or the room for the key
+    /// in the index could not be allocated, the list is left unchanged in that case.
+    /// ```
+    /// use linked_list::KeyedList;
+    /// let mut list = KeyedList::new();
+    /// assert_eq!(list.try_push_front(1, 'a'), Ok(None));
+    /// assert_eq!(list.try_push_front(1, 'b'), Ok(Some('a')));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_push_front(&mut self, key: K, value: V) -> Result<Option<V>, AllocError<(K, V)>> {
+        if let Some(&node) = self.index.get(&key) {
+            return Ok(Some(unsafe { mem::replace(&mut node.val_mut().1, value) }));
+        }
+        if self.index.try_reserve(1).is_err() {
+            return Err(AllocError((key, value)));
+        }
+        self.list.try_push_front((key.clone(), value))?;
+        self.index.insert(key, self.list.head);
+        Ok(None)
+    }
+
+    /// Adds the pair at the back of the list, like `push_back`.
+    /// Returns the pair back inside `AllocError` if the node or the room for the key
+    /// in the index could not be allocated, the list is left unchanged in that case.
+    /// ```
+    /// use linked_list::KeyedList;
+    /// let mut list = KeyedList::new();
+    /// assert_eq!(list.try_push_back(1, 'a'), Ok(None));
+    /// assert_eq!(list.try_push_back(1, 'b'), Ok(Some('a')));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_push_back(&mut self, key: K, value: V) -> Result<Option<V>, AllocError<(K, V)>> {
+        if let Some(&node) = self.index.get(&key) {
+            return Ok(Some(unsafe { mem::replace(&mut node.val_mut().1, value) }));
+        }
+        if self.index.try_reserve(1).is_err() {
+            return Err(AllocError((key, value)));
+        }
+        self.list.try_push_back((key.clone(), value))?;
+        self.index.insert(key, self.list.tail);
+        Ok(None)
+    }
+
+    /// Returns true if the key is present in the list.
+    /// ```
+    /// use linked_list::KeyedList;
+    /// let mut list = KeyedList::new();
+    /// list.push_back("a".to_string(), 1);
+    /// assert!(list.contains_key("a"));
+    /// assert!(!list.contains_key("b"));
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.index.contains_key(key)
+    }
+
+    /// Returns the reference to the value of the key.
+    /// ```
+    /// use linked_list::KeyedList;
+    /// let mut list = KeyedList::new();
+    /// list.push_back(7, "seven");
+    /// assert_eq!(list.get_by_key(&7), Some(&"seven"));
+    /// assert_eq!(list.get_by_key(&8), None);
+    /// ```
+    pub fn get_by_key<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        let node = *self.index.get(key)?;
+        unsafe { Some(&node.val().1) }
+    }
+
+    /// Returns the mutable reference to the value of the key.
+    /// ```
+    /// use linked_list::KeyedList;
+    /// let mut list = KeyedList::new();
+    /// list.push_back(7, 1);
+    /// *list.get_by_key_mut(&7).unwrap() += 1;
+    /// assert_eq!(list.get_by_key(&7), Some(&2));
+    /// ```
+    pub fn get_by_key_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        let node = *self.index.get(key)?;
+        unsafe { Some(&mut node.val_mut().1) }
+    }
+
+    /// Removes the key from the list and returns its value.
+    /// ```
+    /// use linked_list::KeyedList;
+    /// let mut list = KeyedList::new();
+    /// list.push_back(1, 'a'); list.push_back(2, 'b'); list.push_back(3, 'c');
+    /// assert_eq!(list.remove_by_key(&2), Some('b'));
+    /// assert_eq!(list.remove_by_key(&2), None);
+    /// assert!(list.iter().eq([(1, 'a'), (3, 'c')].iter()));
+    /// ```
+    pub fn remove_by_key<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        let node = self.index.remove(key)?;
+        unsafe {
+            self.list.unlink_nodes(node, node);
+            Some(Node::free(node).val.1)
+        }
+    }
+
+    /// Moves the key to the front of the list. Returns false if the key is not present.
+    /// ```
+    /// use linked_list::KeyedList;
+    /// let mut list = KeyedList::new();
+    /// list.push_back(1, 'a'); list.push_back(2, 'b');
+    /// assert!(list.move_key_to_front(&2));
+    /// assert!(list.iter().eq([(2, 'b'), (1, 'a')].iter()));
+    /// ```
+    pub fn move_key_to_front<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        let Some(&node) = self.index.get(key) else {
+            return false;
+        };
+        // relink the same node, its address stays valid in the index
+        unsafe { self.list.unlink_nodes(node, node) };
+        self.list.link_front(node);
+        true
+    }
+
+    /// Moves the key to the back of the list. Returns false if the key is not present.
+    /// ```
+    /// use linked_list::KeyedList;
+    /// let mut list = KeyedList::new();
+    /// list.push_back(1, 'a'); list.push_back(2, 'b');
+    /// assert!(list.move_key_to_back(&1));
+    /// assert!(list.iter().eq([(2, 'b'), (1, 'a')].iter()));
+    /// ```
+    pub fn move_key_to_back<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        let Some(&node) = self.index.get(key) else {
+            return false;
+        };
+        unsafe { self.list.unlink_nodes(node, node) };
+        self.list.link_back(node);
+        true
+    }
+
+    /// Removes the first pair of the list and returns it.
+    /// ```
+    /// use linked_list::KeyedList;
+    /// let mut list = KeyedList::new();
+    /// list.push_back(1, 'a'); list.push_back(2, 'b');
+    /// assert_eq!(list.pop_front(), Some((1, 'a')));
+    /// assert!(!list.contains_key(&1));
+    /// ```
+    pub fn pop_front(&mut self) -> Option<(K, V)> {
+        let pair = self.list.pop_front()?;
+        self.index.remove(&pair.0);
+        Some(pair)
+    }
+
+    /// Removes the last pair of the list and returns it.
+    /// ```
+    /// use linked_list::KeyedList;
+    /// let mut list = KeyedList::new();
+    /// list.push_back(1, 'a'); list.push_back(2, 'b');
+    /// assert_eq!(list.pop_back(), Some((2, 'b')));
+    /// assert!(!list.contains_key(&2));
+    /// ```
+    pub fn pop_back(&mut self) -> Option<(K, V)> {
+        let pair = self.list.pop_back()?;
+        self.index.remove(&pair.0);
+        Some(pair)
+    }
+
+    /// Returns the reference to the first pair of the list.
+    /// ```
+    /// use linked_list::KeyedList;
+    /// let mut list = KeyedList::new();
+    /// list.push_back(1, 'a'); list.push_back(2, 'b');
+    /// assert_eq!(list.peek_front(), Some(&(1, 'a')));
+    /// ```
+    pub fn peek_front(&self) -> Option<&(K, V)> {
+        self.list.peek_front()
+    }
+
+    /// Returns the reference to the last pair of the list.
+    /// ```
+    /// use linked_list::KeyedList;
+    /// let mut list = KeyedList::new();
+    /// list.push_back(1, 'a'); list.push_back(2, 'b');
+    /// assert_eq!(list.peek_back(), Some(&(2, 'b')));
+    /// ```
+    pub fn peek_back(&self) -> Option<&(K, V)> {
+        self.list.peek_back()
+    }
+
+    /// Returns an iterator yielding the pairs from front to back.
+    /// ```
+    /// use linked_list::KeyedList;
+    /// let mut list = KeyedList::new();
+    /// list.push_front(1, 'a'); list.push_front(2, 'b');
+    /// assert!(list.iter().eq([(2, 'b'), (1, 'a')].iter()));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, (K, V)> {
+        self.list.iter()
+    }
+
+    /// Consumes the KeyedList and returns the underlying list.
+    /// There is no borrowing counterpart, `LinkedList` hands out `&mut` to its elements
+    /// through `&self` and a changed key would leave the index pointing at a freed node.
+    /// ```
+    /// use linked_list::{KeyedList, LinkedList};
+    /// let mut list = KeyedList::new();
+    /// list.push_back(1, 'a'); list.push_back(2, 'b');
+    /// assert_eq!(list.into_list(), LinkedList::from([(1, 'a'), (2, 'b')]));
+    /// ```
+    pub fn into_list(self) -> LinkedList<(K, V)> {
+        self.list
+    }
+}
+
+// Implement Default trait for KeyedList
+impl<K: Hash + Eq + Clone, V> Default for KeyedList<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Implement Debug trait for KeyedList
+impl<K: Debug, V: Debug> Debug for KeyedList<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_map()
+            .entries(self.list.iter().map(|(k, v)| (k, v)))
+            .finish()
+    }
+}
+
+// Implement FromIterator<(K, V)> for KeyedList
+impl<K: Hash + Eq + Clone, V> FromIterator<(K, V)> for KeyedList<K, V> {
+    /// Pairs are pushed to the back, a repeated key keeps its first position and its last value.
+    /// ```
+    /// use linked_list::KeyedList;
+    /// let list = KeyedList::from_iter([(1, 'a'), (2, 'b'), (1, 'c')]);
+    /// assert!(list.iter().eq([(1, 'c'), (2, 'b')].iter()));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut list = Self::new();
+        for (key, value) in iter {
+            list.push_back(key, value);
+        }
+        list
+    }
+}
@@ -10,6 +10,41 @@
 //! - Undefined behaviour
 //! - Memory leaks
 //! - Dangling pointers
+//!
+//! With the `miri-strict` feature every node carries a canary which is checked
+//! and poisoned when the node is freed, and the links of its neighbours are checked
+//! to point back at it. A double free or a corrupted link panics instead of going unnoticed.
+//!
+//! With the `fallible-alloc` feature the inserting methods gain a `try_` variant which returns
+//! the element back inside `AllocError` instead of aborting when memory runs out:
+//! - `LinkedList`: `try_push_front`, `try_push_back`, `try_push_front_with`, `try_push_back_with`,
+//!   `try_alloc_insert_at`, `try_extend` (for `Extend`), `try_extend_cloned` (for `Extend<&T>`),
+//!   `try_extend_from_slice`, `try_extend_front`, `try_splice_range`, `try_find_or_insert_by`,
+//!   `try_replace_sublist` and `try_apply_patch`.
+//!   `try_insert_at` is not one of them, it reports an index out of bounds.
+//! - `CursorMut`, `CursorOwned`, `PinnedCursor` and `SlabCursorMut`: `try_insert`,
+//!   `Cursors`: `try_insert_after` and `try_insert_before`.
+//! - `SlabList`: `try_push_front`, `try_push_back`, `try_alloc_insert_at`, `try_extend`,
+//!   `try_extend_cloned` and `try_extend_from_slice`, like `LinkedList`.
+//! - `UnrolledList`: `try_push_front`, `try_push_back` and `try_alloc_insert_at`,
+//!   `SortedList`: `try_insert`.
+//! - `KeyedList`, `SlotList` and `PinnedList`: `try_push_front` and `try_push_back`.
+//! - `mpsc::Queue` and `mpsc::Producer`: `try_push`.
+//!
+//! Methods building a new list (`clone`, `collect`, `map`, ...) still abort.
+//! `CowList` and `ConcurrentLinkedList` have no `try_` variants,
+//! their nodes live in `Arc`s which can not be allocated fallibly on stable Rust.
+//!
+//! With the `slab` feature `SlabList` stores all its nodes in one `Vec` linked by `u32` indices.
+//! It covers a subset of the `LinkedList` API, every method it shares has the same name,
+//! argument order and semantics. See `SlabList` for the list.
+//!
+//! `CowList` shares its nodes between snapshots and copies them only when a snapshot changes them.
+//!
+//! An async consumer of `mpsc::Queue` can await `notify_on_push` until there is an element to pop.
+//!
+//! `ConcurrentLinkedList` and `mpsc::Queue` are model checked with loom as well.
+//! `RUSTFLAGS="--cfg loom" cargo test --test loom --release`
 //! ## Methods
 //! - [] append
 //! - [] peek_back
@@ -59,6 +94,7 @@
 //! Thats lot of work already 😢
 //!
 
+use node_ptr::NodePtr;
 use std::error::Error;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
@@ -66,21 +102,72 @@ use std::fmt::{Debug, Display};
 use std::marker::PhantomData;
 
 mod combinatorics;
+mod concurrent;
+mod cow;
 mod cursors;
+mod diff;
+mod dot;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "arbitrary")]
+pub mod fuzz;
+mod keyed;
+mod list_ops;
+mod macros;
 mod methods;
+pub mod mpsc;
+mod node_box;
+mod node_ptr;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod pinned;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "slab")]
+mod slab;
+mod slice;
+mod slot;
+mod sorted;
+#[cfg(feature = "proptest")]
+pub mod strategy;
 mod traits;
+mod unrolled;
+
+pub use combinatorics::{
+    ChunkBy, Chunks, Drain, IntoChunkBy, IntoChunks, IntoIter, IntoSplit, Iter, IterMut,
+    IterMutPairs, Split, Windows,
+};
+pub use concurrent::ConcurrentLinkedList;
+pub use cow::{CowIter, CowList};
+pub use cursors::{Cursor, CursorId, CursorMut, CursorOwned, Cursors};
+pub use diff::Edit;
+pub use keyed::KeyedList;
+pub use list_ops::ListOps;
+pub use node_box::NodeBox;
+#[cfg(feature = "rayon")]
+pub use parallel::{ParIter, ParIterMut};
+pub use pinned::{PinnedCursor, PinnedList};
+#[cfg(feature = "slab")]
+pub use slab::{SlabCursor, SlabCursorMut, SlabIntoIter, SlabIter, SlabIterMut, SlabList};
+pub use slice::ListSlice;
+pub use slot::{ListKey, SlotList};
+pub use sorted::SortedList;
+pub use traits::{DebugStructure, DisplayWith};
+pub use unrolled::{UnrolledIter, UnrolledList};
+
+type Link<T> = NodePtr<T>;
 
 /// Doubly linked list.
+/// Every element lives in its own heap allocated node, so the address of an element
+/// never changes while it stays in the list. See `PinnedList` to rely on that with `Pin`.
 ///
 /// Example:
 /// ```
 /// use linked_list::LinkedList;
 /// let list: LinkedList<u32> = LinkedList::new();
 /// ```
-///
-
-type Link<T> = *const Node<T>;
-type LinkMut<T> = *mut Node<T>;
 pub struct LinkedList<T> {
     head: Link<T>,
     tail: Link<T>,
@@ -95,10 +182,8 @@ struct Node<T> {
     val: T,
     prev: Link<T>,
     next: Link<T>,
-}
-
-fn to_mut_ptr<T>(ptr: Link<T>) -> LinkMut<T> {
-    ptr as LinkMut<T>
+    #[cfg(feature = "miri-strict")]
+    canary: u64,
 }
 
 #[derive(PartialEq)]
@@ -115,6 +200,68 @@ impl Display for RemoveUnderCursorError {
 }
 impl Error for RemoveUnderCursorError {}
 
+/// Returned by the strict index based methods when the index is past the end of the list.
+pub struct IndexOutOfBounds;
+impl Debug for IndexOutOfBounds {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "IndexOutOfBounds: Index is greater than the length of the list."
+        )
+    }
+}
+impl Display for IndexOutOfBounds {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "IndexOutOfBounds: Index is greater than the length of the list."
+        )
+    }
+}
+impl Error for IndexOutOfBounds {}
+
+/// Returned by `apply_patch` when the edit script does not fit the list,
+/// that is when its `Keep` and `Delete` steps do not consume every element exactly once.
+#[derive(PartialEq)]
+pub struct PatchError;
+impl Debug for PatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "PatchError: Edit script does not match the length of the list."
+        )
+    }
+}
+impl Display for PatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "PatchError: Edit script does not match the length of the list."
+        )
+    }
+}
+impl Error for PatchError {}
+
+/// Returned by the `try_` inserting methods when memory for a new node could not be allocated.
+/// Contains the element which was supposed to be inserted.
+#[cfg(feature = "fallible-alloc")]
+#[derive(PartialEq)]
+pub struct AllocError<T>(pub T);
+#[cfg(feature = "fallible-alloc")]
+impl<T> Debug for AllocError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "AllocError: Memory allocation for a new node failed.")
+    }
+}
+#[cfg(feature = "fallible-alloc")]
+impl<T> Display for AllocError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "AllocError: Memory allocation for a new node failed.")
+    }
+}
+#[cfg(feature = "fallible-alloc")]
+impl<T> Error for AllocError<T> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,7 +275,7 @@ mod tests {
         list.push_front(MyStruct(1));
         list.push_front(MyStruct(2));
         list.push_front(MyStruct(3));
-        assert_eq!(list.contains(&MyStruct(1)), true);
+        assert!(list.contains(&MyStruct(1)));
     }
 
     #[test]
@@ -0,0 +1,232 @@
+//! Common list operations as a trait.
+//! `ListOps` is implemented by `LinkedList`, `UnrolledList` and, with the `slab` feature, `SlabList`,
+//! so code can be written once and run against any of them.
+
+use crate::combinatorics::Iter;
+#[cfg(feature = "slab")]
+use crate::slab::{SlabIter, SlabList};
+use crate::unrolled::UnrolledIter;
+use crate::LinkedList;
+use crate::UnrolledList;
+
+/// Operations shared by the list implementations of this crate.
+///
+/// Example:
+/// ```
+/// use linked_list::{LinkedList, ListOps, UnrolledList};
+/// fn fill<L: ListOps<i32> + Default>() -> L {
+///     let mut list = L::default();
+///     list.push_back(2); list.push_front(0);
+///     list.insert_at(1, 1);
+///     list
+/// }
+/// assert!(fill::<LinkedList<i32>>().iter().eq([0, 1, 2].iter()));
+/// assert!(fill::<UnrolledList<i32, 2>>().iter().eq([0, 1, 2].iter()));
+/// ```
+pub trait ListOps<T> {
+    /// Iterator yielding &T from front to back.
+    type Iter<'a>: DoubleEndedIterator<Item = &'a T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize;
+
+    /// Returns true if the list is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds an element onto the front of the list.
+    fn push_front(&mut self, elem: T);
+
+    /// Adds an element onto the back of the list.
+    fn push_back(&mut self, elem: T);
+
+    /// Removes the first element of the list and returns it.
+    fn pop_front(&mut self) -> Option<T>;
+
+    /// Removes the last element of the list and returns it.
+    fn pop_back(&mut self) -> Option<T>;
+
+    /// Returns the reference to the first element of the list.
+    fn peek_front(&self) -> Option<&T>;
+
+    /// Returns the reference to the last element of the list.
+    fn peek_back(&self) -> Option<&T>;
+
+    /// Returns an iterator yielding &T from front to back.
+    fn iter(&self) -> Self::Iter<'_>;
+
+    /// Insert an element at a given index, like `LinkedList::insert_at`.
+    /// Note: Final index at the list will wrap around when length of the list is lesser.
+    fn insert_at(&mut self, elem: T, index: usize);
+
+    /// Removes the element at the given index and returns it.
+    /// Returns `None` if the index does not exist.
+    fn remove(&mut self, index: usize) -> Option<T>;
+}
+
+// Implement ListOps for LinkedList
+impl<T> ListOps<T> for LinkedList<T> {
+    type Iter<'a>
+        = Iter<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        LinkedList::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        LinkedList::is_empty(self)
+    }
+
+    fn push_front(&mut self, elem: T) {
+        LinkedList::push_front(self, elem)
+    }
+
+    fn push_back(&mut self, elem: T) {
+        LinkedList::push_back(self, elem)
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        LinkedList::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        LinkedList::pop_back(self)
+    }
+
+    fn peek_front(&self) -> Option<&T> {
+        LinkedList::peek_front(self)
+    }
+
+    fn peek_back(&self) -> Option<&T> {
+        LinkedList::peek_back(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        LinkedList::iter(self)
+    }
+
+    fn insert_at(&mut self, elem: T, index: usize) {
+        LinkedList::insert_at(self, elem, index)
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+        self.remove_at(index).ok()
+    }
+}
+
+// Implement ListOps for UnrolledList
+impl<T, const CHUNK: usize> ListOps<T> for UnrolledList<T, CHUNK> {
+    type Iter<'a>
+        = UnrolledIter<'a, T, CHUNK>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        UnrolledList::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        UnrolledList::is_empty(self)
+    }
+
+    fn push_front(&mut self, elem: T) {
+        UnrolledList::push_front(self, elem)
+    }
+
+    fn push_back(&mut self, elem: T) {
+        UnrolledList::push_back(self, elem)
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        UnrolledList::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        UnrolledList::pop_back(self)
+    }
+
+    fn peek_front(&self) -> Option<&T> {
+        UnrolledList::peek_front(self)
+    }
+
+    fn peek_back(&self) -> Option<&T> {
+        UnrolledList::peek_back(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        UnrolledList::iter(self)
+    }
+
+    fn insert_at(&mut self, elem: T, index: usize) {
+        UnrolledList::insert_at(self, elem, index)
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        UnrolledList::remove(self, index)
+    }
+}
+
+// Implement ListOps for SlabList
+#[cfg(feature = "slab")]
+impl<T> ListOps<T> for SlabList<T> {
+    type Iter<'a>
+        = SlabIter<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        SlabList::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        SlabList::is_empty(self)
+    }
+
+    fn push_front(&mut self, elem: T) {
+        SlabList::push_front(self, elem)
+    }
+
+    fn push_back(&mut self, elem: T) {
+        SlabList::push_back(self, elem)
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        SlabList::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        SlabList::pop_back(self)
+    }
+
+    fn peek_front(&self) -> Option<&T> {
+        SlabList::peek_front(self)
+    }
+
+    fn peek_back(&self) -> Option<&T> {
+        SlabList::peek_back(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        SlabList::iter(self)
+    }
+
+    fn insert_at(&mut self, elem: T, index: usize) {
+        SlabList::insert_at(self, elem, index)
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+        self.remove_at(index).ok()
+    }
+}
@@ -0,0 +1,34 @@
+/// Creates a `LinkedList` containing the arguments, like `vec!`.
+/// ```
+/// use linked_list::{linked_list, LinkedList};
+/// let list = linked_list![1, 2, 3];
+/// assert_eq!(list, LinkedList::from([1, 2, 3]));
+/// let list = linked_list!['a'; 3];
+/// assert_eq!(list, LinkedList::from(['a', 'a', 'a']));
+/// let list: LinkedList<i32> = linked_list![];
+/// assert!(list.is_empty());
+/// ```
+#[macro_export]
+macro_rules! linked_list {
+    () => {
+        $crate::LinkedList::new()
+    };
+    ($elem:expr; $n:expr) => {{
+        let mut list = $crate::LinkedList::new();
+        let n: usize = $n;
+        let elem = $elem;
+        // clone for all but the last element, which takes the value itself
+        if n > 0 {
+            for _ in 1..n {
+                list.push_back(::std::clone::Clone::clone(&elem));
+            }
+            list.push_back(elem);
+        }
+        list
+    }};
+    ($($elem:expr),+ $(,)?) => {{
+        let mut list = $crate::LinkedList::new();
+        $(list.push_back($elem);)+
+        list
+    }};
+}
@@ -1,18 +1,156 @@
-use crate::to_mut_ptr;
+#[cfg(feature = "fallible-alloc")]
+use crate::AllocError;
+use crate::IndexOutOfBounds;
+use crate::Link;
 use crate::LinkedList;
 use crate::Node;
 use crate::RemoveUnderCursorError;
+#[cfg(feature = "fallible-alloc")]
+use std::alloc::{alloc, Layout};
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::hash::Hash;
 use std::marker::PhantomData;
+use std::mem;
+use std::mem::MaybeUninit;
+use std::ops::{Bound, RangeBounds};
 use std::ptr;
 
+// Converts the range into `start..end` indices of a sequence of `len` elements.
+// Panics if the range is out of bounds or its start is greater than its end.
+pub(crate) fn range_indices<R>(range: R, len: usize) -> (usize, usize)
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end, "range start is greater than range end");
+    assert!(end <= len, "range end out of bounds");
+    (start, end)
+}
+
+// canary values of a node with the `miri-strict` feature
+#[cfg(feature = "miri-strict")]
+pub(crate) const NODE_LIVE: u64 = 0x4c49_5645_4e4f_4445;
+#[cfg(feature = "miri-strict")]
+const NODE_FREED: u64 = 0x4652_4545_4e4f_4445;
+
 impl<T> Node<T> {
     // creates a new instance of Node
     // prev and next pointers are initialized with null values
     pub(crate) fn new(val: T) -> Self {
         Self {
             val,
-            prev: ptr::null(),
-            next: ptr::null(),
+            prev: Link::null(),
+            next: Link::null(),
+            #[cfg(feature = "miri-strict")]
+            canary: NODE_LIVE,
+        }
+    }
+
+    // allocates a new node in the heap and returns the raw pointer to it.
+    // every node of a list is allocated here or in `try_new_raw`
+    // and must be freed exactly once with `free`.
+    pub(crate) fn new_raw(val: T) -> Link<T> {
+        // Box::new method will allocate the memory in the heap
+        // Link::from_box will provide the raw pointer of the allocated memory
+        Link::from_box(Box::new(Self::new(val)))
+    }
+
+    // allocates the node first and writes the value returned by `f` straight into it,
+    // so a large value does not have to be moved into the node through the stack.
+    // if `f` panics the allocation is freed and nothing else is dropped.
+    pub(crate) fn new_raw_with(f: impl FnOnce() -> T) -> Link<T> {
+        Self::init_with(Box::<Self>::new_uninit(), f)
+    }
+
+    // same as `new_raw_with` but without aborting on failure,
+    // the closure is handed back inside the error if the allocation fails.
+    #[cfg(feature = "fallible-alloc")]
+    pub(crate) fn try_new_raw_with<F: FnOnce() -> T>(f: F) -> Result<Link<T>, AllocError<F>> {
+        let layout = Layout::new::<Self>();
+        unsafe {
+            let raw = alloc(layout) as *mut MaybeUninit<Self>;
+            if raw.is_null() {
+                return Err(AllocError(f));
+            }
+            // the memory has the layout of a node, so the Box frees it if `f` panics
+            Ok(Self::init_with(Box::from_raw(raw), f))
+        }
+    }
+
+    // writes the value returned by `f` and null links into an allocated node
+    fn init_with(mut node: Box<MaybeUninit<Self>>, f: impl FnOnce() -> T) -> Link<T> {
+        let raw = node.as_mut_ptr();
+        unsafe {
+            ptr::addr_of_mut!((*raw).val).write(f());
+            ptr::addr_of_mut!((*raw).prev).write(Link::null());
+            ptr::addr_of_mut!((*raw).next).write(Link::null());
+            #[cfg(feature = "miri-strict")]
+            ptr::addr_of_mut!((*raw).canary).write(NODE_LIVE);
+            Link::from_box(node.assume_init())
+        }
+    }
+
+    // takes back the ownership of a node allocated by `new_raw` or `try_new_raw`,
+    // the node is freed when the returned Box is dropped.
+    // SAFETY: `link` must point to a live node which is not freed afterwards through any other pointer.
+    // the node must still be linked to its neighbours, they are checked with `miri-strict`.
+    pub(crate) unsafe fn free(link: Link<T>) -> Box<Self> {
+        #[cfg(feature = "miri-strict")]
+        Self::check(link);
+        // SAFETY: the node was allocated by `Box::new` (or with the same layout)
+        // and the caller guarantees it is owned by nobody else
+        #[allow(unused_mut)]
+        let mut node = link.into_box();
+        // poison the canary so that a second free of the same node is caught
+        #[cfg(feature = "miri-strict")]
+        {
+            node.canary = NODE_FREED;
+        }
+        node
+    }
+
+    // panics if the node was already freed or its neighbours do not point back at it.
+    // SAFETY: `link` must point to memory allocated for a node.
+    #[cfg(feature = "miri-strict")]
+    unsafe fn check(link: Link<T>) {
+        // the accessors of the link panic if the canary is not live anymore
+        assert!(
+            link.prev().is_null() || link.prev().next() == link,
+            "miri-strict: next of the previous node does not point back at the node"
+        );
+        assert!(
+            link.next().is_null() || link.next().prev() == link,
+            "miri-strict: prev of the next node does not point back at the node"
+        );
+    }
+
+    // allocates a new node in the heap without aborting on failure
+    // and returns the raw pointer to it.
+    // the value is handed back inside the error if the allocation fails.
+    // memory comes from the global allocator with the layout of `Node<T>`,
+    // so it can be freed with `free` like every other node.
+    #[cfg(feature = "fallible-alloc")]
+    pub(crate) fn try_new_raw(val: T) -> Result<Link<T>, AllocError<T>> {
+        let layout = Layout::new::<Self>();
+        unsafe {
+            let new_node = alloc(layout) as *mut Self;
+            if new_node.is_null() {
+                return Err(AllocError(val));
+            }
+            new_node.write(Self::new(val));
+            Ok(Link::from_raw(new_node))
         }
     }
 }
@@ -26,12 +164,54 @@ impl<T> LinkedList<T> {
     /// ```
     pub fn new() -> Self {
         Self {
-            head: ptr::null(),
-            tail: ptr::null(),
+            head: Link::null(),
+            tail: Link::null(),
             _phantom: PhantomData,
         }
     }
 
+    /// Consumes the list and returns its raw parts: the head node, the tail node and the length.
+    /// The node pointers are opaque and null for an empty list.
+    /// The nodes are leaked until the parts are turned back into a list with `from_raw_parts`.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// let (head, tail, len) = list.into_raw_parts();
+    /// assert_eq!(len, 3);
+    /// let list = unsafe { LinkedList::<i32>::from_raw_parts(head, tail, len) };
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    pub fn into_raw_parts(self) -> (*mut c_void, *mut c_void, usize) {
+        let len = self.len();
+        // the nodes are handed over to the caller, the list must not free them
+        let list = mem::ManuallyDrop::new(self);
+        (list.head.as_ptr().cast(), list.tail.as_ptr().cast(), len)
+    }
+
+    /// Creates a list from raw parts returned by `into_raw_parts`.
+    /// In debug builds the length is checked against the number of nodes.
+    ///
+    /// # Safety
+    /// The parts must come from one call of `into_raw_parts` on a `LinkedList<T>` with the same `T`,
+    /// and must be turned back into a list only once. The nodes must not have been accessed
+    /// or changed in between, other than by moving the pointers around.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let (head, tail, len) = LinkedList::<String>::new().into_raw_parts();
+    /// assert!(head.is_null() && tail.is_null());
+    /// let list = unsafe { LinkedList::<String>::from_raw_parts(head, tail, len) };
+    /// assert!(list.is_empty());
+    /// ```
+    pub unsafe fn from_raw_parts(head: *mut c_void, tail: *mut c_void, len: usize) -> Self {
+        let list = Self {
+            head: Link::from_raw(head.cast()),
+            tail: Link::from_raw(tail.cast()),
+            _phantom: PhantomData,
+        };
+        debug_assert_eq!(list.len(), len, "length does not match the raw parts");
+        list
+    }
+
     /// Returns the length of the liked list.
     /// ```
     /// use linked_list::LinkedList;
@@ -50,13 +230,116 @@ impl<T> LinkedList<T> {
         while !curr.is_null() {
             count += 1;
             unsafe {
-                curr = (*curr).next;
+                curr = curr.next();
             }
         }
 
         count
     }
 
+    /// Walks the list and checks its structural invariants, panics with a description of
+    /// the first broken one. Meant for debugging code which relinks nodes.
+    /// Takes O(n) time, call it from tests or behind `cfg(debug_assertions)`.
+    /// Checks that
+    /// - head and tail are either both null or both set, with no node before head and none after tail
+    /// - `prev` of every next node points back at the node
+    /// - the walk from head ends at tail without running into a cycle
+    /// - walking forward and backward counts the same number of nodes
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list: LinkedList<i32> = (0..5).collect();
+    /// let back = list.split_at(2);
+    /// list.debug_validate();
+    /// back.debug_validate();
+    /// ```
+    pub fn debug_validate(&self) {
+        if self.head.is_null() || self.tail.is_null() {
+            assert!(
+                self.head.is_null() && self.tail.is_null(),
+                "debug_validate: only one of head and tail is null"
+            );
+            return;
+        }
+        unsafe {
+            assert!(
+                self.head.prev().is_null(),
+                "debug_validate: prev of the head node is not null"
+            );
+            assert!(
+                self.tail.next().is_null(),
+                "debug_validate: next of the tail node is not null"
+            );
+            // Every node reached twice would need two nodes whose next points at it,
+            // so with the back links checked a cycle shows up as a broken back link,
+            // or as a node linking back to head whose prev must be null.
+            let mut forward = 1;
+            let mut curr = self.head;
+            while !curr.next().is_null() {
+                let next = curr.next();
+                assert!(
+                    next != self.head,
+                    "debug_validate: cycle back to the head node after {} nodes",
+                    forward
+                );
+                assert!(
+                    next.prev() == curr,
+                    "debug_validate: prev of node {} does not point back at node {}",
+                    forward,
+                    forward - 1
+                );
+                curr = next;
+                forward += 1;
+            }
+            assert!(
+                curr == self.tail,
+                "debug_validate: walk from head ends after {} nodes without reaching tail",
+                forward
+            );
+            let mut backward = 1;
+            let mut curr = self.tail;
+            while !curr.prev().is_null() && backward <= forward {
+                curr = curr.prev();
+                backward += 1;
+            }
+            assert!(
+                forward == backward && curr == self.head,
+                "debug_validate: {} nodes from head to tail but {} nodes from tail to head",
+                forward,
+                backward
+            );
+        }
+    }
+
+    /// Returns the number of bytes allocated on the heap for the nodes of the list.
+    /// Heap memory owned by the elements themselves is not included, see `heap_size_with`.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1u64, 2, 3]);
+    /// // every node holds at least the value and two pointers
+    /// assert!(list.heap_size() >= 3 * (8 + 2 * std::mem::size_of::<usize>()));
+    /// assert_eq!(LinkedList::<u64>::new().heap_size(), 0);
+    /// ```
+    pub fn heap_size(&self) -> usize {
+        self.heap_size_with(|_| 0)
+    }
+
+    /// Returns the number of bytes allocated on the heap for the nodes of the list
+    /// plus the heap usage reported by `f` for every element.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([String::with_capacity(10), String::with_capacity(20)]);
+    /// let nodes = list.heap_size();
+    /// assert_eq!(list.heap_size_with(|s| s.capacity()), nodes + 30);
+    /// ```
+    pub fn heap_size_with<F>(&self, mut f: F) -> usize
+    where
+        F: FnMut(&T) -> usize,
+    {
+        self.iter()
+            .map(|elem| mem::size_of::<Node<T>>() + f(elem))
+            .sum()
+    }
+
     /// Returns true if the list is empty.
     /// ```
     /// use linked_list::LinkedList;
@@ -85,232 +368,1519 @@ impl<T> LinkedList<T> {
     }
 
     /// Returns true if the list contains the given value otherwise false.
+    /// The value can be any borrowed form of the element type, like `&str` for `String`.
     /// ```
     /// use linked_list::LinkedList;
     /// let list = [1, 2, 3].into_iter().collect::<LinkedList<i32>>();
     /// assert_eq!(list.contains(&3), true);
     /// assert_eq!(list.contains(&4), false);
+    /// let list = LinkedList::from([String::from("foo")]);
+    /// assert!(list.contains("foo"));
     /// ```
-    pub fn contains(&self, item: &T) -> bool
+    pub fn contains<Q>(&self, item: &Q) -> bool
     where
-        T: PartialEq,
+        T: Borrow<Q>,
+        Q: ?Sized + PartialEq,
     {
-        self.iter().any(|x| x == item)
+        self.iter().any(|x| x.borrow() == item)
     }
 
-    /// Adds a new node onto the front of the list.
-    /// `head` pointer will point to the newly created node after this operation.
+    /// Returns the number of elements equal to the given value.
+    /// The value can be any borrowed form of the element type.
     /// ```
     /// use linked_list::LinkedList;
-    /// let mut list = LinkedList::new();
-    /// list.push_front(2);
+    /// let list = LinkedList::from([1, 2, 1, 3, 1]);
+    /// assert_eq!(list.count_of(&1), 3);
+    /// assert_eq!(list.count_of(&4), 0);
     /// ```
-    pub fn push_front(&mut self, elem: T) {
-        // create a new node with elem
-        // create a raw pointer with the Node
-        // Box::new method will allocate the memory in the heap
-        // Box::into_raw method will provide the raw pointer of the allocated memory
-        let new_node = Box::into_raw(Box::new(Node::new(elem)));
+    pub fn count_of<Q>(&self, item: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + PartialEq,
+    {
+        self.count_by(|x| x.borrow() == item)
+    }
+
+    /// Returns the number of elements matching the predicate.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// assert_eq!(list.count_by(|x| x % 2 == 1), 3);
+    /// ```
+    pub fn count_by<F>(&self, mut pred: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().filter(|x| pred(x)).count()
+    }
+
+    /// Returns the index of the first element equal to the given value.
+    /// The value can be any borrowed form of the element type.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([String::from("a"), String::from("b")]);
+    /// assert_eq!(list.position("b"), Some(1));
+    /// assert_eq!(list.position("c"), None);
+    /// ```
+    pub fn position<Q>(&self, item: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + PartialEq,
+    {
+        self.iter().position(|x| x.borrow() == item)
+    }
+
+    /// Returns the index of the first element equal to the given value.
+    /// Same as `position`, paired with `rindex_of` searching from the back.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 1, 3]);
+    /// assert_eq!(list.index_of(&1), Some(0));
+    /// assert_eq!(list.index_of(&4), None);
+    /// ```
+    pub fn index_of<Q>(&self, item: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + PartialEq,
+    {
+        self.position(item)
+    }
+
+    /// Returns the index of the last element equal to the given value.
+    /// The value can be any borrowed form of the element type.
+    /// The search walks backward from the tail, so the elements behind the match are never compared.
+    /// It only skips comparisons, not traversal: the index is the distance from the tail
+    /// subtracted from `len`, which walks the whole list.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 1, 3]);
+    /// assert_eq!(list.rindex_of(&1), Some(2));
+    /// assert_eq!(list.rindex_of(&4), None);
+    /// ```
+    #[doc(alias = "rposition")]
+    pub fn rindex_of<Q>(&self, item: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + PartialEq,
+    {
+        let mut curr = self.tail;
+        // number of nodes behind the current one
+        let mut from_tail = 0;
         unsafe {
-            // set current head as the next of new_node
-            (*new_node).next = self.head;
+            // find the last node holding an equal value
+            while !curr.is_null() && curr.val().borrow() != item {
+                curr = curr.prev();
+                from_tail += 1;
+            }
         }
-        // if head is null that means list is empty
-        // tail will also point to new_node.
-        if self.head.is_null() {
-            self.tail = new_node;
-        } else {
+        if curr.is_null() {
+            return None;
+        }
+        Some(self.len() - 1 - from_tail)
+    }
+
+    /// Removes the first element equal to the given value and returns it.
+    /// The value can be any borrowed form of the element type.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([String::from("a"), String::from("b"), String::from("a")]);
+    /// assert_eq!(list.remove_item("a"), Some(String::from("a")));
+    /// assert_eq!(list.remove_item("c"), None);
+    /// assert_eq!(list.position("a"), Some(1));
+    /// assert_eq!(list.len(), 2);
+    /// ```
+    pub fn remove_item<Q>(&mut self, item: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + PartialEq,
+    {
+        self.remove_by(|elem| elem.borrow() == item)
+    }
+
+    /// Removes the first element matching the predicate and returns it.
+    /// The list is walked once, the matching node is unlinked where it was found.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut jobs = LinkedList::from([(1, "low"), (2, "high"), (3, "high")]);
+    /// assert_eq!(jobs.remove_by(|&(_, priority)| priority == "high"), Some((2, "high")));
+    /// assert_eq!(jobs.remove_by(|&(id, _)| id > 5), None);
+    /// assert_eq!(jobs, [(1, "low"), (3, "high")]);
+    /// ```
+    pub fn remove_by<F>(&mut self, mut pred: F) -> Option<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut curr = self.head;
+        unsafe {
+            // find the first node holding a matching value
+            while !curr.is_null() && !pred(curr.val()) {
+                curr = curr.next();
+            }
+            if curr.is_null() {
+                return None;
+            }
+            self.unlink_nodes(curr, curr);
+            Some(Node::free(curr).val)
+        }
+    }
+
+    /// Removes every element equal to the given value in a single pass
+    /// and returns the number of removed elements.
+    /// The value can be any borrowed form of the element type.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 1, 3, 1]);
+    /// assert_eq!(list.remove_all(&1), 3);
+    /// assert_eq!(list.remove_all(&4), 0);
+    /// assert_eq!(list, [2, 3]);
+    /// ```
+    pub fn remove_all<Q>(&mut self, item: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + PartialEq,
+    {
+        let mut removed = 0;
+        let mut curr = self.head;
+        unsafe {
+            while !curr.is_null() {
+                // remember the next node before the current one is freed
+                let next = curr.next();
+                if curr.val().borrow() == item {
+                    self.unlink_nodes(curr, curr);
+                    drop(Node::free(curr));
+                    removed += 1;
+                }
+                curr = next;
+            }
+        }
+        removed
+    }
+
+    /// Replaces every element equal to `old` with a clone of `new`
+    /// and returns the number of replaced elements.
+    /// The values are swapped in place, no node is relinked.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from(["a", "b", "a"].map(String::from));
+    /// assert_eq!(list.replace_all("a", String::from("c")), 2);
+    /// assert_eq!(list, ["c", "b", "c"].map(String::from));
+    /// ```
+    pub fn replace_all<Q>(&mut self, old: &Q, new: T) -> usize
+    where
+        T: Borrow<Q> + Clone,
+        Q: ?Sized + PartialEq,
+    {
+        let mut replaced = 0;
+        for elem in self.iter_mut() {
+            if (*elem).borrow() == old {
+                *elem = new.clone();
+                replaced += 1;
+            }
+        }
+        replaced
+    }
+
+    /// Returns true if the list contains exactly the same elements as the given iterable.
+    /// Comparison stops at the first mismatch.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// assert!(list.eq_iter([1, 2, 3]));
+    /// assert!(list.eq_iter(&[1, 2, 3]));
+    /// assert!(!list.eq_iter(vec![1, 2]));
+    /// assert!(!list.eq_iter(1..5));
+    /// ```
+    pub fn eq_iter<I, B>(&self, iter: I) -> bool
+    where
+        T: PartialEq,
+        I: IntoIterator<Item = B>,
+        B: Borrow<T>,
+    {
+        let mut iter = iter.into_iter();
+        // compare element by element and bail out on the first mismatch
+        for elem in self.iter() {
+            match iter.next() {
+                Some(other) if elem == other.borrow() => {}
+                _ => return false,
+            }
+        }
+        // the given iterable must not have any more elements left
+        iter.next().is_none()
+    }
+
+    /// Returns true if the elements of the needle occur as a contiguous run in the list.
+    /// The needle can be another list or anything iterable, like a slice or an array.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3, 4]);
+    /// assert!(list.contains_sublist(&LinkedList::from([2, 3])));
+    /// assert!(list.contains_sublist([3, 4]));
+    /// assert!(!list.contains_sublist(&[1, 3]));
+    /// ```
+    pub fn contains_sublist<I, B>(&self, needle: I) -> bool
+    where
+        T: PartialEq,
+        I: IntoIterator<Item = B>,
+        B: Borrow<T>,
+    {
+        self.find_sublist(needle).is_some()
+    }
+
+    /// Returns the index where the elements of the needle first occur as a contiguous run in the list.
+    /// An empty needle is found at index 0. Takes O(n * m) time for a needle of length m.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from(['a', 'b', 'a', 'b', 'c']);
+    /// assert_eq!(list.find_sublist(['a', 'b', 'c']), Some(2));
+    /// assert_eq!(list.find_sublist(&LinkedList::from(['b', 'a'])), Some(1));
+    /// assert_eq!(list.find_sublist(['c', 'a']), None);
+    /// ```
+    pub fn find_sublist<I, B>(&self, needle: I) -> Option<usize>
+    where
+        T: PartialEq,
+        I: IntoIterator<Item = B>,
+        B: Borrow<T>,
+    {
+        let needle = needle.into_iter().collect::<Vec<_>>();
+        let mut curr = self.head;
+        let mut index = 0;
+        loop {
+            if unsafe { Self::match_sublist(curr, &needle) }.is_some() {
+                return Some(index);
+            }
+            if curr.is_null() {
+                return None;
+            }
+            curr = unsafe { curr.next() };
+            index += 1;
+        }
+    }
+
+    // Checks if the needle matches the nodes starting at `first`.
+    // Returns the node after the matched run, which is null when the run ends at the tail.
+    // `first` may be null, then only an empty needle matches.
+    pub(crate) unsafe fn match_sublist<B>(first: Link<T>, needle: &[B]) -> Option<Link<T>>
+    where
+        T: PartialEq,
+        B: Borrow<T>,
+    {
+        let mut curr = first;
+        for elem in needle {
+            if curr.is_null() || *curr.val() != *elem.borrow() {
+                return None;
+            }
+            curr = curr.next();
+        }
+        Some(curr)
+    }
+
+    /// Binary searches a sorted list for the given element.
+    /// Returns `Ok` with the index of a matching element,
+    /// or `Err` with the index where the element could be inserted to keep the list sorted.
+    /// Only O(log n) comparisons are made, although reaching the nodes still takes O(n) steps.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 3, 5, 7]);
+    /// assert_eq!(list.binary_search(&5), Ok(2));
+    /// assert_eq!(list.binary_search(&4), Err(2));
+    /// assert_eq!(list.binary_search(&8), Err(4));
+    /// ```
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.binary_search_by(|elem| elem.cmp(x))
+    }
+
+    /// Binary searches a sorted list with a comparator function.
+    /// The comparator returns the order of the element relative to the target.
+    /// Returns the same as `binary_search`.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([(1, 'a'), (3, 'b'), (5, 'c')]);
+    /// assert_eq!(list.binary_search_by(|(k, _)| k.cmp(&3)), Ok(1));
+    /// assert_eq!(list.binary_search_by(|(k, _)| k.cmp(&0)), Err(0));
+    /// ```
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        // the search range starts at index `base` with the node `base_node` and has `size` nodes
+        let mut base = 0;
+        let mut base_node = self.head;
+        let mut size = self.len();
+        while size > 0 {
+            let half = size / 2;
             unsafe {
-                // otherwise `prev` of current head will point to new_node
-                (*to_mut_ptr(self.head)).prev = new_node;
+                // walk to the middle node of the range
+                let mut mid = base_node;
+                (0..half).for_each(|_| mid = mid.next());
+                match f(mid.val()) {
+                    Ordering::Equal => return Ok(base + half),
+                    // search the part after the middle node
+                    Ordering::Less => {
+                        base += half + 1;
+                        base_node = mid.next();
+                        size -= half + 1;
+                    }
+                    // search the part before the middle node
+                    Ordering::Greater => size = half,
+                }
             }
         }
-        // and head will be set to new_node
-        self.head = new_node;
+        Err(base)
+    }
+
+    /// Walks a sorted list and returns the mutable reference to the first element
+    /// for which the comparator returns `Ordering::Equal`.
+    /// The comparator returns the order of the element relative to the target, like `binary_search_by`.
+    /// If no element is equal, a new one created by `default` is inserted before the first greater element
+    /// and the reference to it is returned. The list is walked only once.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([(1, 1), (4, 1)]);
+    /// for key in [4, 2, 4, 5] {
+    ///     list.find_or_insert_by(|(k, _)| k.cmp(&key), || (key, 0)).1 += 1;
+    /// }
+    /// assert!(list.iter().eq([(1, 1), (2, 1), (4, 3), (5, 1)].iter()));
+    /// ```
+    pub fn find_or_insert_by<F, D>(&mut self, mut f: F, default: D) -> &mut T
+    where
+        F: FnMut(&T) -> Ordering,
+        D: FnOnce() -> T,
+    {
+        let mut curr = self.head;
+        unsafe {
+            while !curr.is_null() {
+                match f(curr.val()) {
+                    Ordering::Equal => return curr.val_mut(),
+                    Ordering::Greater => break,
+                    Ordering::Less => curr = curr.next(),
+                }
+            }
+            // no equal element, curr is the first greater node or null if there is none
+            if curr.is_null() {
+                self.push_back(default());
+                return self.tail.val_mut();
+            }
+            if curr == self.head {
+                self.push_front(default());
+                return self.head.val_mut();
+            }
+            // link the new node between curr and its prev
+            let new_node = Node::new_raw(default());
+            let prev = curr.prev();
+            new_node.set_prev(prev);
+            new_node.set_next(curr);
+            prev.set_next(new_node);
+            curr.set_prev(new_node);
+            new_node.val_mut()
+        }
+    }
+
+    /// Walks a sorted list and returns the mutable reference to the first element equal to the target,
+    /// or inserts a new one created by `default`, like `find_or_insert_by`.
+    /// Returns the new element back inside `AllocError` if its node could not be allocated,
+    /// the list is left unchanged in that case.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([(1, 1), (4, 1)]);
+    /// for key in [4, 2] {
+    ///     list.try_find_or_insert_by(|(k, _)| k.cmp(&key), || (key, 0)).unwrap().1 += 1;
+    /// }
+    /// assert!(list.iter().eq([(1, 1), (2, 1), (4, 2)].iter()));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_find_or_insert_by<F, D>(
+        &mut self,
+        mut f: F,
+        default: D,
+    ) -> Result<&mut T, AllocError<T>>
+    where
+        F: FnMut(&T) -> Ordering,
+        D: FnOnce() -> T,
+    {
+        let mut curr = self.head;
+        unsafe {
+            while !curr.is_null() {
+                match f(curr.val()) {
+                    Ordering::Equal => return Ok(curr.val_mut()),
+                    Ordering::Greater => break,
+                    Ordering::Less => curr = curr.next(),
+                }
+            }
+            // no equal element, curr is the first greater node or null if there is none
+            let new_node = Node::try_new_raw(default())?;
+            if curr.is_null() {
+                self.link_back(new_node);
+            } else if curr == self.head {
+                self.link_front(new_node);
+            } else {
+                // link the new node between curr and its prev
+                let prev = curr.prev();
+                new_node.set_prev(prev);
+                new_node.set_next(curr);
+                prev.set_next(new_node);
+                curr.set_prev(new_node);
+            }
+            Ok(new_node.val_mut())
+        }
+    }
+
+    /// Returns the number of leading elements both lists have in common.
+    /// Both lists are walked from the head until the first mismatch.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3, 4]);
+    /// assert_eq!(list.longest_common_prefix(&LinkedList::from([1, 2, 5])), 2);
+    /// assert_eq!(list.longest_common_prefix(&LinkedList::from([0])), 0);
+    /// assert_eq!(list.longest_common_prefix(&list.clone()), 4);
+    /// ```
+    pub fn longest_common_prefix(&self, other: &Self) -> usize
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .zip(other.iter())
+            .take_while(|(a, b)| a == b)
+            .count()
+    }
+
+    /// Returns the number of trailing elements both lists have in common.
+    /// Both lists are walked from the tail until the first mismatch.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3, 4]);
+    /// assert_eq!(list.longest_common_suffix(&LinkedList::from([0, 3, 4])), 2);
+    /// assert_eq!(list.longest_common_suffix(&LinkedList::from([1])), 0);
+    /// assert_eq!(list.longest_common_suffix(&list.clone()), 4);
+    /// ```
+    pub fn longest_common_suffix(&self, other: &Self) -> usize
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .rev()
+            .zip(other.iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count()
+    }
+
+    /// Returns true if both lists contain the same elements the same number of times, in any order.
+    /// Elements are counted in a hash map, so it takes O(n) time.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 2, 3]);
+    /// assert!(list.is_permutation_of(&LinkedList::from([2, 3, 1, 2])));
+    /// assert!(!list.is_permutation_of(&LinkedList::from([1, 2, 3, 3])));
+    /// ```
+    pub fn is_permutation_of(&self, other: &Self) -> bool
+    where
+        T: Hash + Eq,
+    {
+        let mut counts = HashMap::new();
+        // count up the elements of this list and count down the ones of the other list
+        for elem in self.iter() {
+            *counts.entry(elem).or_insert(0usize) += 1;
+        }
+        for elem in other.iter() {
+            match counts.get_mut(elem) {
+                Some(count) if *count > 0 => *count -= 1,
+                _ => return false,
+            }
+        }
+        counts.values().all(|count| *count == 0)
+    }
+
+    /// Returns true if both lists contain the same elements the same number of times, in any order.
+    /// Fallback of `is_permutation_of` for elements which are only `PartialEq`, it takes O(n²) time.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([0.5, 1.5, 0.5]);
+    /// assert!(list.is_permutation_of_by_eq(&LinkedList::from([0.5, 0.5, 1.5])));
+    /// assert!(!list.is_permutation_of_by_eq(&LinkedList::from([0.5, 1.5, 1.5])));
+    /// ```
+    pub fn is_permutation_of_by_eq(&self, other: &Self) -> bool
+    where
+        T: PartialEq,
+    {
+        // every element must occur equally often in both lists
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|elem| self.count_by(|x| x == elem) == other.count_by(|x| x == elem))
+    }
+
+    /// Returns true if the list reads the same from front to back and from back to front.
+    /// Both ends are walked towards the middle, nothing is allocated.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// assert!(LinkedList::from(['r', 'a', 'c', 'e', 'c', 'a', 'r']).is_palindrome());
+    /// assert!(!LinkedList::from([1, 2]).is_palindrome());
+    /// assert!(LinkedList::<i32>::new().is_palindrome());
+    /// ```
+    pub fn is_palindrome(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        let mut iter = self.iter();
+        // stops when both ends meet in the middle
+        while let (Some(front), Some(back)) = (iter.next(), iter.next_back()) {
+            if front != back {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Adds a new node onto the front of the list.
+    /// `head` pointer will point to the newly created node after this operation.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// list.push_front(2);
+    /// ```
+    pub fn push_front(&mut self, elem: T) {
+        // create a new node with elem in the heap
+        let new_node = Node::new_raw(elem);
+        self.link_front(new_node);
+    }
+
+    /// Adds a new node onto the front of the list holding the value returned by `f`.
+    /// The node is allocated before `f` is called and the value is written straight into it,
+    /// which saves moving large values through the stack.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([[1u8; 4096]]);
+    /// list.push_front_with(|| [0u8; 4096]);
+    /// assert_eq!(list.peek_front().unwrap()[0], 0);
+    /// ```
+    pub fn push_front_with<F: FnOnce() -> T>(&mut self, f: F) {
+        let new_node = Node::new_raw_with(f);
+        self.link_front(new_node);
+    }
+
+    /// Adds a new node onto the front of the list holding the value returned by `f`.
+    /// Returns the closure back inside `AllocError`, without calling it, if the node could not be allocated.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([2]);
+    /// assert!(list.try_push_front_with(|| 1).is_ok());
+    /// assert_eq!(list.peek_front(), Some(&1));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_push_front_with<F: FnOnce() -> T>(&mut self, f: F) -> Result<(), AllocError<F>> {
+        let new_node = Node::try_new_raw_with(f)?;
+        self.link_front(new_node);
+        Ok(())
+    }
+
+    /// Adds a new node onto the front of the list.
+    /// Returns the element back inside `AllocError` if the node could not be allocated.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// assert!(list.try_push_front(2).is_ok());
+    /// assert_eq!(list.peek_front(), Some(&2));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_push_front(&mut self, elem: T) -> Result<(), AllocError<T>> {
+        let new_node = Node::try_new_raw(elem)?;
+        self.link_front(new_node);
+        Ok(())
+    }
+
+    // links an already allocated node in front of the current head
+    pub(crate) fn link_front(&mut self, new_node: Link<T>) {
+        unsafe {
+            // set current head as the next of new_node
+            new_node.set_next(self.head);
+        }
+        // if head is null that means list is empty
+        // tail will also point to new_node.
+        if self.head.is_null() {
+            self.tail = new_node;
+        } else {
+            unsafe {
+                // otherwise `prev` of current head will point to new_node
+                self.head.set_prev(new_node);
+            }
+        }
+        // and head will be set to new_node
+        self.head = new_node;
+    }
+
+    /// Adds a new node onto the back of the list.
+    /// `tail` pointer will point to the newly created node after this operation.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// list.push_back(2);
+    /// ```
+    pub fn push_back(&mut self, elem: T) {
+        // create a new node with elem in the heap
+        let new_node = Node::new_raw(elem);
+        self.link_back(new_node);
+    }
+
+    /// Adds a new node onto the back of the list holding the value returned by `f`.
+    /// The node is allocated before `f` is called and the value is written straight into it,
+    /// which saves moving large values through the stack.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([[0u8; 4096]]);
+    /// list.push_back_with(|| [1u8; 4096]);
+    /// assert_eq!(list.peek_back().unwrap()[0], 1);
+    /// ```
+    pub fn push_back_with<F: FnOnce() -> T>(&mut self, f: F) {
+        let new_node = Node::new_raw_with(f);
+        self.link_back(new_node);
+    }
+
+    /// Adds a new node onto the back of the list holding the value returned by `f`.
+    /// Returns the closure back inside `AllocError`, without calling it, if the node could not be allocated.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1]);
+    /// assert!(list.try_push_back_with(|| 2).is_ok());
+    /// assert_eq!(list.peek_back(), Some(&2));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_push_back_with<F: FnOnce() -> T>(&mut self, f: F) -> Result<(), AllocError<F>> {
+        let new_node = Node::try_new_raw_with(f)?;
+        self.link_back(new_node);
+        Ok(())
+    }
+
+    /// Adds a new node onto the back of the list.
+    /// Returns the element back inside `AllocError` if the node could not be allocated.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// assert!(list.try_push_back(2).is_ok());
+    /// assert_eq!(list.peek_back(), Some(&2));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_push_back(&mut self, elem: T) -> Result<(), AllocError<T>> {
+        let new_node = Node::try_new_raw(elem)?;
+        self.link_back(new_node);
+        Ok(())
+    }
+
+    // links an already allocated node after the current tail
+    pub(crate) fn link_back(&mut self, new_node: Link<T>) {
+        unsafe {
+            // set current tail as the prev of new_node
+            new_node.set_prev(self.tail);
+        }
+        // if tail is null that means list is empty
+        // head will also point to new_node.
+        if self.tail.is_null() {
+            self.head = new_node;
+        } else {
+            // otherwise `next` of current tail will point to new_node
+            unsafe {
+                self.tail.set_next(new_node);
+            }
+        }
+        // and tail will be set to new_node
+        self.tail = new_node;
+    }
+
+    /// Removes a node from the front of the list and returns the contained value.
+    /// Returns `None` if the list is empty.
+    /// `head` pointer will move backward one step after this operation.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// list.push_front(1); list.push_front(2); list.push_front(3);
+    /// assert_eq!(list.pop_front(), Some(3));
+    /// assert_eq!(list.pop_front(), Some(2));
+    /// assert_eq!(list.pop_front(), Some(1));
+    /// assert_eq!(list.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        // if head is null that means list is empty return None
+        if self.head.is_null() {
+            return None;
+        }
+
+        unsafe {
+            // take out the node head currently pointing to.
+            // turn into a Box so that it can be dropped
+            let node = Node::free(self.head);
+            // set head as the next of the current head
+            self.head = node.next;
+            // if head is becoming null that means list is empty
+            // reset tail to null as well
+            if self.head.is_null() {
+                self.tail = Link::null();
+            } else {
+                // prev of head must be null
+                self.head.set_prev(Link::null());
+            }
+            // return the value inside node
+            Some(node.val)
+        }
+    }
+
+    /// Removes a node from the front of the list and returns the contained value.
+    /// Returns `None` if the list is empty.
+    /// `tail` pointer will move forward one step after this operation.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// list.push_front(1); list.push_front(2); list.push_front(3);
+    /// assert_eq!(list.pop_back(), Some(1));
+    /// assert_eq!(list.pop_back(), Some(2));
+    /// assert_eq!(list.pop_back(), Some(3));
+    /// assert_eq!(list.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        // if tail is null that means list is empty return None
+        if self.tail.is_null() {
+            return None;
+        }
+
+        unsafe {
+            // take out the node tail currently pointing to.
+            // turn into a Box so that it can be dropped
+            let node = Node::free(self.tail);
+            // set tail as the prev of the current tail
+            self.tail = node.prev;
+            // if tail is becoming null that means list is empty
+            // reset head to null as well
+            if self.tail.is_null() {
+                self.head = Link::null();
+            } else {
+                // next of tail must be null
+                self.tail.set_next(Link::null());
+            }
+            // return the value inside node
+            Some(node.val)
+        }
+    }
+
+    /// Removes the front element and returns it if the predicate returns true for it.
+    /// Returns `None` and leaves the list unchanged if the list is empty or the predicate returns false.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// assert_eq!(list.pop_front_if(|x| *x == 1), Some(1));
+    /// assert_eq!(list.pop_front_if(|x| *x == 1), None);
+    /// assert!(list.iter().eq([2, 3].iter()));
+    /// ```
+    pub fn pop_front_if<F>(&mut self, pred: F) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        // if head is null that means list is empty return None
+        if self.head.is_null() {
+            return None;
+        }
+        if unsafe { pred(self.head.val_mut()) } {
+            self.pop_front()
+        } else {
+            None
+        }
+    }
+
+    /// Removes the back element and returns it if the predicate returns true for it.
+    /// Returns `None` and leaves the list unchanged if the list is empty or the predicate returns false.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// while let Some(x) = list.pop_back_if(|x| *x > 1) {
+    ///     assert!(x > 1);
+    /// }
+    /// assert!(list.iter().eq([1].iter()));
+    /// ```
+    pub fn pop_back_if<F>(&mut self, pred: F) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        // if tail is null that means list is empty return None
+        if self.tail.is_null() {
+            return None;
+        }
+        if unsafe { pred(self.tail.val_mut()) } {
+            self.pop_back()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the reference to the first element from the front
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// assert_eq!(list.peek_front(), None);
+    /// list.push_front(1); list.push_front(2); list.push_front(3);
+    /// assert_eq!(list.peek_front(), Some(&3));
+    /// ```
+    pub fn peek_front(&self) -> Option<&T> {
+        // if head is null then list is empty, return None
+        if self.head.is_null() {
+            return None;
+        }
+        unsafe {
+            // return the reference to the value contains in the node
+            // the head is pointing to
+            Some(self.head.val())
+        }
+    }
+
+    /// Returns mutable reference to the first element from the front
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// assert_eq!(list.peek_front_mut(), None);
+    /// list.push_front(1); list.push_front(2); list.push_front(3);
+    /// assert_eq!(list.peek_front_mut(), Some(&mut 3));
+    /// ```
+    pub fn peek_front_mut(&mut self) -> Option<&mut T> {
+        // if head is null then list is empty, return None
+        if self.head.is_null() {
+            return None;
+        }
+        unsafe {
+            // return the reference to the value contains in the node
+            // the head is pointing to
+            Some(self.head.val_mut())
+        }
+    }
+
+    /// Returns the reference to the last element from the back
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// assert_eq!(list.peek_back(), None);
+    /// list.push_front(1); list.push_front(2); list.push_front(3);
+    /// assert_eq!(list.peek_back(), Some(&1));
+    /// ```
+    pub fn peek_back(&self) -> Option<&T> {
+        // if tail is null then list is empty, return None
+        if self.tail.is_null() {
+            return None;
+        }
+        unsafe {
+            // return the reference to the value contains in the node
+            // the head is pointing to
+            Some(self.tail.val())
+        }
+    }
+
+    /// Returns the reference to the last element from the back
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// assert_eq!(list.peek_back_mut(), None);
+    /// list.push_front(1); list.push_front(2); list.push_front(3);
+    /// assert_eq!(list.peek_back_mut(), Some(&mut 1));
+    /// ```
+    pub fn peek_back_mut(&mut self) -> Option<&mut T> {
+        // if tail is null then list is empty, return None
+        if self.tail.is_null() {
+            return None;
+        }
+        unsafe {
+            // return the reference to the value contains in the node
+            // the head is pointing to
+            Some(self.tail.val_mut())
+        }
+    }
+
+    /// Returns the reference to the element `n` places from the front, `peek_nth(0)` is the first element.
+    /// Only the first `n` nodes are walked.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// assert_eq!(list.peek_nth(1), Some(&2));
+    /// assert_eq!(list.peek_nth(3), None);
+    /// ```
+    pub fn peek_nth(&self, n: usize) -> Option<&T> {
+        let node = self.nth_node(n);
+        if node.is_null() {
+            return None;
+        }
+        unsafe { Some(node.val()) }
+    }
+
+    /// Returns the mutable reference to the element `n` places from the front.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// *list.peek_nth_mut(1).unwrap() = 20;
+    /// assert_eq!(list, [1, 20, 3]);
+    /// ```
+    pub fn peek_nth_mut(&mut self, n: usize) -> Option<&mut T> {
+        let node = self.nth_node(n);
+        if node.is_null() {
+            return None;
+        }
+        unsafe { Some(node.val_mut()) }
+    }
+
+    /// Returns the reference to the element `n` places from the back, `peek_nth_back(0)` is the last element.
+    /// Only the last `n` nodes are walked.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// assert_eq!(list.peek_nth_back(0), Some(&3));
+    /// assert_eq!(list.peek_nth_back(2), Some(&1));
+    /// assert_eq!(list.peek_nth_back(3), None);
+    /// ```
+    pub fn peek_nth_back(&self, n: usize) -> Option<&T> {
+        let node = self.nth_node_back(n);
+        if node.is_null() {
+            return None;
+        }
+        unsafe { Some(node.val()) }
+    }
+
+    /// Returns the mutable reference to the element `n` places from the back.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// *list.peek_nth_back_mut(1).unwrap() = 20;
+    /// assert_eq!(list, [1, 20, 3]);
+    /// ```
+    pub fn peek_nth_back_mut(&mut self, n: usize) -> Option<&mut T> {
+        let node = self.nth_node_back(n);
+        if node.is_null() {
+            return None;
+        }
+        unsafe { Some(node.val_mut()) }
+    }
+
+    // Returns the node `n` places from the head, or null if the list is shorter.
+    fn nth_node(&self, n: usize) -> Link<T> {
+        let mut curr = self.head;
+        for _ in 0..n {
+            if curr.is_null() {
+                break;
+            }
+            unsafe { curr = curr.next() };
+        }
+        curr
+    }
+
+    // Returns the node `n` places from the tail, or null if the list is shorter.
+    fn nth_node_back(&self, n: usize) -> Link<T> {
+        let mut curr = self.tail;
+        for _ in 0..n {
+            if curr.is_null() {
+                break;
+            }
+            unsafe { curr = curr.prev() };
+        }
+        curr
+    }
+
+    /// Returns the middle element and its index, the element at index `len / 2`.
+    /// The list is walked once with a slow and a fast pointer, the length is not computed first.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// assert_eq!(LinkedList::from([1, 2, 3]).middle(), Some((&2, 1)));
+    /// assert_eq!(LinkedList::from([1, 2, 3, 4]).middle(), Some((&3, 2)));
+    /// assert_eq!(LinkedList::<i32>::new().middle(), None);
+    /// ```
+    pub fn middle(&self) -> Option<(&T, usize)> {
+        if self.head.is_null() {
+            return None;
+        }
+        let mut slow = self.head;
+        let mut fast = self.head;
+        let mut index = 0;
+        unsafe {
+            // the fast pointer moves two nodes for every node of the slow pointer
+            while !fast.is_null() && !fast.next().is_null() {
+                fast = fast.next().next();
+                slow = slow.next();
+                index += 1;
+            }
+            Some((slow.val(), index))
+        }
+    }
+
+    /// Applies the given closure to the element at the given index.
+    /// The list is walked from whichever end is nearer to the index.
+    /// Returns false if the index does not exist, the index does not wrap around.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3, 4]);
+    /// assert!(list.update_at(1, |x| *x *= 10));
+    /// assert!(list.update_at(3, |x| *x += 1));
+    /// assert!(!list.update_at(4, |x| *x = 0));
+    /// assert!(list.iter().eq([1, 20, 3, 5].iter()));
+    /// ```
+    pub fn update_at<F>(&mut self, index: usize, f: F) -> bool
+    where
+        F: FnOnce(&mut T),
+    {
+        let node = self.node_at(index);
+        // if node is null then index does not exist in the list
+        if node.is_null() {
+            return false;
+        }
+        unsafe {
+            f(node.val_mut());
+        }
+        true
+    }
+
+    // Returns the pointer to the node at the given index,
+    // or null pointer if the index does not exist.
+    // Walks from the head or from the tail, whichever is nearer.
+    pub(crate) fn node_at(&self, index: usize) -> Link<T> {
+        let len = self.len();
+        if index >= len {
+            return Link::null();
+        }
+        unsafe {
+            if index < len / 2 {
+                let mut curr = self.head;
+                (0..index).for_each(|_| curr = curr.next());
+                curr
+            } else {
+                let mut curr = self.tail;
+                (index..len - 1).for_each(|_| curr = curr.prev());
+                curr
+            }
+        }
+    }
+
+    /// Reverses the elements in the given range of indices in place.
+    /// Nodes are relinked, the rest of the list is left untouched.
+    /// Panics if the range is out of bounds or its start is greater than its end.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3, 4, 5, 6]);
+    /// list.reverse_range(1..4);
+    /// assert!(list.iter().eq([1, 4, 3, 2, 5, 6].iter()));
+    /// list.reverse_range(..);
+    /// assert!(list.iter().eq([6, 5, 2, 3, 4, 1].iter()));
+    /// ```
+    pub fn reverse_range<R>(&mut self, range: R)
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = self.range_indices(range);
+        // less than two nodes in the range, nothing to reverse
+        if end - start < 2 {
+            return;
+        }
+        let first = self.node_at(start);
+        let last = self.node_at(end - 1);
+        unsafe { self.reverse_nodes(first, last) }
+    }
+
+    // Converts the range into `start..end` indices of the list.
+    // Panics if the range is out of bounds or its start is greater than its end.
+    pub(crate) fn range_indices<R>(&self, range: R) -> (usize, usize)
+    where
+        R: RangeBounds<usize>,
+    {
+        range_indices(range, self.len())
+    }
+
+    /// Removes the elements in the given range of indices and returns them as a new list.
+    /// The nodes are relinked in one pass, no element is moved.
+    /// Panics if the range is out of bounds or its start is greater than its end.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// let removed = list.remove_range(1..3);
+    /// assert_eq!(removed, LinkedList::from([2, 3]));
+    /// assert_eq!(list, LinkedList::from([1, 4, 5]));
+    /// assert_eq!(list.remove_range(2..), LinkedList::from([5]));
+    /// ```
+    pub fn remove_range<R>(&mut self, range: R) -> Self
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = self.range_indices(range);
+        if start == end {
+            return Self::new();
+        }
+        let first = self.node_at(start);
+        let last = self.node_at(end - 1);
+        unsafe { self.unlink_nodes(first, last) }
+        Self {
+            head: first,
+            tail: last,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Replaces the elements in the given range of indices with the elements of `replace_with`
+    /// and returns the removed elements as a new list.
+    /// Panics if the range is out of bounds or its start is greater than its end.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// let removed = list.splice_range(1..3, [10, 20, 30]);
+    /// assert_eq!(removed, LinkedList::from([2, 3]));
+    /// assert_eq!(list, LinkedList::from([1, 10, 20, 30, 4, 5]));
+    /// ```
+    pub fn splice_range<R, I>(&mut self, range: R, replace_with: I) -> Self
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        // link the new elements among themselves first,
+        // the list is left untouched if the iterator panics
+        let mut replacement = replace_with.into_iter().collect::<Self>();
+        let (start, end) = self.range_indices(range);
+        // detach everything from start, take the range off its front,
+        // then put the replacement and the rest back
+        let mut back = self.remove_range(start..);
+        let removed = back.remove_range(..end - start);
+        self.append(&mut replacement);
+        self.append(&mut back);
+        removed
+    }
+
+    /// Replaces the elements in the given range of indices with the elements of `replace_with`
+    /// and returns the removed elements as a new list.
+    /// Returns the element whose node could not be allocated inside `AllocError`,
+    /// the list is left unchanged and the rest of `replace_with` is dropped in that case.
+    /// Panics if the range is out of bounds or its start is greater than its end.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// let removed = list.try_splice_range(1..2, [20, 30]).unwrap();
+    /// assert_eq!(removed, LinkedList::from([2]));
+    /// assert_eq!(list, LinkedList::from([1, 20, 30, 3]));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_splice_range<R, I>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> Result<Self, AllocError<T>>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        let mut replacement = Self::new();
+        replacement.try_extend(replace_with)?;
+        Ok(self.splice_range(range, replacement))
     }
 
-    /// Adds a new node onto the back of the list.
-    /// `tail` pointer will point to the newly created node after this operation.
+    /// Replaces every occurrence of the needle with a copy of the replacement and
+    /// returns the number of replacements made.
+    /// Occurrences are found from front to back and do not overlap, the replaced elements are not searched again.
+    /// The matched nodes are unlinked and dropped, the copies are linked in their place.
+    /// An empty needle is never replaced.
     /// ```
     /// use linked_list::LinkedList;
-    /// let mut list = LinkedList::new();
-    /// list.push_back(2);
+    /// let mut list = LinkedList::from([1, 2, 3, 1, 2]);
+    /// assert_eq!(list.replace_sublist([1, 2], &LinkedList::from([0])), 2);
+    /// assert_eq!(list, [0, 3, 0]);
     /// ```
-    pub fn push_back(&mut self, elem: T) {
-        // create a new node with elem
-        // create a raw pointer with the Node
-        // Box::new method will allocate the memory in the heap
-        // Box::into_raw method will provide the raw pointer of the allocated memory
-        let new_node = Box::into_raw(Box::new(Node::new(elem)));
-        unsafe {
-            // set current tail as the prev of new_node
-            (*new_node).prev = self.tail;
+    pub fn replace_sublist<I, B>(&mut self, needle: I, replacement: &Self) -> usize
+    where
+        T: PartialEq + Clone,
+        I: IntoIterator<Item = B>,
+        B: Borrow<T>,
+    {
+        let needle = needle.into_iter().collect::<Vec<_>>();
+        if needle.is_empty() {
+            return 0;
         }
-        // if tail is null that means list is empty
-        // head will also point to new_node.
-        if self.tail.is_null() {
-            self.head = new_node;
-        } else {
-            // otherwise `next` of current tail will point to new_node
-            unsafe {
-                (*to_mut_ptr(self.tail)).next = new_node;
+        let mut count = 0;
+        let mut curr = self.head;
+        unsafe {
+            while !curr.is_null() {
+                let Some(after) = Self::match_sublist(curr, &needle) else {
+                    curr = curr.next();
+                    continue;
+                };
+                // take the matched run out of the list and drop it as a list of its own
+                let last = if after.is_null() {
+                    self.tail
+                } else {
+                    after.prev()
+                };
+                self.unlink_nodes(curr, last);
+                drop(Self {
+                    head: curr,
+                    tail: last,
+                    _phantom: PhantomData,
+                });
+                self.link_list_before(replacement.clone(), after);
+                count += 1;
+                // continue searching behind the replacement
+                curr = after;
             }
         }
-        // and tail will be set to new_node
-        self.tail = new_node;
+        count
     }
 
-    /// Removes a node from the front of the list and returns the contained value.
-    /// Returns `None` if the list is empty.
-    /// `head` pointer will move backward one step after this operation.
+    /// Replaces every occurrence of the needle with a copy of the replacement, like `replace_sublist`,
+    /// and returns the number of replacements made.
+    /// Returns the clone whose node could not be allocated inside `AllocError`,
+    /// the occurrences replaced before stay replaced and the rest of the list is left unchanged.
     /// ```
     /// use linked_list::LinkedList;
-    /// let mut list = LinkedList::new();
-    /// list.push_front(1); list.push_front(2); list.push_front(3);
-    /// assert_eq!(list.pop_front(), Some(3));
-    /// assert_eq!(list.pop_front(), Some(2));
-    /// assert_eq!(list.pop_front(), Some(1));
-    /// assert_eq!(list.pop_front(), None);
+    /// let mut list = LinkedList::from([1, 2, 3, 1, 2]);
+    /// assert_eq!(list.try_replace_sublist([1, 2], &LinkedList::from([0])), Ok(2));
+    /// assert_eq!(list, [0, 3, 0]);
     /// ```
-    pub fn pop_front(&mut self) -> Option<T> {
-        // if head is null that means list is empty return None
-        if self.head.is_null() {
-            return None;
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_replace_sublist<I, B>(
+        &mut self,
+        needle: I,
+        replacement: &Self,
+    ) -> Result<usize, AllocError<T>>
+    where
+        T: PartialEq + Clone,
+        I: IntoIterator<Item = B>,
+        B: Borrow<T>,
+    {
+        let needle = needle.into_iter().collect::<Vec<_>>();
+        if needle.is_empty() {
+            return Ok(0);
         }
-
+        let mut count = 0;
+        let mut curr = self.head;
         unsafe {
-            // take out the node head currently pointing to.
-            // turn into a Box so that it can be dropped
-            let node = Box::from_raw(to_mut_ptr(self.head));
-            // set head as the next of the current head
-            self.head = node.next;
-            // if head is becoming null that means list is empty
-            // reset tail to null as well
-            if self.head.is_null() {
-                self.tail = ptr::null();
-            } else {
-                // prev of head must be null
-                (*to_mut_ptr(self.head)).prev = ptr::null();
+            while !curr.is_null() {
+                let Some(after) = Self::match_sublist(curr, &needle) else {
+                    curr = curr.next();
+                    continue;
+                };
+                // copy the replacement before the matched run is touched
+                let mut copy = Self::new();
+                copy.try_extend_cloned(replacement)?;
+                let last = if after.is_null() {
+                    self.tail
+                } else {
+                    after.prev()
+                };
+                self.unlink_nodes(curr, last);
+                drop(Self {
+                    head: curr,
+                    tail: last,
+                    _phantom: PhantomData,
+                });
+                self.link_list_before(copy, after);
+                count += 1;
+                // continue searching behind the replacement
+                curr = after;
             }
-            // return the value inside node
-            Some(node.val)
         }
+        Ok(count)
     }
 
-    /// Removes a node from the front of the list and returns the contained value.
-    /// Returns `None` if the list is empty.
-    /// `tail` pointer will move forward one step after this operation.
+    /// Moves every element not matching the predicate into a new list and returns it.
+    /// Matching elements stay in the list, both lists keep the relative order of their elements.
+    /// Only the nodes are relinked, no element is moved.
     /// ```
     /// use linked_list::LinkedList;
-    /// let mut list = LinkedList::new();
-    /// list.push_front(1); list.push_front(2); list.push_front(3);
-    /// assert_eq!(list.pop_back(), Some(1));
-    /// assert_eq!(list.pop_back(), Some(2));
-    /// assert_eq!(list.pop_back(), Some(3));
-    /// assert_eq!(list.pop_back(), None);
+    /// let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// let odd = list.partition(|x| x % 2 == 0);
+    /// assert_eq!(list, LinkedList::from([2, 4]));
+    /// assert_eq!(odd, LinkedList::from([1, 3, 5]));
     /// ```
-    pub fn pop_back(&mut self) -> Option<T> {
-        // if tail is null that means list is empty return None
-        if self.tail.is_null() {
-            return None;
-        }
-
-        unsafe {
-            // take out the node tail currently pointing to.
-            // turn into a Box so that it can be dropped
-            let node = Box::from_raw(to_mut_ptr(self.tail));
-            // set tail as the prev of the current tail
-            self.tail = node.prev;
-            // if tail is becoming null that means list is empty
-            // reset head to null as well
-            if self.tail.is_null() {
-                self.head = ptr::null();
-            } else {
-                // next of tail must be null
-                (*to_mut_ptr(self.tail)).next = ptr::null();
+    pub fn partition<F>(&mut self, mut pred: F) -> Self
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut rest = Self::new();
+        let mut curr = self.head;
+        while !curr.is_null() {
+            unsafe {
+                let next = curr.next();
+                // move the non matching node to the back of the other list
+                if !pred(curr.val()) {
+                    self.unlink_nodes(curr, curr);
+                    rest.link_back(curr);
+                }
+                curr = next;
             }
-            // return the value inside node
-            Some(node.val)
         }
+        rest
     }
 
-    /// Returns the reference to the first element from the front
+    /// Detaches the first `n` elements into a new list and returns it.
+    /// If the list has fewer than `n` elements all of them are taken.
+    /// The nodes are unlinked in one go, no element is moved.
     /// ```
     /// use linked_list::LinkedList;
-    /// let mut list = LinkedList::new();
-    /// assert_eq!(list.peek_front(), None);
-    /// list.push_front(1); list.push_front(2); list.push_front(3);
-    /// assert_eq!(list.peek_front(), Some(&3));
+    /// let mut queue = LinkedList::from([1, 2, 3, 4, 5]);
+    /// assert_eq!(queue.take_front(2), LinkedList::from([1, 2]));
+    /// assert_eq!(queue.take_front(10), LinkedList::from([3, 4, 5]));
+    /// assert!(queue.is_empty());
     /// ```
-    pub fn peek_front(&self) -> Option<&T> {
-        // if head is null then list is empty, return None
-        if self.head.is_null() {
-            return None;
+    pub fn take_front(&mut self, n: usize) -> Self {
+        if n == 0 || self.is_empty() {
+            return Self::new();
         }
+        // find the last node to be taken
+        let first = self.head;
+        let mut last = first;
         unsafe {
-            // return the reference to the value contains in the node
-            // the head is pointing to
-            Some(&(*self.head).val)
+            for _ in 1..n {
+                if last.next().is_null() {
+                    break;
+                }
+                last = last.next();
+            }
+            self.unlink_nodes(first, last);
+        }
+        Self {
+            head: first,
+            tail: last,
+            _phantom: PhantomData,
         }
     }
 
-    /// Returns mutable reference to the first element from the front
+    /// Detaches the last `n` elements into a new list and returns it.
+    /// If the list has fewer than `n` elements all of them are taken.
+    /// The walk starts from the tail, so only the taken nodes are visited.
     /// ```
     /// use linked_list::LinkedList;
-    /// let mut list = LinkedList::new();
-    /// assert_eq!(list.peek_front_mut(), None);
-    /// list.push_front(1); list.push_front(2); list.push_front(3);
-    /// assert_eq!(list.peek_front_mut(), Some(&mut 3));
+    /// let mut recent = LinkedList::from([1, 2, 3, 4, 5]);
+    /// assert_eq!(recent.take_back(2), LinkedList::from([4, 5]));
+    /// assert_eq!(recent.take_back(10), LinkedList::from([1, 2, 3]));
+    /// assert!(recent.is_empty());
     /// ```
-    pub fn peek_front_mut(&self) -> Option<&mut T> {
-        // if head is null then list is empty, return None
-        if self.head.is_null() {
-            return None;
+    pub fn take_back(&mut self, n: usize) -> Self {
+        if n == 0 || self.is_empty() {
+            return Self::new();
         }
+        // find the first node to be taken
+        let last = self.tail;
+        let mut first = last;
         unsafe {
-            // return the reference to the value contains in the node
-            // the head is pointing to
-            Some(&mut (*to_mut_ptr(self.head)).val)
+            for _ in 1..n {
+                if first.prev().is_null() {
+                    break;
+                }
+                first = first.prev();
+            }
+            self.unlink_nodes(first, last);
+        }
+        Self {
+            head: first,
+            tail: last,
+            _phantom: PhantomData,
         }
     }
 
-    /// Returns the reference to the last element from the back
+    /// Consumes the list and splits it into `n` parts of balanced size, in order.
+    /// The first `len % n` parts get one element more than the others, parts are empty if `n` is greater than the length.
+    /// Only the nodes are relinked, no element is moved.
+    /// Panics if `n` is zero.
     /// ```
     /// use linked_list::LinkedList;
-    /// let mut list = LinkedList::new();
-    /// assert_eq!(list.peek_back(), None);
-    /// list.push_front(1); list.push_front(2); list.push_front(3);
-    /// assert_eq!(list.peek_back(), Some(&1));
+    /// let parts = LinkedList::from([1, 2, 3, 4, 5]).split_into(3);
+    /// assert_eq!(parts, [LinkedList::from([1, 2]), LinkedList::from([3, 4]), LinkedList::from([5])]);
     /// ```
-    pub fn peek_back(&self) -> Option<&T> {
-        // if tail is null then list is empty, return None
-        if self.tail.is_null() {
-            return None;
+    pub fn split_into(mut self, n: usize) -> Vec<Self> {
+        assert!(n > 0, "number of parts must be greater than zero");
+        let len = self.len();
+        let mut parts = Vec::with_capacity(n);
+        for i in 0..n {
+            let size = len / n + usize::from(i < len % n);
+            if size == 0 {
+                parts.push(Self::new());
+                continue;
+            }
+            // detach the first `size` nodes from the front
+            let first = self.head;
+            let mut last = first;
+            unsafe {
+                (1..size).for_each(|_| last = last.next());
+                self.unlink_nodes(first, last);
+            }
+            parts.push(Self {
+                head: first,
+                tail: last,
+                _phantom: PhantomData,
+            });
         }
-        unsafe {
-            // return the reference to the value contains in the node
-            // the head is pointing to
-            Some(&(*self.tail).val)
+        parts
+    }
+
+    // Links all nodes of `other` in front of the node `next`, or at the back if `next` is null.
+    // `next` must be a node of this list.
+    pub(crate) unsafe fn link_list_before(&mut self, mut other: Self, next: Link<T>) {
+        if other.is_empty() {
+            return;
+        }
+        if next.is_null() {
+            return self.append(&mut other);
+        }
+        let prev = next.prev();
+        // connect the other list with its new neighbours
+        other.head.set_prev(prev);
+        other.tail.set_next(next);
+        next.set_prev(other.tail);
+        if prev.is_null() {
+            self.head = other.head;
+        } else {
+            prev.set_next(other.head);
         }
+        // the nodes belong to this list now
+        other.head = Link::null();
+        other.tail = Link::null();
     }
 
-    /// Returns the reference to the last element from the back
-    /// ```
-    /// use linked_list::LinkedList;
-    /// let mut list = LinkedList::new();
-    /// assert_eq!(list.peek_back_mut(), None);
-    /// list.push_front(1); list.push_front(2); list.push_front(3);
-    /// assert_eq!(list.peek_back_mut(), Some(&mut 1));
-    /// ```
-    pub fn peek_back_mut(&self) -> Option<&mut T> {
-        // if tail is null then list is empty, return None
-        if self.tail.is_null() {
-            return None;
+    // Unlinks the nodes from `first` to `last`, both inclusive, from the list.
+    // `first` must not come after `last` in the list.
+    // The unlinked span is left with null `prev` of first and null `next` of last.
+    pub(crate) unsafe fn unlink_nodes(&mut self, first: Link<T>, last: Link<T>) {
+        let before = first.prev();
+        let after = last.next();
+        // join the nodes surrounding the span, or move head and tail past it
+        if before.is_null() {
+            self.head = after;
+        } else {
+            before.set_next(after);
         }
-        unsafe {
-            // return the reference to the value contains in the node
-            // the head is pointing to
-            Some(&mut (*to_mut_ptr(self.tail)).val)
+        if after.is_null() {
+            self.tail = before;
+        } else {
+            after.set_prev(before);
+        }
+        first.set_prev(Link::null());
+        last.set_next(Link::null());
+    }
+
+    // Reverses the nodes from `first` to `last`, both inclusive, by relinking them.
+    // `first` must not come after `last` in the list.
+    // head and tail are adjusted when the span touches either end.
+    pub(crate) unsafe fn reverse_nodes(&mut self, first: Link<T>, last: Link<T>) {
+        if first == last {
+            return;
+        }
+        // nodes surrounding the span, null when the span touches the ends
+        let before = first.prev();
+        let after = last.next();
+        // swap prev and next of every node in the span
+        let mut curr = first;
+        while curr != after {
+            let node = curr;
+            curr = node.next();
+            let (next, prev) = (node.next(), node.prev());
+            node.set_next(prev);
+            node.set_prev(next);
+        }
+        // `last` is now the first node of the span and `first` the last one
+        last.set_prev(before);
+        first.set_next(after);
+        if before.is_null() {
+            self.head = last;
+        } else {
+            before.set_next(last);
+        }
+        if after.is_null() {
+            self.tail = first;
+        } else {
+            after.set_prev(first);
         }
     }
 
@@ -342,8 +1912,8 @@ impl<T> LinkedList<T> {
             // if self is not empty then next of current tail
             // will point to other head
             if !self.tail.is_null() {
-                (*to_mut_ptr(self.tail)).next = other.head;
-                (*to_mut_ptr(other.head)).prev = self.tail;
+                self.tail.set_next(other.head);
+                other.head.set_prev(self.tail);
             }
         }
         // set tail as the other tail
@@ -354,8 +1924,179 @@ impl<T> LinkedList<T> {
         }
         // clear head and tail in other list
         // so that it becomes empty
-        other.head = ptr::null();
-        other.tail = ptr::null();
+        other.head = Link::null();
+        other.tail = Link::null();
+    }
+
+    /// Clones all elements of the slice onto the back of the list, keeping their order.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([String::from("a")]);
+    /// list.extend_from_slice(&[String::from("b"), String::from("c")]);
+    /// assert_eq!(list, ["a", "b", "c"].map(String::from));
+    /// ```
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        for elem in other {
+            // every node is linked right away, so the list stays whole if a clone panics
+            self.link_back(Node::new_raw(elem.clone()));
+        }
+    }
+
+    /// Clones all elements of the slice onto the back of the list, keeping their order.
+    /// Returns the clone whose node could not be allocated inside `AllocError`,
+    /// the elements cloned before it stay in the list.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1]);
+    /// assert!(list.try_extend_from_slice(&[2, 3]).is_ok());
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), AllocError<T>>
+    where
+        T: Clone,
+    {
+        for elem in other {
+            self.link_back(Node::try_new_raw(elem.clone())?);
+        }
+        Ok(())
+    }
+
+    /// Pushes all elements of the iterator onto the back of the list, like `Extend`.
+    /// Returns the element whose node could not be allocated inside `AllocError`,
+    /// the elements pushed before it stay in the list and the rest of the iterator is dropped.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1]);
+    /// assert!(list.try_extend([2, 3]).is_ok());
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), AllocError<T>> {
+        for elem in iter {
+            self.try_push_back(elem)?;
+        }
+        Ok(())
+    }
+
+    /// Pushes clones of all elements of the iterator onto the back of the list, like `Extend<&T>`.
+    /// Returns the clone whose node could not be allocated inside `AllocError`,
+    /// the elements pushed before it stay in the list and the rest of the iterator is dropped.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1]);
+    /// assert!(list.try_extend_cloned(&[2, 3]).is_ok());
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_extend_cloned<'a, I>(&mut self, iter: I) -> Result<(), AllocError<T>>
+    where
+        T: Clone + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        self.try_extend(iter.into_iter().cloned())
+    }
+
+    /// Pushes all elements of the iterator onto the front of the list.
+    /// The elements keep the order of the iterator,
+    /// the first element yielded becomes the first element of the list.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([4, 5]);
+    /// list.extend_front([1, 2, 3]);
+    /// assert!(list.iter().eq([1, 2, 3, 4, 5].iter()));
+    /// ```
+    pub fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        // link the new elements among themselves first,
+        // the list is left untouched if the iterator panics
+        let mut front = iter.into_iter().collect::<Self>();
+        // move the current nodes behind the new ones and take over the result
+        front.append(self);
+        std::mem::swap(self, &mut front);
+    }
+
+    /// Pushes all elements of the iterator onto the front of the list, keeping their order.
+    /// Returns the element whose node could not be allocated inside `AllocError`,
+    /// the list is left unchanged and the rest of the iterator is dropped in that case.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([3]);
+    /// assert!(list.try_extend_front([1, 2]).is_ok());
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_extend_front<I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError<T>> {
+        let mut front = Self::new();
+        front.try_extend(iter)?;
+        front.append(self);
+        std::mem::swap(self, &mut front);
+        Ok(())
+    }
+
+    /// Consumes both lists and returns a new list with `f` applied to each pair of elements.
+    /// Stops at the end of the shorter list, the remaining elements of the longer list are dropped.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let a = LinkedList::from([1, 2, 3]);
+    /// let b = LinkedList::from([10, 20]);
+    /// assert_eq!(a.zip_with(b, |x, y| x + y), LinkedList::from([11, 22]));
+    /// ```
+    pub fn zip_with<U, V, F>(self, other: LinkedList<U>, mut f: F) -> LinkedList<V>
+    where
+        F: FnMut(T, U) -> V,
+    {
+        self.into_iter()
+            .zip(other.into_iter())
+            .map(|(a, b)| f(a, b))
+            .collect()
+    }
+
+    /// Consumes the list and returns a new list of the values `f` returns `Some` for, in order.
+    /// Every element is moved into `f`, so the element type can change.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from(["1", "x", "3"]);
+    /// let nums = list.filter_map(|s| s.parse::<i32>().ok());
+    /// assert_eq!(nums, LinkedList::from([1, 3]));
+    /// ```
+    pub fn filter_map<U, F>(self, f: F) -> LinkedList<U>
+    where
+        F: FnMut(T) -> Option<U>,
+    {
+        self.into_iter().filter_map(f).collect()
+    }
+
+    /// Consumes both lists and returns one list alternating their elements,
+    /// starting with the first element of this list.
+    /// The rest of the longer list is appended at the end.
+    /// Only the nodes are relinked, no element is moved.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let a = LinkedList::from(['a', 'b', 'c', 'd']);
+    /// let x = LinkedList::from(['x', 'y']);
+    /// assert_eq!(a.interleave(x), LinkedList::from(['a', 'x', 'b', 'y', 'c', 'd']));
+    /// ```
+    pub fn interleave(mut self, mut other: Self) -> Self {
+        let mut merged = Self::new();
+        // take one node from the front of each list in turn while both have nodes
+        while !self.head.is_null() && !other.head.is_null() {
+            for list in [&mut self, &mut other] {
+                let node = list.head;
+                unsafe {
+                    list.unlink_nodes(node, node);
+                }
+                merged.link_back(node);
+            }
+        }
+        merged.append(&mut self);
+        merged.append(&mut other);
+        merged
     }
 
     /// Insert a node at a given index.
@@ -377,6 +2118,47 @@ impl<T> LinkedList<T> {
         cursor.insert(elem);
     }
 
+    /// Insert a node at a given index.
+    /// Unlike `insert_at` the index does not wrap around,
+    /// an index equal to the length of the list pushes the element to the back.
+    /// Returns error if the index is greater than the length of the list.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2, 4]);
+    /// assert!(list.try_insert_at(3, 2).is_ok());
+    /// assert!(list.try_insert_at(5, 4).is_ok());
+    /// assert!(list.try_insert_at(7, 6).is_err());
+    /// assert!(list.iter().eq([1, 2, 3, 4, 5].iter()));
+    /// ```
+    pub fn try_insert_at(&mut self, elem: T, index: usize) -> Result<(), IndexOutOfBounds> {
+        if index > self.len() {
+            return Err(IndexOutOfBounds);
+        }
+        // `insert_at` does not wrap around for indices up to the length
+        self.insert_at(elem, index);
+        Ok(())
+    }
+
+    /// Insert a node at a given index, like `insert_at`.
+    /// Note: Final index at the list will wrap around when length of the list is lesser.
+    /// Returns the element back inside `AllocError` if the node could not be allocated.
+    /// Unlike `try_insert_at` it does not check the index, only the allocation can fail.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 3]);
+    /// assert!(list.try_alloc_insert_at(2, 1).is_ok());
+    /// assert!(list.iter().eq([1, 2, 3].iter()));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_alloc_insert_at(&mut self, elem: T, index: usize) -> Result<(), AllocError<T>> {
+        if self.is_empty() || index == 0 {
+            return self.try_push_front(elem);
+        }
+        let mut cursor = self.cursor_front_mut().unwrap();
+        cursor.step_by(index - 1);
+        cursor.try_insert(elem)
+    }
+
     /// Remove a node at a given index.
     /// Note: Final index at the list will wrap around when length of the list is lesser.
     /// If the list is empty then it throws error
@@ -442,8 +2224,8 @@ impl<T> LinkedList<T> {
         if self.is_empty() {
             self.head = other.head;
             self.tail = other.tail;
-            other.head = ptr::null();
-            other.tail = ptr::null();
+            other.head = Link::null();
+            other.tail = Link::null();
             return;
         }
         let mut cursor = self.cursor_front_mut().unwrap();
@@ -451,3 +2233,41 @@ impl<T> LinkedList<T> {
         cursor.splice(other);
     }
 }
+
+impl<A, B> LinkedList<(A, B)> {
+    /// Consumes a list of pairs and returns two lists, one with the first
+    /// and one with the second element of every pair, in one pass.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([(1, 'a'), (2, 'b')]);
+    /// let (nums, chars) = list.unzip();
+    /// assert_eq!(nums, LinkedList::from([1, 2]));
+    /// assert_eq!(chars, LinkedList::from(['a', 'b']));
+    /// ```
+    pub fn unzip(self) -> (LinkedList<A>, LinkedList<B>) {
+        let mut left = LinkedList::new();
+        let mut right = LinkedList::new();
+        // move both values out of each node as it is popped
+        for (a, b) in self.into_iter() {
+            left.push_back(a);
+            right.push_back(b);
+        }
+        (left, right)
+    }
+}
+
+impl<T> LinkedList<LinkedList<T>> {
+    /// Consumes a list of lists and concatenates all inner lists into one.
+    /// The inner nodes are relinked, no element is moved or cloned, so it takes O(number of inner lists) time.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let lists = LinkedList::from([LinkedList::from([1, 2]), LinkedList::new(), LinkedList::from([3])]);
+    /// assert_eq!(lists.flatten(), LinkedList::from([1, 2, 3]));
+    /// ```
+    pub fn flatten(self) -> LinkedList<T> {
+        let mut flat = LinkedList::new();
+        // every inner list is appended behind the current tail
+        flat.extend(self);
+        flat
+    }
+}
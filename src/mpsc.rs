@@ -0,0 +1,368 @@
+//! Lock free multi producer single consumer queue.
+//! Nodes are allocated and freed the same way as in `LinkedList`
+//! (`Box::into_raw` / `Box::from_raw`), but the `next` pointers are atomic.
+//! - `head` points to the most recently pushed node. Producers swap it atomically
+//!   and then link the previous last node to the new one.
+//! - `tail` points to a dummy node which is only ever touched by the consumer.
+//!   A pop moves the value out of the node after the dummy and turns that node into the new dummy.
+//!
+//! `Queue` is the consumer end, it is `Send` but not `Sync`, so only one thread can pop at a time.
+//! Any number of `Producer`s can be handed out and pushed from concurrently.
+//!
+//! An async consumer can await `Queue::notify_on_push` instead of polling `is_empty` in a loop.
+//! There is a single waker slot, the consumer stores its waker there and every push wakes it.
+
+#[cfg(feature = "fallible-alloc")]
+use crate::AllocError;
+#[cfg(feature = "fallible-alloc")]
+use std::alloc::{alloc, Layout};
+use std::cell::Cell;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::ptr;
+use std::task::{Context, Poll, Waker};
+
+#[cfg(loom)]
+use loom::sync::atomic::{AtomicPtr, Ordering};
+#[cfg(loom)]
+use loom::sync::{Arc, Mutex};
+#[cfg(not(loom))]
+use std::sync::atomic::{AtomicPtr, Ordering};
+#[cfg(not(loom))]
+use std::sync::{Arc, Mutex};
+
+// Node of the queue.
+// `val` is None only for the dummy node.
+struct Node<T> {
+    val: Option<T>,
+    next: AtomicPtr<Node<T>>,
+}
+
+impl<T> Node<T> {
+    // creates a new node in the heap and returns the raw pointer to it
+    fn new_raw(val: Option<T>) -> *mut Node<T> {
+        Box::into_raw(Box::new(Self {
+            val,
+            next: AtomicPtr::new(ptr::null_mut()),
+        }))
+    }
+
+    // allocates a new node without aborting on failure,
+    // memory has the layout of the node so it can be freed with `Box::from_raw`.
+    #[cfg(feature = "fallible-alloc")]
+    fn try_new_raw(val: T) -> Result<*mut Node<T>, AllocError<T>> {
+        unsafe {
+            let new_node = alloc(Layout::new::<Self>()) as *mut Node<T>;
+            if new_node.is_null() {
+                return Err(AllocError(val));
+            }
+            new_node.write(Self {
+                val: Some(val),
+                next: AtomicPtr::new(ptr::null_mut()),
+            });
+            Ok(new_node)
+        }
+    }
+}
+
+// State shared between the consumer and all producers
+struct Inner<T> {
+    head: AtomicPtr<Node<T>>,
+    tail: AtomicPtr<Node<T>>,
+    // waker of the consumer waiting in `notify_on_push`, taken and woken by the next push
+    waker: Mutex<Option<Waker>>,
+    _phantom: PhantomData<*mut T>,
+}
+
+// Values are only ever moved between threads, never shared,
+// so `T: Send` is enough for the queue to be used from several threads.
+unsafe impl<T: Send> Send for Inner<T> {}
+unsafe impl<T: Send> Sync for Inner<T> {}
+
+impl<T> Inner<T> {
+    fn push(&self, elem: T) {
+        self.link(Node::new_raw(Some(elem)));
+    }
+
+    #[cfg(feature = "fallible-alloc")]
+    fn try_push(&self, elem: T) -> Result<(), AllocError<T>> {
+        self.link(Node::try_new_raw(elem)?);
+        Ok(())
+    }
+
+    // links an allocated node after the last node
+    fn link(&self, new_node: *mut Node<T>) {
+        // take over the position of the last node
+        let prev = self.head.swap(new_node, Ordering::AcqRel);
+        // link the previous last node to the new one,
+        // from this point on the consumer can see the new node
+        unsafe {
+            (*prev).next.store(new_node, Ordering::Release);
+        }
+        // the node is linked before the slot is locked, so a consumer storing its waker
+        // after this point sees the node when it checks the queue again
+        let waker = self.waker.lock().unwrap().take();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+
+    // true if there is no node linked after the dummy node, must only be called by the consumer
+    fn is_empty(&self) -> bool {
+        let tail = self.tail.load(Ordering::Relaxed);
+        unsafe { (*tail).next.load(Ordering::Acquire).is_null() }
+    }
+
+    // must only be called by the single consumer
+    fn pop(&self) -> Option<T> {
+        unsafe {
+            let tail = self.tail.load(Ordering::Relaxed);
+            let next = (*tail).next.load(Ordering::Acquire);
+            // nothing linked after the dummy node, queue is empty
+            // or a push is still in progress
+            if next.is_null() {
+                return None;
+            }
+            // next node becomes the new dummy node, move the value out of it
+            self.tail.store(next, Ordering::Relaxed);
+            let val = (*next).val.take();
+            // free the old dummy node
+            drop(Box::from_raw(tail));
+            val
+        }
+    }
+}
+
+impl<T> Drop for Inner<T> {
+    fn drop(&mut self) {
+        // no producer or consumer is left, free all nodes from the dummy node onwards
+        let mut curr = self.tail.load(Ordering::Relaxed);
+        while !curr.is_null() {
+            unsafe {
+                let node = Box::from_raw(curr);
+                curr = node.next.load(Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+/// Consumer end of a lock free multi producer single consumer queue.
+///
+/// Example:
+/// ```
+/// use std::thread;
+/// use linked_list::mpsc::Queue;
+/// let queue = Queue::new();
+/// let handles = (0..4)
+///     .map(|n| {
+///         let producer = queue.producer();
+///         thread::spawn(move || producer.push(n))
+///     })
+///     .collect::<Vec<_>>();
+/// handles.into_iter().for_each(|h| h.join().unwrap());
+/// let mut popped = std::iter::from_fn(|| queue.pop()).collect::<Vec<_>>();
+/// popped.sort();
+/// assert_eq!(popped, [0, 1, 2, 3]);
+/// ```
+///
+/// Only one thread can pop, the queue must not be shared across threads.
+/// ```compile_fail
+/// use linked_list::mpsc::Queue;
+/// fn is_sync<S: Sync>(_: &S) {}
+/// let queue: Queue<i32> = Queue::new();
+/// is_sync(&queue);
+/// ```
+pub struct Queue<T> {
+    inner: Arc<Inner<T>>,
+    _not_sync: PhantomData<Cell<()>>,
+}
+
+/// Producer end of a lock free multi producer single consumer queue.
+/// Created by `Queue::producer`, it can be cloned and shared across threads.
+pub struct Producer<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T> Queue<T> {
+    /// Creates a new empty queue.
+    /// Both `head` and `tail` point to the same dummy node.
+    /// ```
+    /// use linked_list::mpsc::Queue;
+    /// let queue: Queue<i32> = Queue::new();
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        let dummy = Node::new_raw(None);
+        Self {
+            inner: Arc::new(Inner {
+                head: AtomicPtr::new(dummy),
+                tail: AtomicPtr::new(dummy),
+                waker: Mutex::new(None),
+                _phantom: PhantomData,
+            }),
+            _not_sync: PhantomData,
+        }
+    }
+
+    /// Returns a new producer pushing into this queue.
+    /// ```
+    /// use linked_list::mpsc::Queue;
+    /// let queue = Queue::new();
+    /// let producer = queue.producer();
+    /// producer.push(1);
+    /// assert_eq!(queue.pop(), Some(1));
+    /// ```
+    pub fn producer(&self) -> Producer<T> {
+        Producer {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+
+    /// Adds an element onto the back of the queue.
+    /// ```
+    /// use linked_list::mpsc::Queue;
+    /// let queue = Queue::new();
+    /// queue.push(1); queue.push(2);
+    /// assert_eq!(queue.pop(), Some(1));
+    /// ```
+    pub fn push(&self, elem: T) {
+        self.inner.push(elem);
+    }
+
+    /// Adds an element onto the back of the queue.
+    /// Returns the element back inside `AllocError` if the node could not be allocated.
+    /// ```
+    /// use linked_list::mpsc::Queue;
+    /// let queue = Queue::new();
+    /// assert!(queue.try_push(1).is_ok());
+    /// assert_eq!(queue.pop(), Some(1));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_push(&self, elem: T) -> Result<(), AllocError<T>> {
+        self.inner.try_push(elem)
+    }
+
+    /// Removes the element from the front of the queue and returns it.
+    /// Returns `None` if the queue is empty.
+    /// An element whose `push` has not finished yet is not visible to `pop`.
+    /// ```
+    /// use linked_list::mpsc::Queue;
+    /// let queue = Queue::new();
+    /// queue.push(1);
+    /// assert_eq!(queue.pop(), Some(1));
+    /// assert_eq!(queue.pop(), None);
+    /// ```
+    pub fn pop(&self) -> Option<T> {
+        self.inner.pop()
+    }
+
+    /// Returns true if there is no element ready to be popped.
+    /// ```
+    /// use linked_list::mpsc::Queue;
+    /// let queue = Queue::new();
+    /// assert!(queue.is_empty());
+    /// queue.push('a');
+    /// assert!(!queue.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns a future which resolves once there is an element ready to be popped.
+    /// It resolves right away if the queue is not empty, otherwise the next push wakes it.
+    /// Takes `&mut self` because the queue has a single waker slot for its single consumer.
+    /// ```
+    /// use std::future::Future;
+    /// use std::pin::pin;
+    /// use std::task::{Context, Poll, Waker};
+    /// use linked_list::mpsc::Queue;
+    /// let mut queue = Queue::new();
+    /// let producer = queue.producer();
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// {
+    ///     let mut notified = pin!(queue.notify_on_push());
+    ///     assert_eq!(notified.as_mut().poll(&mut cx), Poll::Pending);
+    ///     producer.push(1);
+    ///     assert_eq!(notified.as_mut().poll(&mut cx), Poll::Ready(()));
+    /// }
+    /// assert_eq!(queue.pop(), Some(1));
+    /// ```
+    pub fn notify_on_push(&mut self) -> NotifyOnPush<'_, T> {
+        NotifyOnPush { queue: self }
+    }
+}
+
+impl<T> Default for Queue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Producer<T> {
+    /// Adds an element onto the back of the queue.
+    /// ```
+    /// use linked_list::mpsc::Queue;
+    /// let queue = Queue::new();
+    /// let producer = queue.producer();
+    /// producer.push(1); producer.push(2);
+    /// assert_eq!(queue.pop(), Some(1));
+    /// assert_eq!(queue.pop(), Some(2));
+    /// ```
+    pub fn push(&self, elem: T) {
+        self.inner.push(elem);
+    }
+
+    /// Adds an element onto the back of the queue.
+    /// Returns the element back inside `AllocError` if the node could not be allocated.
+    /// ```
+    /// use linked_list::mpsc::Queue;
+    /// let queue = Queue::new();
+    /// assert!(queue.producer().try_push(1).is_ok());
+    /// assert_eq!(queue.pop(), Some(1));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_push(&self, elem: T) -> Result<(), AllocError<T>> {
+        self.inner.try_push(elem)
+    }
+}
+
+impl<T> Clone for Producer<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+/// Future returned by `Queue::notify_on_push`.
+/// Resolves once there is an element ready to be popped.
+pub struct NotifyOnPush<'a, T> {
+    queue: &'a mut Queue<T>,
+}
+
+// Implement Future for NotifyOnPush
+impl<T> Future for NotifyOnPush<'_, T> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let inner = &self.queue.inner;
+        if !inner.is_empty() {
+            return Poll::Ready(());
+        }
+        *inner.waker.lock().unwrap() = Some(cx.waker().clone());
+        // a push linking its node before the waker was stored did not see the waker,
+        // check again so that push is not missed
+        if !inner.is_empty() {
+            return Poll::Ready(());
+        }
+        Poll::Pending
+    }
+}
+
+// The waker is not needed anymore once the future is dropped.
+impl<T> Drop for NotifyOnPush<'_, T> {
+    fn drop(&mut self) {
+        self.queue.inner.waker.lock().unwrap().take();
+    }
+}
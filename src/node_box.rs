@@ -0,0 +1,136 @@
+//! Whole nodes detached from a list.
+//! `NodeBox` owns a node outside of any list. Moving it from one list to another
+//! relinks the same allocation, so the element is never moved or reallocated.
+
+use crate::Link;
+use crate::LinkedList;
+use crate::Node;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::ops::{Deref, DerefMut};
+
+/// Node of a `LinkedList` which is not linked into any list.
+/// It is returned by `pop_front_node` and `pop_back_node` and taken by
+/// `push_front_node` and `push_back_node`. It derefs to the element.
+///
+/// Example:
+/// ```
+/// use linked_list::{LinkedList, NodeBox};
+/// let mut ready = LinkedList::from([1, 2]);
+/// let mut running = LinkedList::new();
+/// let task = ready.pop_front_node().unwrap();
+/// let address: *const i32 = &*task;
+/// running.push_back_node(task);
+/// assert!(std::ptr::eq(address, running.peek_front().unwrap()));
+/// running.push_back_node(NodeBox::new(3));
+/// assert_eq!(running, [1, 3]);
+/// ```
+pub struct NodeBox<T>(Box<Node<T>>);
+
+// The links of a detached node are always null, so it is as thread safe as the element.
+unsafe impl<T: Send> Send for NodeBox<T> {}
+unsafe impl<T: Sync> Sync for NodeBox<T> {}
+
+impl<T> NodeBox<T> {
+    /// Allocates a detached node holding the element.
+    /// ```
+    /// use linked_list::NodeBox;
+    /// let node = NodeBox::new(1);
+    /// assert_eq!(*node, 1);
+    /// ```
+    pub fn new(elem: T) -> Self {
+        Self(Box::new(Node::new(elem)))
+    }
+
+    /// Consumes the node and returns the element.
+    /// ```
+    /// use linked_list::NodeBox;
+    /// assert_eq!(NodeBox::new(1).into_inner(), 1);
+    /// ```
+    pub fn into_inner(self) -> T {
+        self.0.val
+    }
+}
+
+impl<T> Deref for NodeBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0.val
+    }
+}
+
+impl<T> DerefMut for NodeBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0.val
+    }
+}
+
+impl<T: Debug> Debug for NodeBox<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("NodeBox").field(&self.0.val).finish()
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Links a detached node onto the front of the list without allocating.
+    /// ```
+    /// use linked_list::{LinkedList, NodeBox};
+    /// let mut list = LinkedList::from([2]);
+    /// list.push_front_node(NodeBox::new(1));
+    /// assert_eq!(list, [1, 2]);
+    /// ```
+    pub fn push_front_node(&mut self, node: NodeBox<T>) {
+        self.link_front(Link::from_box(node.0));
+    }
+
+    /// Links a detached node onto the back of the list without allocating.
+    /// ```
+    /// use linked_list::{LinkedList, NodeBox};
+    /// let mut list = LinkedList::from([1]);
+    /// list.push_back_node(NodeBox::new(2));
+    /// assert_eq!(list, [1, 2]);
+    /// ```
+    pub fn push_back_node(&mut self, node: NodeBox<T>) {
+        self.link_back(Link::from_box(node.0));
+    }
+
+    /// Unlinks the first node of the list and returns it without freeing it.
+    /// Returns `None` if the list is empty.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2]);
+    /// assert_eq!(list.pop_front_node().map(|node| *node), Some(1));
+    /// assert_eq!(list, [2]);
+    /// ```
+    pub fn pop_front_node(&mut self) -> Option<NodeBox<T>> {
+        let node = self.head;
+        unsafe { self.detach(node) }
+    }
+
+    /// Unlinks the last node of the list and returns it without freeing it.
+    /// Returns `None` if the list is empty.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2]);
+    /// assert_eq!(list.pop_back_node().map(|node| *node), Some(2));
+    /// assert_eq!(list, [1]);
+    /// ```
+    pub fn pop_back_node(&mut self) -> Option<NodeBox<T>> {
+        let node = self.tail;
+        unsafe { self.detach(node) }
+    }
+
+    // Unlinks the node from the list and takes ownership of its allocation.
+    // Returns `None` if the node is null, otherwise it must be a node of this list.
+    unsafe fn detach(&mut self, node: Link<T>) -> Option<NodeBox<T>> {
+        if node.is_null() {
+            return None;
+        }
+        // unlink_nodes leaves prev and next of the detached node null,
+        // the node is not freed so its miri-strict canary stays live
+        self.unlink_nodes(node, node);
+        Some(NodeBox(node.into_box()))
+    }
+}
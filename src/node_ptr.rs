@@ -0,0 +1,147 @@
+//! Pointer to a node of a `LinkedList`.
+//! Every dereference of a node and every cast of a node pointer goes through `NodePtr`,
+//! so the aliasing rules the list relies on are written down in one place:
+//! - A node is allocated as a `Box` and turned into a `*mut` pointer once, in `from_box`.
+//!   The pointer is never derived from a shared reference, so writing through it is allowed.
+//! - A node is owned by exactly one list (or `NodeBox`) and is turned back into a `Box`
+//!   exactly once, in `into_box`, when it is freed or detached.
+//! - The links are read and written by value (`next`, `set_next`, ...), no reference to them
+//!   is ever handed out, so they can be changed while references to the values are alive.
+//! - References to a value (`val`, `val_mut`) get an unbounded lifetime, the caller ties it
+//!   to a borrow of the list owning the node.
+//!
+//! With the `miri-strict` feature every access checks the canary of the node,
+//! so a use after free or a pointer to something which is not a node panics.
+
+#[cfg(feature = "miri-strict")]
+use crate::methods::NODE_LIVE;
+use crate::Node;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Pointer;
+use std::fmt::Result as FmtResult;
+use std::hash::{Hash, Hasher};
+use std::ptr;
+
+// stored as `*const` so that `NodePtr<T>`, and with it `LinkedList<T>`, is covariant in `T`,
+// `mut_ptr` is the only place where it is cast back
+pub(crate) struct NodePtr<T>(*const Node<T>);
+
+impl<T> NodePtr<T> {
+    // pointer to no node, used for the links of the first and last node and of an empty list
+    pub(crate) const fn null() -> Self {
+        Self(ptr::null())
+    }
+
+    pub(crate) fn is_null(self) -> bool {
+        self.0.is_null()
+    }
+
+    // gives up the ownership of a node, it has to be taken back with `into_box`
+    pub(crate) fn from_box(node: Box<Node<T>>) -> Self {
+        Self(Box::into_raw(node))
+    }
+
+    // wraps a node allocated with the global allocator and the layout of `Node<T>`
+    // SAFETY: `node` must be null or point to such an allocation holding an initialized node.
+    pub(crate) unsafe fn from_raw(node: *mut Node<T>) -> Self {
+        Self(node)
+    }
+
+    // the raw pointer, only handed out as an opaque handle by `into_raw_parts`
+    pub(crate) fn as_ptr(self) -> *mut Node<T> {
+        self.mut_ptr()
+    }
+
+    // takes back the ownership of the node
+    // SAFETY: the pointer must point to a live node, after this call it must not be used anymore
+    // through this or any other copy of the pointer.
+    pub(crate) unsafe fn into_box(self) -> Box<Node<T>> {
+        self.check();
+        Box::from_raw(self.mut_ptr())
+    }
+
+    // SAFETY (all accessors below): the pointer must point to a live node.
+    pub(crate) unsafe fn next(self) -> Self {
+        self.check();
+        (*self.0).next
+    }
+
+    pub(crate) unsafe fn prev(self) -> Self {
+        self.check();
+        (*self.0).prev
+    }
+
+    pub(crate) unsafe fn set_next(self, next: Self) {
+        self.check();
+        (*self.mut_ptr()).next = next;
+    }
+
+    pub(crate) unsafe fn set_prev(self, prev: Self) {
+        self.check();
+        (*self.mut_ptr()).prev = prev;
+    }
+
+    // SAFETY: no mutable reference to the value may be alive for `'a`.
+    pub(crate) unsafe fn val<'a>(self) -> &'a T {
+        self.check();
+        &(*self.0).val
+    }
+
+    // SAFETY: no other reference to the value may be alive for `'a`.
+    pub(crate) unsafe fn val_mut<'a>(self) -> &'a mut T {
+        self.check();
+        &mut (*self.mut_ptr()).val
+    }
+
+    // the pointer was created as `*mut` in `from_box` or `from_raw`, so writing through it is allowed
+    fn mut_ptr(self) -> *mut Node<T> {
+        self.0 as *mut Node<T>
+    }
+
+    // panics with `miri-strict` if the pointer does not point to a live node
+    #[inline]
+    unsafe fn check(self) {
+        #[cfg(feature = "miri-strict")]
+        assert!(
+            !self.0.is_null() && (*self.0).canary == NODE_LIVE,
+            "miri-strict: node freed or pointer does not point to a node"
+        );
+    }
+}
+
+// A copy of the pointer does not copy the node, so `T` does not have to be `Copy`.
+impl<T> Clone for NodePtr<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for NodePtr<T> {}
+
+// Pointers are compared, hashed and printed by address.
+impl<T> PartialEq for NodePtr<T> {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.0, other.0)
+    }
+}
+
+impl<T> Eq for NodePtr<T> {}
+
+impl<T> Hash for NodePtr<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<T> Debug for NodePtr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl<T> Pointer for NodePtr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Pointer::fmt(&self.0, f)
+    }
+}
@@ -0,0 +1,144 @@
+//! Rayon support, enabled with the `rayon` feature.
+//! `&LinkedList<T>` and `&mut LinkedList<T>` implement `IntoParallelIterator`,
+//! which also provides `par_iter` and `par_iter_mut` through rayon's blanket traits.
+//! The list is split into ranges of nodes, each split walks
+//! from the nearer end of the range to the split point.
+
+use crate::combinatorics::{Iter, IterMut};
+use crate::LinkedList;
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+/// Parallel iterator yielding &T.
+/// This struct can be instantiated by calling `par_iter` method on the LinkedList.
+pub struct ParIter<'a, T> {
+    iter: Iter<'a, T>,
+}
+
+/// Parallel iterator yielding &mut T.
+/// This struct can be instantiated by calling `par_iter_mut` method on the LinkedList.
+pub struct ParIterMut<'a, T> {
+    iter: IterMut<'a, T>,
+}
+
+impl<'a, T: Sync + 'a> IntoParallelIterator for &'a LinkedList<T> {
+    type Iter = ParIter<'a, T>;
+    type Item = &'a T;
+
+    /// Returns ParIter.
+    /// ```
+    /// use rayon::prelude::*;
+    /// use linked_list::LinkedList;
+    /// let list = (1..=100).collect::<LinkedList<u64>>();
+    /// let sum: u64 = list.par_iter().map(|x| x * 2).sum();
+    /// assert_eq!(sum, 10100);
+    /// ```
+    fn into_par_iter(self) -> Self::Iter {
+        ParIter { iter: self.iter() }
+    }
+}
+
+impl<'a, T: Send + 'a> IntoParallelIterator for &'a mut LinkedList<T> {
+    type Iter = ParIterMut<'a, T>;
+    type Item = &'a mut T;
+
+    /// Returns ParIterMut.
+    /// ```
+    /// use rayon::prelude::*;
+    /// use linked_list::LinkedList;
+    /// let mut list = (1..=4).collect::<LinkedList<u64>>();
+    /// list.par_iter_mut().for_each(|x| *x *= 10);
+    /// assert!(list.iter().eq([10, 20, 30, 40].iter()));
+    /// ```
+    fn into_par_iter(self) -> Self::Iter {
+        ParIterMut {
+            iter: self.iter_mut(),
+        }
+    }
+}
+
+impl<'a, T: Sync + 'a> ParallelIterator for ParIter<'a, T> {
+    type Item = &'a T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+impl<'a, T: Sync + 'a> IndexedParallelIterator for ParIter<'a, T> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(self)
+    }
+}
+
+impl<'a, T: Sync + 'a> Producer for ParIter<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.iter.split_at(index);
+        (ParIter { iter: left }, ParIter { iter: right })
+    }
+}
+
+impl<'a, T: Send + 'a> ParallelIterator for ParIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+impl<'a, T: Send + 'a> IndexedParallelIterator for ParIterMut<'a, T> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(self)
+    }
+}
+
+impl<'a, T: Send + 'a> Producer for ParIterMut<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.iter.split_at(index);
+        (ParIterMut { iter: left }, ParIterMut { iter: right })
+    }
+}
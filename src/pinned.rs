@@ -0,0 +1,570 @@
+//! Pinned linked list.
+//! Every element of a `LinkedList` lives in its own heap allocated node,
+//! so its address never changes while the element stays in the list,
+//! no matter how many elements are pushed, popped or relinked around it.
+//! `LinkedList` still moves values out (`pop_front`, `into_iter`, ...) and hands out
+//! `&mut T`, so it can not promise that a value is never moved.
+//! `PinnedList` wraps a `LinkedList` and never gives out an element by value
+//! or as a plain `&mut T`, which makes it sound to hand out `Pin<&mut T>`
+//! for self referential values or values whose address is registered elsewhere (e.g. over FFI).
+//! Elements are dropped in place when they are removed.
+
+use crate::combinatorics::Iter;
+#[cfg(feature = "fallible-alloc")]
+use crate::AllocError;
+use crate::Link;
+use crate::LinkedList;
+use crate::Node;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::pin::Pin;
+
+/// Linked list whose elements are pinned in place.
+///
+/// Example:
+/// ```
+/// use std::pin::Pin;
+/// use linked_list::PinnedList;
+/// let mut list = PinnedList::new();
+/// let first: Pin<&mut i32> = list.push_back(1);
+/// let addr = &*first as *const i32;
+/// list.push_front(0);
+/// list.push_back(2);
+/// assert_eq!(list.get(1).map(|x| x as *const i32), Some(addr));
+/// ```
+pub struct PinnedList<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> PinnedList<T> {
+    /// Creates a new empty PinnedList.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let list: PinnedList<i32> = PinnedList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            list: LinkedList::new(),
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// list.push_back(1);
+    /// assert_eq!(list.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns true if the list is empty.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// assert!(list.is_empty());
+    /// list.push_back(1);
+    /// assert!(!list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Adds an element onto the front of the list and returns it pinned.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// list.push_back(2);
+    /// *list.push_front(1) += 10;
+    /// assert!(list.iter().eq([11, 2].iter()));
+    /// ```
+    pub fn push_front(&mut self, elem: T) -> Pin<&mut T> {
+        self.list.push_front(elem);
+        // SAFETY: the new node is at the front and the list is not empty
+        unsafe { Self::pin(self.list.head) }
+    }
+
+    /// Adds an element onto the back of the list and returns it pinned.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// list.push_back(1);
+    /// *list.push_back(2) += 10;
+    /// assert!(list.iter().eq([1, 12].iter()));
+    /// ```
+    pub fn push_back(&mut self, elem: T) -> Pin<&mut T> {
+        self.list.push_back(elem);
+        // SAFETY: the new node is at the back and the list is not empty
+        unsafe { Self::pin(self.list.tail) }
+    }
+
+    /// Adds an element onto the front of the list and returns it pinned.
+    /// Returns the element back inside `AllocError` if the node could not be allocated.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// assert_eq!(*list.try_push_front(1).unwrap(), 1);
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_push_front(&mut self, elem: T) -> Result<Pin<&mut T>, AllocError<T>> {
+        self.list.try_push_front(elem)?;
+        // SAFETY: the new node is at the front and the list is not empty
+        unsafe { Ok(Self::pin(self.list.head)) }
+    }
+
+    /// Adds an element onto the back of the list and returns it pinned.
+    /// Returns the element back inside `AllocError` if the node could not be allocated.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// assert_eq!(*list.try_push_back(1).unwrap(), 1);
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_push_back(&mut self, elem: T) -> Result<Pin<&mut T>, AllocError<T>> {
+        self.list.try_push_back(elem)?;
+        // SAFETY: the new node is at the back and the list is not empty
+        unsafe { Ok(Self::pin(self.list.tail)) }
+    }
+
+    /// Returns the first element of the list pinned.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// assert!(list.front_mut().is_none());
+    /// list.push_back(1);
+    /// assert_eq!(*list.front_mut().unwrap(), 1);
+    /// ```
+    pub fn front_mut(&mut self) -> Option<Pin<&mut T>> {
+        if self.list.head.is_null() {
+            return None;
+        }
+        unsafe { Some(Self::pin(self.list.head)) }
+    }
+
+    /// Returns the last element of the list pinned.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// assert!(list.back_mut().is_none());
+    /// list.push_back(1); list.push_back(2);
+    /// assert_eq!(*list.back_mut().unwrap(), 2);
+    /// ```
+    pub fn back_mut(&mut self) -> Option<Pin<&mut T>> {
+        if self.list.tail.is_null() {
+            return None;
+        }
+        unsafe { Some(Self::pin(self.list.tail)) }
+    }
+
+    /// Returns the reference to the element at the given index.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// list.push_back('a'); list.push_back('b');
+    /// assert_eq!(list.get(1), Some(&'b'));
+    /// assert_eq!(list.get(2), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let node = self.list.node_at(index);
+        if node.is_null() {
+            return None;
+        }
+        unsafe { Some(node.val()) }
+    }
+
+    /// Returns the element at the given index pinned.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// list.push_back(1); list.push_back(2);
+    /// *list.get_mut(1).unwrap() = 20;
+    /// assert!(list.iter().eq([1, 20].iter()));
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<Pin<&mut T>> {
+        let node = self.list.node_at(index);
+        if node.is_null() {
+            return None;
+        }
+        unsafe { Some(Self::pin(node)) }
+    }
+
+    /// Returns an iterator yielding &T from front to back.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// list.push_back(1); list.push_back(2);
+    /// assert!(list.iter().eq([1, 2].iter()));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+
+    /// Drops the first element of the list in place.
+    /// Returns false if the list is empty.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// list.push_back(1); list.push_back(2);
+    /// assert!(list.drop_front());
+    /// assert!(list.iter().eq([2].iter()));
+    /// ```
+    pub fn drop_front(&mut self) -> bool {
+        let head = self.list.head;
+        if head.is_null() {
+            return false;
+        }
+        unsafe {
+            self.list.unlink_nodes(head, head);
+            // the value is dropped inside its node, it is never moved
+            drop(Node::free(head));
+        }
+        true
+    }
+
+    /// Drops the last element of the list in place.
+    /// Returns false if the list is empty.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// list.push_back(1); list.push_back(2);
+    /// assert!(list.drop_back());
+    /// assert!(list.iter().eq([1].iter()));
+    /// ```
+    pub fn drop_back(&mut self) -> bool {
+        let tail = self.list.tail;
+        if tail.is_null() {
+            return false;
+        }
+        unsafe {
+            self.list.unlink_nodes(tail, tail);
+            drop(Node::free(tail));
+        }
+        true
+    }
+
+    /// Drops all elements of the list in place.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// list.push_back(1); list.push_back(2);
+    /// list.clear();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        while self.drop_front() {}
+    }
+
+    /// Returns a new PinnedCursor initialized at the front of the list.
+    /// Returns `None` if the list is empty.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// assert!(list.cursor_front_mut().is_none());
+    /// list.push_back(1);
+    /// assert_eq!(list.cursor_front_mut().unwrap().current(), (&1, 0));
+    /// ```
+    pub fn cursor_front_mut(&mut self) -> Option<PinnedCursor<'_, T>> {
+        if self.list.head.is_null() {
+            return None;
+        }
+        let length = self.len();
+        Some(PinnedCursor {
+            curr: self.list.head,
+            list: self,
+            index: 0,
+            length,
+        })
+    }
+
+    /// Returns a new PinnedCursor initialized at the back of the list.
+    /// Returns `None` if the list is empty.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// assert!(list.cursor_back_mut().is_none());
+    /// list.push_back(1); list.push_back(2);
+    /// assert_eq!(list.cursor_back_mut().unwrap().current(), (&2, 1));
+    /// ```
+    pub fn cursor_back_mut(&mut self) -> Option<PinnedCursor<'_, T>> {
+        if self.list.tail.is_null() {
+            return None;
+        }
+        let length = self.len();
+        Some(PinnedCursor {
+            curr: self.list.tail,
+            list: self,
+            index: length - 1,
+            length,
+        })
+    }
+
+    // pins the value of a node of this list.
+    // SAFETY: `node` must be a node of this list. The value stays at its address
+    // until it is dropped in place, `PinnedList` never moves values out of the nodes.
+    unsafe fn pin<'a>(node: Link<T>) -> Pin<&'a mut T> {
+        Pin::new_unchecked(node.val_mut())
+    }
+}
+
+impl<T> Default for PinnedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// `LinkedList` would move every value out of its node when dropped,
+// drop them in place instead.
+impl<T> Drop for PinnedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+        self.list.head = Link::null();
+        self.list.tail = Link::null();
+    }
+}
+
+impl<T> From<LinkedList<T>> for PinnedList<T> {
+    /// Creates a PinnedList from a list, none of its values is pinned yet.
+    /// ```
+    /// use linked_list::{LinkedList, PinnedList};
+    /// let mut list = PinnedList::from(LinkedList::from([1, 2]));
+    /// assert_eq!(*list.back_mut().unwrap(), 2);
+    /// ```
+    fn from(list: LinkedList<T>) -> Self {
+        Self { list }
+    }
+}
+
+impl<T: Debug> Debug for PinnedList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list().entries(self.list.iter()).finish()
+    }
+}
+
+/// Cursor over a `PinnedList`.
+/// Like `CursorMut` it always points to an element, but it hands out the elements
+/// only as `Pin<&mut T>` and drops them in place when they are removed.
+/// This struct can be instantiated by calling `cursor_front_mut` or `cursor_back_mut` in the PinnedList.
+pub struct PinnedCursor<'a, T> {
+    curr: Link<T>,
+    list: &'a mut PinnedList<T>,
+    index: usize,
+    length: usize,
+}
+
+// PinnedCursor behaves like `&mut PinnedList<T>` across threads.
+unsafe impl<'a, T: Send> Send for PinnedCursor<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for PinnedCursor<'a, T> {}
+
+impl<'a, T> Debug for PinnedCursor<'a, T> {
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// list.push_back(1); list.push_back(2);
+    /// let cursor = list.cursor_back_mut().unwrap();
+    /// assert_eq!(format!("{:?}", cursor), "PinnedCursor { index: 1, len: 2 }");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("PinnedCursor")
+            .field("index", &self.index)
+            .field("len", &self.length)
+            .finish()
+    }
+}
+
+impl<'a, T> PinnedCursor<'a, T> {
+    /// Returns the reference to the value under the cursor and its index.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// list.push_back('a'); list.push_back('b');
+    /// let cursor = list.cursor_front_mut().unwrap();
+    /// assert_eq!(cursor.current(), (&'a', 0));
+    /// ```
+    pub fn current(&self) -> (&T, usize) {
+        unsafe { (self.curr.val(), self.index) }
+    }
+
+    /// Returns the value under the cursor pinned and its index.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// list.push_back(1); list.push_back(2);
+    /// let mut cursor = list.cursor_back_mut().unwrap();
+    /// *cursor.current_mut().0 += 10;
+    /// assert!(list.iter().eq([1, 12].iter()));
+    /// ```
+    pub fn current_mut(&mut self) -> (Pin<&mut T>, usize) {
+        // SAFETY: the cursor always points to a node of the list
+        unsafe { (PinnedList::pin(self.curr), self.index) }
+    }
+
+    /// Move the cursor one node towards back.
+    /// When the cursor is on the last node then this method moves the cursor to the first node.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// list.push_back(1); list.push_back(2);
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), (&2, 1));
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), (&1, 0));
+    /// ```
+    pub fn move_next(&mut self) {
+        if !self.try_move_next() {
+            self.curr = self.list.list.head;
+            self.index = 0;
+        }
+    }
+
+    /// Move the cursor one node towards front.
+    /// When the cursor is on the first node then this method moves the cursor to the last node.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// list.push_back(1); list.push_back(2);
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// cursor.move_prev();
+    /// assert_eq!(cursor.current(), (&2, 1));
+    /// ```
+    pub fn move_prev(&mut self) {
+        if !self.try_move_prev() {
+            self.curr = self.list.list.tail;
+            self.index = self.length - 1;
+        }
+    }
+
+    /// Moves the cursor one node towards back and returns true.
+    /// Unlike `move_next` it does not wrap around, on the last node it returns false and stays there.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// list.push_back(1); list.push_back(2);
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// assert!(cursor.try_move_next());
+    /// assert!(!cursor.try_move_next());
+    /// assert_eq!(cursor.current(), (&2, 1));
+    /// ```
+    pub fn try_move_next(&mut self) -> bool {
+        if self.index == self.length - 1 {
+            return false;
+        }
+        unsafe {
+            self.curr = self.curr.next();
+        }
+        self.index += 1;
+        true
+    }
+
+    /// Moves the cursor one node towards front and returns true.
+    /// Unlike `move_prev` it does not wrap around, on the first node it returns false and stays there.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// list.push_back(1); list.push_back(2);
+    /// let mut cursor = list.cursor_back_mut().unwrap();
+    /// assert!(cursor.try_move_prev());
+    /// assert!(!cursor.try_move_prev());
+    /// assert_eq!(cursor.current(), (&1, 0));
+    /// ```
+    pub fn try_move_prev(&mut self) -> bool {
+        if self.index == 0 {
+            return false;
+        }
+        unsafe {
+            self.curr = self.curr.prev();
+        }
+        self.index -= 1;
+        true
+    }
+
+    /// Inserts a new element after the element under the cursor and returns it pinned.
+    /// Cursor also moves one node towards back.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// list.push_back(1); list.push_back(3);
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// *cursor.insert(2) *= 10;
+    /// assert_eq!(cursor.current(), (&20, 1));
+    /// assert!(list.iter().eq([1, 20, 3].iter()));
+    /// ```
+    pub fn insert(&mut self, elem: T) -> Pin<&mut T> {
+        let mut single = LinkedList::new();
+        single.push_back(elem);
+        self.link_after(single)
+    }
+
+    /// Inserts a new element after the element under the cursor and returns it pinned.
+    /// Cursor also moves one node towards back.
+    /// Returns the element back inside `AllocError` if the node could not be allocated.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// list.push_back(1);
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// assert_eq!(*cursor.try_insert(2).unwrap(), 2);
+    /// assert!(list.iter().eq([1, 2].iter()));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_insert(&mut self, elem: T) -> Result<Pin<&mut T>, AllocError<T>> {
+        let mut single = LinkedList::new();
+        single.try_push_back(elem)?;
+        Ok(self.link_after(single))
+    }
+
+    // links the single node of `single` after the node under the cursor,
+    // moves the cursor onto it and returns its value pinned
+    fn link_after(&mut self, single: LinkedList<T>) -> Pin<&mut T> {
+        unsafe {
+            // a null next links the new node at the back
+            let next = self.curr.next();
+            self.list.list.link_list_before(single, next);
+            self.curr = self.curr.next();
+        }
+        self.index += 1;
+        self.length += 1;
+        self.current_mut().0
+    }
+
+    /// Drops the element under the cursor in place and moves the cursor to the next element,
+    /// or to the first element when the last one was dropped.
+    /// Returns false and leaves the list unchanged if it is the only element,
+    /// the cursor can not point to an empty list.
+    /// ```
+    /// use linked_list::PinnedList;
+    /// let mut list = PinnedList::new();
+    /// list.push_back(1); list.push_back(2); list.push_back(3);
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// cursor.move_next();
+    /// assert!(cursor.drop_current());
+    /// assert_eq!(cursor.current(), (&3, 1));
+    /// assert!(cursor.drop_current());
+    /// assert_eq!(cursor.current(), (&1, 0));
+    /// assert!(!cursor.drop_current());
+    /// assert!(list.iter().eq([1].iter()));
+    /// ```
+    pub fn drop_current(&mut self) -> bool {
+        if self.length < 2 {
+            return false;
+        }
+        unsafe {
+            let node = self.curr;
+            let next = node.next();
+            self.list.list.unlink_nodes(node, node);
+            // the value is dropped inside its node, it is never moved
+            drop(Node::free(node));
+            self.curr = if next.is_null() {
+                self.list.list.head
+            } else {
+                next
+            };
+        }
+        self.length -= 1;
+        self.index %= self.length;
+        true
+    }
+}
@@ -0,0 +1,19 @@
+//! quickcheck support, enabled with the `quickcheck` feature.
+//! `LinkedList<T>` implements `quickcheck::Arbitrary`, so it can be the input of a quickcheck property.
+//! A failing list is shrunk by removing nodes first, then by shrinking the remaining elements.
+
+use crate::LinkedList;
+use quickcheck::{Arbitrary, Gen};
+
+// Implement quickcheck Arbitrary for LinkedList
+impl<T: Arbitrary> Arbitrary for LinkedList<T> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Vec::<T>::arbitrary(g).into_iter().collect()
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        // the Vec shrinker removes chunks of elements before shrinking single elements
+        let elems: Vec<T> = self.iter().cloned().collect();
+        Box::new(elems.shrink().map(LinkedList::from_iter))
+    }
+}
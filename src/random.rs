@@ -0,0 +1,91 @@
+//! Random access and shuffling, enabled with the `rand` feature.
+//! `choose` picks a uniformly random index and walks to its node.
+//! `shuffle` collects the node pointers once, shuffles them with Fisher–Yates
+//! and relinks the nodes in the new order. No element is moved.
+
+use crate::Link;
+use crate::LinkedList;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+impl<T> LinkedList<T> {
+    /// Returns the reference to a uniformly random element, or `None` if the list is empty.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// let x = list.choose(&mut StdRng::seed_from_u64(7)).unwrap();
+    /// assert!(list.contains(x));
+    /// ```
+    pub fn choose<R>(&self, rng: &mut R) -> Option<&T>
+    where
+        R: Rng + ?Sized,
+    {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        self.iter().nth(rng.gen_range(0..len))
+    }
+
+    /// Returns the mutable reference to a uniformly random element, or `None` if the list is empty.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// let mut list = LinkedList::from([0, 0, 0]);
+    /// *list.choose_mut(&mut StdRng::seed_from_u64(7)).unwrap() = 1;
+    /// assert_eq!(list.count_of(&1), 1);
+    /// ```
+    pub fn choose_mut<R>(&mut self, rng: &mut R) -> Option<&mut T>
+    where
+        R: Rng + ?Sized,
+    {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        self.iter_mut().nth(rng.gen_range(0..len))
+    }
+
+    /// Shuffles the list in place with the given random number generator.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// let mut list = LinkedList::from_iter(0..10);
+    /// list.shuffle(&mut StdRng::seed_from_u64(7));
+    /// assert_eq!(list.len(), 10);
+    /// assert!(list.is_permutation_of(&LinkedList::from_iter(0..10)));
+    /// ```
+    pub fn shuffle<R>(&mut self, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        let mut nodes = Vec::new();
+        let mut curr = self.head;
+        while !curr.is_null() {
+            nodes.push(curr);
+            curr = unsafe { curr.next() };
+        }
+        if nodes.len() < 2 {
+            return;
+        }
+        nodes.shuffle(rng);
+        // relink the nodes in the shuffled order
+        unsafe {
+            let mut prev: Link<T> = Link::null();
+            for &node in &nodes {
+                node.set_prev(prev);
+                if !prev.is_null() {
+                    prev.set_next(node);
+                }
+                prev = node;
+            }
+            prev.set_next(Link::null());
+        }
+        self.head = nodes[0];
+        self.tail = nodes[nodes.len() - 1];
+    }
+}
@@ -0,0 +1,1515 @@
+//! Slab backed linked list, enabled with the `slab` feature.
+//! All nodes of a `SlabList` live in a single `Vec` and link to each other with `u32` indices
+//! instead of pointers. Pushing an element does not allocate unless the slab has to grow,
+//! neighbouring nodes tend to share cache lines and a node costs two `u32` instead of two pointers.
+//! Removed nodes are kept on a free list inside the slab and reused by later pushes.
+//! The list holds at most `u32::MAX - 1` elements.
+
+#[cfg(feature = "fallible-alloc")]
+use crate::AllocError;
+use crate::IndexOutOfBounds;
+use crate::RemoveUnderCursorError;
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::hash::{Hash, Hasher};
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::mem;
+
+// index used as the null link
+const NIL: u32 = u32::MAX;
+
+// SlabNode struct represents each node in the slab
+// contains value owned by the node, `None` if the node is on the free list,
+// and the indices of previous and next node. Free nodes are chained through `next`.
+struct SlabNode<T> {
+    val: Option<T>,
+    prev: u32,
+    next: u32,
+}
+
+/// Doubly linked list storing its nodes in one contiguous slab.
+///
+/// `SlabList` has a subset of the `LinkedList` API, with the same names, argument order and semantics:
+/// - pushing, popping and peeking at both ends, `peek_nth`, `insert_at`, `try_insert_at`,
+///   `remove_at`, `contains`, `append`, `split_at`, `splice_at` and `extend_from_slice`,
+/// - `iter`, `iter_mut`, `into_iter` and the cursors `SlabCursor` and `SlabCursorMut`,
+/// - `Clone`, `Debug`, `Display`, `Default`, `Eq`, `Ord`, `Hash`, `From<[T; N]>`,
+///   `FromIterator` and `Extend` for `T` and `&T`.
+///
+/// Moving elements between two lists (`append`, `splice_at`, `split_at`) moves them into the other slab one by one,
+/// so it takes O(n) time where `LinkedList` only relinks the ends.
+///
+/// Example:
+/// ```
+/// use linked_list::SlabList;
+/// let list: SlabList<u32> = SlabList::new();
+/// ```
+pub struct SlabList<T> {
+    nodes: Vec<SlabNode<T>>,
+    head: u32,
+    tail: u32,
+    // first node of the free list
+    free: u32,
+    len: usize,
+}
+
+impl<T> SlabList<T> {
+    /// Creates a new instance of the SlabList.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<i32> = SlabList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new SlabList with room for `capacity` elements before the slab grows.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<i32> = SlabList::with_capacity(16);
+    /// assert!(list.capacity() >= 16);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            nodes: Vec::with_capacity(capacity),
+            head: NIL,
+            tail: NIL,
+            free: NIL,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in the list. Unlike `LinkedList::len` it takes O(1) time.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<i32> = (0..3).collect();
+    /// assert_eq!(list.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the list is empty.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list = SlabList::new();
+    /// assert!(list.is_empty());
+    /// list.push_back(1);
+    /// assert!(!list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of elements the slab can hold without growing.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list = SlabList::with_capacity(4);
+    /// list.push_back(1);
+    /// assert!(list.capacity() >= 4);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.nodes.capacity()
+    }
+
+    /// Removes all the elements from the list, the slab keeps its capacity.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (0..3).collect();
+    /// list.clear();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.head = NIL;
+        self.tail = NIL;
+        self.free = NIL;
+        self.len = 0;
+    }
+
+    /// Adds an element onto the front of the list.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list = SlabList::new();
+    /// list.push_front(1); list.push_front(2);
+    /// assert!(list.iter().eq([2, 1].iter()));
+    /// ```
+    pub fn push_front(&mut self, elem: T) {
+        let index = self.alloc_node(elem);
+        self.link_before(index, self.head);
+    }
+
+    /// Adds an element onto the back of the list.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list = SlabList::new();
+    /// list.push_back(1); list.push_back(2);
+    /// assert!(list.iter().eq([1, 2].iter()));
+    /// ```
+    pub fn push_back(&mut self, elem: T) {
+        let index = self.alloc_node(elem);
+        self.link_before(index, NIL);
+    }
+
+    /// Adds an element onto the front of the list.
+    /// Returns the element back inside `AllocError` if there is no free node and the slab could not grow.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list = SlabList::new();
+    /// assert!(list.try_push_front(1).is_ok());
+    /// assert_eq!(list.peek_front(), Some(&1));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_push_front(&mut self, elem: T) -> Result<(), AllocError<T>> {
+        let elem = self.try_reserve_node(elem)?;
+        self.push_front(elem);
+        Ok(())
+    }
+
+    /// Adds an element onto the back of the list.
+    /// Returns the element back inside `AllocError` if there is no free node and the slab could not grow.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list = SlabList::new();
+    /// assert!(list.try_push_back(1).is_ok());
+    /// assert_eq!(list.peek_back(), Some(&1));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_push_back(&mut self, elem: T) -> Result<(), AllocError<T>> {
+        let elem = self.try_reserve_node(elem)?;
+        self.push_back(elem);
+        Ok(())
+    }
+
+    /// Removes the first element of the list and returns it.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (0..2).collect();
+    /// assert_eq!(list.pop_front(), Some(0));
+    /// assert_eq!(list.pop_front(), Some(1));
+    /// assert_eq!(list.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.head == NIL {
+            return None;
+        }
+        Some(self.unlink(self.head))
+    }
+
+    /// Removes the last element of the list and returns it.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (0..2).collect();
+    /// assert_eq!(list.pop_back(), Some(1));
+    /// assert_eq!(list.pop_back(), Some(0));
+    /// assert_eq!(list.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.tail == NIL {
+            return None;
+        }
+        Some(self.unlink(self.tail))
+    }
+
+    /// Returns the reference to the first element of the list.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<i32> = (0..3).collect();
+    /// assert_eq!(list.peek_front(), Some(&0));
+    /// ```
+    pub fn peek_front(&self) -> Option<&T> {
+        self.val(self.head)
+    }
+
+    /// Returns the reference to the last element of the list.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<i32> = (0..3).collect();
+    /// assert_eq!(list.peek_back(), Some(&2));
+    /// ```
+    pub fn peek_back(&self) -> Option<&T> {
+        self.val(self.tail)
+    }
+
+    /// Returns the mutable reference to the first element of the list.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (0..3).collect();
+    /// *list.peek_front_mut().unwrap() = 10;
+    /// assert_eq!(list.peek_front(), Some(&10));
+    /// ```
+    pub fn peek_front_mut(&mut self) -> Option<&mut T> {
+        self.val_mut(self.head)
+    }
+
+    /// Returns the mutable reference to the last element of the list.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (0..3).collect();
+    /// *list.peek_back_mut().unwrap() = 10;
+    /// assert_eq!(list.peek_back(), Some(&10));
+    /// ```
+    pub fn peek_back_mut(&mut self) -> Option<&mut T> {
+        self.val_mut(self.tail)
+    }
+
+    /// Returns the reference to the element `n` places from the front, `peek_nth(0)` is the first element.
+    /// The walk starts from whichever end is closer to the element.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<i32> = (0..5).collect();
+    /// assert_eq!(list.peek_nth(3), Some(&3));
+    /// assert_eq!(list.peek_nth(5), None);
+    /// ```
+    pub fn peek_nth(&self, n: usize) -> Option<&T> {
+        self.val(self.node_at(n))
+    }
+
+    /// Returns the mutable reference to the element `n` places from the front.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (0..3).collect();
+    /// *list.peek_nth_mut(1).unwrap() = 20;
+    /// assert!(list.iter().eq([0, 20, 2].iter()));
+    /// ```
+    pub fn peek_nth_mut(&mut self, n: usize) -> Option<&mut T> {
+        self.val_mut(self.node_at(n))
+    }
+
+    /// Insert an element at a given index.
+    /// Note: Final index at the list will wrap around when length of the list is lesser.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (0..3).collect();
+    /// list.insert_at(10, 1);
+    /// list.insert_at(20, 4);
+    /// assert!(list.iter().eq([0, 10, 1, 2, 20].iter()));
+    /// ```
+    pub fn insert_at(&mut self, elem: T, index: usize) {
+        if self.is_empty() || index == 0 {
+            return self.push_front(elem);
+        }
+        // the element goes after the node at `index - 1`, wrapping around like `LinkedList::insert_at`
+        let prev = self.node_at((index - 1) % self.len);
+        let node = self.alloc_node(elem);
+        self.link_before(node, self.nodes[prev as usize].next);
+    }
+
+    /// Insert an element at a given index.
+    /// Unlike `insert_at` the index does not wrap around,
+    /// an index equal to the length of the list pushes the element to the back.
+    /// Returns error if the index is greater than the length of the list.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = [1, 2, 4].into_iter().collect();
+    /// assert!(list.try_insert_at(3, 2).is_ok());
+    /// assert!(list.try_insert_at(5, 4).is_ok());
+    /// assert!(list.try_insert_at(7, 6).is_err());
+    /// assert!(list.iter().eq([1, 2, 3, 4, 5].iter()));
+    /// ```
+    pub fn try_insert_at(&mut self, elem: T, index: usize) -> Result<(), IndexOutOfBounds> {
+        if index > self.len {
+            return Err(IndexOutOfBounds);
+        }
+        // `insert_at` does not wrap around for indices up to the length
+        self.insert_at(elem, index);
+        Ok(())
+    }
+
+    /// Insert an element at a given index, like `insert_at`.
+    /// Note: Final index at the list will wrap around when length of the list is lesser.
+    /// Returns the element back inside `AllocError` if there is no free node and the slab could not grow.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (0..2).collect();
+    /// assert!(list.try_alloc_insert_at(10, 1).is_ok());
+    /// assert!(list.iter().eq([0, 10, 1].iter()));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_alloc_insert_at(&mut self, elem: T, index: usize) -> Result<(), AllocError<T>> {
+        let elem = self.try_reserve_node(elem)?;
+        self.insert_at(elem, index);
+        Ok(())
+    }
+
+    /// Remove an element at a given index.
+    /// Note: Final index at the list will wrap around when length of the list is lesser.
+    /// If the list is empty then it throws error
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (0..4).collect();
+    /// assert_eq!(list.remove_at(1), Ok(1));
+    /// assert_eq!(list.remove_at(3), Ok(0));
+    /// assert!(list.iter().eq([2, 3].iter()));
+    /// ```
+    pub fn remove_at(&mut self, index: usize) -> Result<T, RemoveUnderCursorError> {
+        if self.is_empty() {
+            return Err(RemoveUnderCursorError);
+        }
+        Ok(self.unlink(self.node_at(index % self.len)))
+    }
+
+    /// Returns true if the list contains the given value otherwise false.
+    /// The value can be any borrowed form of the element type, like `&str` for `String`.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<String> = ["foo".to_string()].into_iter().collect();
+    /// assert!(list.contains("foo"));
+    /// assert!(!list.contains("bar"));
+    /// ```
+    pub fn contains<Q>(&self, item: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + PartialEq,
+    {
+        self.iter().any(|x| x.borrow() == item)
+    }
+
+    /// Moves all elements of `other` onto the back of the list, leaving `other` empty.
+    /// Unlike `LinkedList::append` the elements are moved into the slab of this list,
+    /// so it takes O(n) time in the length of `other` unless this list is empty.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<char> = ['a', 'b'].into_iter().collect();
+    /// let mut other: SlabList<char> = ['c'].into_iter().collect();
+    /// list.append(&mut other);
+    /// assert!(list.iter().eq(['a', 'b', 'c'].iter()));
+    /// assert!(other.is_empty());
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        if self.is_empty() {
+            // take over the whole slab of other
+            return mem::swap(self, other);
+        }
+        self.nodes.reserve(other.len);
+        while let Some(elem) = other.pop_front() {
+            self.push_back(elem);
+        }
+    }
+
+    /// Splits the list at a given index. Returns a new list.
+    /// Note: Final index at the list will wrap around when length of the list is lesser.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (1..5).collect();
+    /// let new_list = list.split_at(2);
+    /// assert!(list.iter().eq([1, 2, 3].iter()));
+    /// assert!(new_list.iter().eq([4].iter()));
+    /// ```
+    pub fn split_at(&mut self, index: usize) -> Self {
+        if self.is_empty() {
+            return Self::new();
+        }
+        let index = index.min(self.len - 1);
+        self.split_after(self.node_at(index))
+    }
+
+    /// Splice the list at a given index
+    /// Note: Final index at the list will wrap around when length of the list is lesser.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list = SlabList::from([1, 2, 3, 4]);
+    /// list.splice_at(SlabList::from([10, 11]), 2);
+    /// assert!(list.iter().eq([1, 2, 3, 10, 11, 4].iter()));
+    /// ```
+    pub fn splice_at(&mut self, other: Self, index: usize) {
+        if self.is_empty() {
+            *self = other;
+            return;
+        }
+        let mut cursor = self.cursor_front_mut().unwrap();
+        cursor.step_by(index);
+        cursor.splice(other);
+    }
+
+    /// Clones all elements of the slice onto the back of the list, keeping their order.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list = SlabList::from([String::from("a")]);
+    /// list.extend_from_slice(&[String::from("b"), String::from("c")]);
+    /// assert!(list.iter().eq(["a", "b", "c"].iter()));
+    /// ```
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.nodes.reserve(other.len());
+        for elem in other {
+            self.push_back(elem.clone());
+        }
+    }
+
+    /// Clones all elements of the slice onto the back of the list, keeping their order.
+    /// Returns the clone which could not be stored inside `AllocError`,
+    /// the elements cloned before it stay in the list.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list = SlabList::from([1]);
+    /// assert!(list.try_extend_from_slice(&[2, 3]).is_ok());
+    /// assert!(list.iter().eq([1, 2, 3].iter()));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), AllocError<T>>
+    where
+        T: Clone,
+    {
+        for elem in other {
+            self.try_push_back(elem.clone())?;
+        }
+        Ok(())
+    }
+
+    /// Pushes all elements of the iterator onto the back of the list, like `Extend`.
+    /// Returns the element which could not be stored inside `AllocError`,
+    /// the elements pushed before it stay in the list and the rest of the iterator is dropped.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list = SlabList::from([1]);
+    /// assert!(list.try_extend([2, 3]).is_ok());
+    /// assert!(list.iter().eq([1, 2, 3].iter()));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), AllocError<T>> {
+        for elem in iter {
+            self.try_push_back(elem)?;
+        }
+        Ok(())
+    }
+
+    /// Pushes clones of all elements of the iterator onto the back of the list, like `Extend<&T>`.
+    /// Returns the clone which could not be stored inside `AllocError`,
+    /// the elements pushed before it stay in the list and the rest of the iterator is dropped.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list = SlabList::from([1]);
+    /// assert!(list.try_extend_cloned(&[2, 3]).is_ok());
+    /// assert!(list.iter().eq([1, 2, 3].iter()));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_extend_cloned<'a, I>(&mut self, iter: I) -> Result<(), AllocError<T>>
+    where
+        T: Clone + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        self.try_extend(iter.into_iter().cloned())
+    }
+
+    /// Returns an iterator yielding &T from front to back.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<i32> = (1..4).collect();
+    /// let mut iter = list.iter();
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next_back(), Some(&3));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter(&self) -> SlabIter<'_, T> {
+        SlabIter {
+            nodes: &self.nodes,
+            head: self.head,
+            tail: self.tail,
+            size: self.len,
+        }
+    }
+
+    /// Returns an iterator yielding &mut T from front to back.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (1..4).collect();
+    /// list.iter_mut().for_each(|x| *x *= 10);
+    /// assert!(list.iter().eq([10, 20, 30].iter()));
+    /// ```
+    pub fn iter_mut(&mut self) -> SlabIterMut<'_, T> {
+        SlabIterMut {
+            nodes: self.nodes.as_mut_ptr(),
+            head: self.head,
+            tail: self.tail,
+            size: self.len,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns an iterator which takes the list by value and yields owned T from front to back.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<i32> = (1..4).collect();
+    /// let mut iter = list.into_iter();
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next_back(), Some(3));
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn into_iter(self) -> SlabIntoIter<T> {
+        SlabIntoIter(self)
+    }
+
+    /// Returns a new SlabCursor initialized at the front of the list.
+    /// Returns `None` if the list is empty.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<i32> = (1..4).collect();
+    /// assert_eq!(list.cursor_front().unwrap().current(), (&1, 0));
+    /// assert!(SlabList::<i32>::new().cursor_front().is_none());
+    /// ```
+    pub fn cursor_front(&self) -> Option<SlabCursor<'_, T>> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(SlabCursor {
+            curr: self.head,
+            list: self,
+            index: 0,
+        })
+    }
+
+    /// Returns a new SlabCursor initialized at the back of the list.
+    /// Returns `None` if the list is empty.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<i32> = (1..4).collect();
+    /// assert_eq!(list.cursor_back().unwrap().current(), (&3, 2));
+    /// ```
+    pub fn cursor_back(&self) -> Option<SlabCursor<'_, T>> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(SlabCursor {
+            curr: self.tail,
+            list: self,
+            index: self.len - 1,
+        })
+    }
+
+    /// Returns a new SlabCursorMut initialized at the front of the list.
+    /// Returns `None` if the list is empty.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (1..4).collect();
+    /// assert_eq!(list.cursor_front_mut().unwrap().current_mut(), (&mut 1, 0));
+    /// ```
+    pub fn cursor_front_mut(&mut self) -> Option<SlabCursorMut<'_, T>> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(SlabCursorMut {
+            curr: self.head,
+            list: self,
+            index: 0,
+        })
+    }
+
+    /// Returns a new SlabCursorMut initialized at the back of the list.
+    /// Returns `None` if the list is empty.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (1..4).collect();
+    /// assert_eq!(list.cursor_back_mut().unwrap().current_mut(), (&mut 3, 2));
+    /// ```
+    pub fn cursor_back_mut(&mut self) -> Option<SlabCursorMut<'_, T>> {
+        if self.is_empty() {
+            return None;
+        }
+        let index = self.len - 1;
+        Some(SlabCursorMut {
+            curr: self.tail,
+            list: self,
+            index,
+        })
+    }
+
+    // Moves the elements after the given node into a new list and returns it.
+    // The unlinked nodes go on the free list of this slab.
+    fn split_after(&mut self, node: u32) -> Self {
+        let mut new_list = Self::new();
+        let mut curr = self.nodes[node as usize].next;
+        while curr != NIL {
+            let next = self.nodes[curr as usize].next;
+            new_list.push_back(self.unlink(curr));
+            curr = next;
+        }
+        new_list
+    }
+
+    // Returns the index of the node at the given position, or NIL if it is out of bounds.
+    fn node_at(&self, index: usize) -> u32 {
+        if index >= self.len {
+            return NIL;
+        }
+        if index <= self.len / 2 {
+            let mut curr = self.head;
+            for _ in 0..index {
+                curr = self.nodes[curr as usize].next;
+            }
+            curr
+        } else {
+            let mut curr = self.tail;
+            for _ in index + 1..self.len {
+                curr = self.nodes[curr as usize].prev;
+            }
+            curr
+        }
+    }
+
+    // Returns the node after the given one and its position, wrapping around to the head.
+    fn wrapping_next(&self, node: u32, index: usize) -> (u32, usize) {
+        if index == self.len - 1 {
+            return (self.head, 0);
+        }
+        (self.nodes[node as usize].next, index + 1)
+    }
+
+    // Returns the node before the given one and its position, wrapping around to the tail.
+    fn wrapping_prev(&self, node: u32, index: usize) -> (u32, usize) {
+        if index == 0 {
+            return (self.tail, self.len - 1);
+        }
+        (self.nodes[node as usize].prev, index - 1)
+    }
+
+    // Returns the value of a linked node, the cursors always point to one.
+    fn value(&self, node: u32) -> &T {
+        self.val(node).expect("linked node holds a value")
+    }
+
+    fn value_mut(&mut self, node: u32) -> &mut T {
+        self.val_mut(node).expect("linked node holds a value")
+    }
+
+    fn val(&self, index: u32) -> Option<&T> {
+        if index == NIL {
+            return None;
+        }
+        self.nodes[index as usize].val.as_ref()
+    }
+
+    fn val_mut(&mut self, index: u32) -> Option<&mut T> {
+        if index == NIL {
+            return None;
+        }
+        self.nodes[index as usize].val.as_mut()
+    }
+
+    // Stores the element in a free node, or in a new one at the end of the slab,
+    // and returns its index. The node is not linked into the list yet.
+    fn alloc_node(&mut self, elem: T) -> u32 {
+        if self.free != NIL {
+            let index = self.free;
+            let node = &mut self.nodes[index as usize];
+            self.free = node.next;
+            node.val = Some(elem);
+            return index;
+        }
+        assert!(
+            self.nodes.len() < NIL as usize,
+            "SlabList capacity overflow"
+        );
+        self.nodes.push(SlabNode {
+            val: Some(elem),
+            prev: NIL,
+            next: NIL,
+        });
+        (self.nodes.len() - 1) as u32
+    }
+
+    // Makes sure `alloc_node` will not grow the slab, the element is handed back if it can not.
+    #[cfg(feature = "fallible-alloc")]
+    fn try_reserve_node(&mut self, elem: T) -> Result<T, AllocError<T>> {
+        if self.free == NIL && self.nodes.try_reserve(1).is_err() {
+            return Err(AllocError(elem));
+        }
+        Ok(elem)
+    }
+
+    // Links an allocated node right before `next`, or at the back if `next` is NIL.
+    fn link_before(&mut self, index: u32, next: u32) {
+        let prev = if next == NIL {
+            self.tail
+        } else {
+            self.nodes[next as usize].prev
+        };
+        let node = &mut self.nodes[index as usize];
+        node.prev = prev;
+        node.next = next;
+        if prev == NIL {
+            self.head = index;
+        } else {
+            self.nodes[prev as usize].next = index;
+        }
+        if next == NIL {
+            self.tail = index;
+        } else {
+            self.nodes[next as usize].prev = index;
+        }
+        self.len += 1;
+    }
+
+    // Unlinks a node from the list, puts it on the free list and returns its element.
+    fn unlink(&mut self, index: u32) -> T {
+        let node = &mut self.nodes[index as usize];
+        let (prev, next) = (node.prev, node.next);
+        let elem = node.val.take().expect("linked node holds a value");
+        node.next = self.free;
+        self.free = index;
+        if prev == NIL {
+            self.head = next;
+        } else {
+            self.nodes[prev as usize].next = next;
+        }
+        if next == NIL {
+            self.tail = prev;
+        } else {
+            self.nodes[next as usize].prev = prev;
+        }
+        self.len -= 1;
+        elem
+    }
+}
+
+/// Iterator over the elements of a `SlabList`.
+/// This struct can be instantiated by calling `iter` method in the SlabList.
+pub struct SlabIter<'a, T> {
+    nodes: &'a [SlabNode<T>],
+    head: u32,
+    tail: u32,
+    size: usize,
+}
+
+impl<'a, T> Iterator for SlabIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // size keeps track of the elements not yet yielded from either end
+        if self.size == 0 {
+            return None;
+        }
+        let node = &self.nodes[self.head as usize];
+        self.head = node.next;
+        self.size -= 1;
+        node.val.as_ref()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.size, Some(self.size))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for SlabIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.size == 0 {
+            return None;
+        }
+        let node = &self.nodes[self.tail as usize];
+        self.tail = node.prev;
+        self.size -= 1;
+        node.val.as_ref()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for SlabIter<'a, T> {}
+impl<'a, T> FusedIterator for SlabIter<'a, T> {}
+
+impl<'a, T> IntoIterator for &'a SlabList<T> {
+    type Item = &'a T;
+    type IntoIter = SlabIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut SlabList<T> {
+    type Item = &'a mut T;
+    type IntoIter = SlabIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> IntoIterator for SlabList<T> {
+    type Item = T;
+    type IntoIter = SlabIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SlabIntoIter(self)
+    }
+}
+
+/// Iterator over mutable references to the elements of a `SlabList`.
+/// This struct can be instantiated by calling `iter_mut` method in the SlabList.
+pub struct SlabIterMut<'a, T> {
+    // start of the slab, borrowed mutably for 'a
+    nodes: *mut SlabNode<T>,
+    head: u32,
+    tail: u32,
+    size: usize,
+    _phantom: PhantomData<&'a mut T>,
+}
+
+// SlabIterMut hands out mutable references to the values,
+// so it behaves like `&mut SlabList<T>` across threads.
+unsafe impl<'a, T: Send> Send for SlabIterMut<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for SlabIterMut<'a, T> {}
+
+impl<'a, T> Iterator for SlabIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.size == 0 {
+            return None;
+        }
+        // SAFETY: head is a linked node of the slab, and size makes sure
+        // no node is handed out twice from either end
+        let node = unsafe { &mut *self.nodes.add(self.head as usize) };
+        self.head = node.next;
+        self.size -= 1;
+        node.val.as_mut()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.size, Some(self.size))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for SlabIterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.size == 0 {
+            return None;
+        }
+        // SAFETY: same as in `next`
+        let node = unsafe { &mut *self.nodes.add(self.tail as usize) };
+        self.tail = node.prev;
+        self.size -= 1;
+        node.val.as_mut()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for SlabIterMut<'a, T> {}
+impl<'a, T> FusedIterator for SlabIterMut<'a, T> {}
+
+/// An iterator that owns the SlabList. Returns the owned value T when `next` is called.
+/// This struct can be instantiated by calling `into_iter` method in the SlabList.
+pub struct SlabIntoIter<T>(SlabList<T>);
+
+impl<T> Iterator for SlabIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for SlabIntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for SlabIntoIter<T> {}
+impl<T> FusedIterator for SlabIntoIter<T> {}
+
+/// Cursor over a `SlabList`, the counterpart of `Cursor` for `LinkedList`.
+/// Moving past either end wraps around to the other end.
+/// This struct can be instantiated by calling `cursor_front` or `cursor_back` method in the SlabList.
+pub struct SlabCursor<'a, T> {
+    list: &'a SlabList<T>,
+    curr: u32,
+    index: usize,
+}
+
+// Implement Debug for SlabCursor
+// Shows the position of the cursor instead of the node indices.
+impl<'a, T> Debug for SlabCursor<'a, T> {
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<i32> = (1..4).collect();
+    /// let cursor = list.cursor_back().unwrap();
+    /// assert_eq!(format!("{:?}", cursor), "SlabCursor { index: 2, len: 3 }");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("SlabCursor")
+            .field("index", &self.index)
+            .field("len", &self.list.len)
+            .finish()
+    }
+}
+
+impl<'a, T> SlabCursor<'a, T> {
+    /// Returns the reference to the value under the cursor and its index.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<i32> = (1..4).collect();
+    /// assert_eq!(list.cursor_front().unwrap().current(), (&1, 0));
+    /// ```
+    pub fn current(&self) -> (&'a T, usize) {
+        (self.list.value(self.curr), self.index)
+    }
+
+    /// Returns the reference to the value previous to the node under the cursor and its index.
+    /// Note: index will wrap around 0 to (length - 1) of the list.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<i32> = (1..4).collect();
+    /// assert_eq!(list.cursor_front().unwrap().prev(), (&3, 2));
+    /// ```
+    pub fn prev(&self) -> (&'a T, usize) {
+        let (node, index) = self.list.wrapping_prev(self.curr, self.index);
+        (self.list.value(node), index)
+    }
+
+    /// Returns the reference to the value next to the node under the cursor and its index.
+    /// Note: index will wrap around 0 to (length - 1) of the list.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<i32> = (1..4).collect();
+    /// assert_eq!(list.cursor_back().unwrap().next(), (&1, 0));
+    /// ```
+    pub fn next(&self) -> (&'a T, usize) {
+        let (node, index) = self.list.wrapping_next(self.curr, self.index);
+        (self.list.value(node), index)
+    }
+
+    /// Move the cursor one node towards back.
+    /// When the cursor is on the last node then this method moves the cursor to the first node.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<i32> = (1..4).collect();
+    /// let mut cursor = list.cursor_back().unwrap();
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), (&1, 0));
+    /// ```
+    pub fn move_next(&mut self) {
+        (self.curr, self.index) = self.list.wrapping_next(self.curr, self.index);
+    }
+
+    /// Move the cursor one node towards front.
+    /// When the cursor is on the first node then this method moves the cursor to the last node.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<i32> = (1..4).collect();
+    /// let mut cursor = list.cursor_front().unwrap();
+    /// cursor.move_prev();
+    /// assert_eq!(cursor.current(), (&3, 2));
+    /// ```
+    pub fn move_prev(&mut self) {
+        (self.curr, self.index) = self.list.wrapping_prev(self.curr, self.index);
+    }
+
+    /// Moves the cursor one node towards back and returns true.
+    /// Unlike `move_next` it does not wrap around, on the last node it returns false and stays there.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<i32> = (1..3).collect();
+    /// let mut cursor = list.cursor_front().unwrap();
+    /// assert!(cursor.try_move_next());
+    /// assert!(!cursor.try_move_next());
+    /// assert_eq!(cursor.current(), (&2, 1));
+    /// ```
+    pub fn try_move_next(&mut self) -> bool {
+        if self.index == self.list.len - 1 {
+            return false;
+        }
+        self.move_next();
+        true
+    }
+
+    /// Moves the cursor one node towards front and returns true.
+    /// Unlike `move_prev` it does not wrap around, on the first node it returns false and stays there.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<i32> = (1..3).collect();
+    /// let mut cursor = list.cursor_back().unwrap();
+    /// assert!(cursor.try_move_prev());
+    /// assert!(!cursor.try_move_prev());
+    /// assert_eq!(cursor.current(), (&1, 0));
+    /// ```
+    pub fn try_move_prev(&mut self) -> bool {
+        if self.index == 0 {
+            return false;
+        }
+        self.move_prev();
+        true
+    }
+
+    /// Moves the cursor to the node at the absolute index.
+    /// Returns an error and stays in place if the index is out of bounds.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list: SlabList<i32> = (1..6).collect();
+    /// let mut cursor = list.cursor_front().unwrap();
+    /// assert!(cursor.seek_to(3).is_ok());
+    /// assert_eq!(cursor.current(), (&4, 3));
+    /// assert!(cursor.seek_to(5).is_err());
+    /// assert_eq!(cursor.current(), (&4, 3));
+    /// ```
+    pub fn seek_to(&mut self, index: usize) -> Result<(), IndexOutOfBounds> {
+        if index >= self.list.len {
+            return Err(IndexOutOfBounds);
+        }
+        self.curr = self.list.node_at(index);
+        self.index = index;
+        Ok(())
+    }
+
+    /// Move the cursor no of steps at once.
+    /// index will wrap around according to the no of steps given.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list = SlabList::from([1, 2, 3, 4, 5]);
+    /// let mut cursor = list.cursor_front().unwrap();
+    /// cursor.step_by(2);
+    /// assert_eq!(cursor.current(), (&3, 2));
+    /// cursor.step_by(10);
+    /// assert_eq!(cursor.current(), (&3, 2));
+    /// ```
+    pub fn step_by(&mut self, steps: usize) {
+        self.index = (self.index + steps % self.list.len) % self.list.len;
+        self.curr = self.list.node_at(self.index);
+    }
+
+    /// Move the cursor backward no of steps at once.
+    /// index will wrap around according to the no of steps given.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list = SlabList::from([1, 2, 3, 4, 5]);
+    /// let mut cursor = list.cursor_front().unwrap();
+    /// cursor.step_by_backward(2);
+    /// assert_eq!(cursor.current(), (&4, 3));
+    /// cursor.step_by_backward(10);
+    /// assert_eq!(cursor.current(), (&4, 3));
+    /// ```
+    pub fn step_by_backward(&mut self, steps: usize) {
+        self.step_by(self.list.len - steps % self.list.len);
+    }
+}
+
+/// Mutable cursor over a `SlabList`, the counterpart of `CursorMut` for `LinkedList`.
+/// Moving past either end wraps around to the other end.
+/// This struct can be instantiated by calling `cursor_front_mut` or `cursor_back_mut` method in the SlabList.
+pub struct SlabCursorMut<'a, T> {
+    list: &'a mut SlabList<T>,
+    curr: u32,
+    index: usize,
+}
+
+// Implement Debug for SlabCursorMut
+// Shows the position of the cursor instead of the node indices.
+impl<'a, T> Debug for SlabCursorMut<'a, T> {
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (1..4).collect();
+    /// let cursor = list.cursor_front_mut().unwrap();
+    /// assert_eq!(format!("{:?}", cursor), "SlabCursorMut { index: 0, len: 3 }");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("SlabCursorMut")
+            .field("index", &self.index)
+            .field("len", &self.list.len)
+            .finish()
+    }
+}
+
+impl<'a, T> SlabCursorMut<'a, T> {
+    /// Returns the reference to the value under the cursor and its index.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list = SlabList::from([1, 2, 3]);
+    /// assert_eq!(list.cursor_back_mut().unwrap().current(), (&3, 2));
+    /// ```
+    pub fn current(&self) -> (&T, usize) {
+        (self.list.value(self.curr), self.index)
+    }
+
+    /// Returns the reference to the value previous to the node under the cursor and its index.
+    /// Note: index will wrap around 0 to (length - 1) of the list.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list = SlabList::from([1, 2, 3]);
+    /// assert_eq!(list.cursor_front_mut().unwrap().prev(), (&3, 2));
+    /// ```
+    pub fn prev(&self) -> (&T, usize) {
+        let (node, index) = self.list.wrapping_prev(self.curr, self.index);
+        (self.list.value(node), index)
+    }
+
+    /// Returns the reference to the value next to the node under the cursor and its index.
+    /// Note: index will wrap around 0 to (length - 1) of the list.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list = SlabList::from([1, 2, 3]);
+    /// assert_eq!(list.cursor_back_mut().unwrap().next(), (&1, 0));
+    /// ```
+    pub fn next(&self) -> (&T, usize) {
+        let (node, index) = self.list.wrapping_next(self.curr, self.index);
+        (self.list.value(node), index)
+    }
+
+    /// Returns the mutable reference to the value under the cursor and its index.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (1..4).collect();
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// *cursor.current_mut().0 = 10;
+    /// assert!(list.iter().eq([10, 2, 3].iter()));
+    /// ```
+    pub fn current_mut(&mut self) -> (&mut T, usize) {
+        (self.list.value_mut(self.curr), self.index)
+    }
+
+    /// Returns the mutable reference to the value previous to the node under the cursor and its index.
+    /// Note: index will wrap around 0 to (length - 1) of the list.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (1..4).collect();
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// assert_eq!(cursor.prev_mut(), (&mut 3, 2));
+    /// ```
+    pub fn prev_mut(&mut self) -> (&mut T, usize) {
+        let (node, index) = self.list.wrapping_prev(self.curr, self.index);
+        (self.list.value_mut(node), index)
+    }
+
+    /// Returns the mutable reference to the value next to the node under the cursor and its index.
+    /// Note: index will wrap around 0 to (length - 1) of the list.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (1..4).collect();
+    /// let mut cursor = list.cursor_back_mut().unwrap();
+    /// assert_eq!(cursor.next_mut(), (&mut 1, 0));
+    /// ```
+    pub fn next_mut(&mut self) -> (&mut T, usize) {
+        let (node, index) = self.list.wrapping_next(self.curr, self.index);
+        (self.list.value_mut(node), index)
+    }
+
+    /// Move the cursor one node towards back.
+    /// When the cursor is on the last node then this method moves the cursor to the first node.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (1..4).collect();
+    /// let mut cursor = list.cursor_back_mut().unwrap();
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current_mut(), (&mut 1, 0));
+    /// ```
+    pub fn move_next(&mut self) {
+        (self.curr, self.index) = self.list.wrapping_next(self.curr, self.index);
+    }
+
+    /// Move the cursor one node towards front.
+    /// When the cursor is on the first node then this method moves the cursor to the last node.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (1..4).collect();
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// cursor.move_prev();
+    /// assert_eq!(cursor.current_mut(), (&mut 3, 2));
+    /// ```
+    pub fn move_prev(&mut self) {
+        (self.curr, self.index) = self.list.wrapping_prev(self.curr, self.index);
+    }
+
+    /// Moves the cursor one node towards back and returns true.
+    /// Unlike `move_next` it does not wrap around, on the last node it returns false and stays there.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (1..3).collect();
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// assert!(cursor.try_move_next());
+    /// assert!(!cursor.try_move_next());
+    /// assert_eq!(cursor.current_mut(), (&mut 2, 1));
+    /// ```
+    pub fn try_move_next(&mut self) -> bool {
+        if self.index == self.list.len - 1 {
+            return false;
+        }
+        self.move_next();
+        true
+    }
+
+    /// Moves the cursor one node towards front and returns true.
+    /// Unlike `move_prev` it does not wrap around, on the first node it returns false and stays there.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (1..3).collect();
+    /// let mut cursor = list.cursor_back_mut().unwrap();
+    /// assert!(cursor.try_move_prev());
+    /// assert!(!cursor.try_move_prev());
+    /// assert_eq!(cursor.current_mut(), (&mut 1, 0));
+    /// ```
+    pub fn try_move_prev(&mut self) -> bool {
+        if self.index == 0 {
+            return false;
+        }
+        self.move_prev();
+        true
+    }
+
+    /// Moves the cursor to the node at the absolute index.
+    /// Returns an error and stays in place if the index is out of bounds.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (1..6).collect();
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// assert!(cursor.seek_to(3).is_ok());
+    /// assert_eq!(cursor.current_mut(), (&mut 4, 3));
+    /// assert!(cursor.seek_to(5).is_err());
+    /// ```
+    pub fn seek_to(&mut self, index: usize) -> Result<(), IndexOutOfBounds> {
+        if index >= self.list.len {
+            return Err(IndexOutOfBounds);
+        }
+        self.curr = self.list.node_at(index);
+        self.index = index;
+        Ok(())
+    }
+
+    /// Move the cursor no of steps at once.
+    /// index will wrap around according to the no of steps given.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list = SlabList::from([1, 2, 3, 4, 5]);
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// cursor.step_by(2);
+    /// assert_eq!(cursor.current(), (&3, 2));
+    /// cursor.step_by(10);
+    /// assert_eq!(cursor.current(), (&3, 2));
+    /// ```
+    pub fn step_by(&mut self, steps: usize) {
+        self.index = (self.index + steps % self.list.len) % self.list.len;
+        self.curr = self.list.node_at(self.index);
+    }
+
+    /// Move the cursor backward no of steps at once.
+    /// index will wrap around according to the no of steps given.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list = SlabList::from([1, 2, 3, 4, 5]);
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// cursor.step_by_backward(2);
+    /// assert_eq!(cursor.current(), (&4, 3));
+    /// cursor.step_by_backward(10);
+    /// assert_eq!(cursor.current(), (&4, 3));
+    /// ```
+    pub fn step_by_backward(&mut self, steps: usize) {
+        self.step_by(self.list.len - steps % self.list.len);
+    }
+
+    /// Insert a new element after the element under the cursor.
+    /// Cursor also moves one node towards back.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = [1, 3].into_iter().collect();
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// cursor.insert(2);
+    /// assert_eq!(cursor.current_mut(), (&mut 2, 1));
+    /// assert!(list.iter().eq([1, 2, 3].iter()));
+    /// ```
+    pub fn insert(&mut self, elem: T) {
+        let node = self.list.alloc_node(elem);
+        let next = self.list.nodes[self.curr as usize].next;
+        self.list.link_before(node, next);
+        self.curr = node;
+        self.index += 1;
+    }
+
+    /// Insert a new element after the element under the cursor.
+    /// Cursor also moves one node towards back.
+    /// Returns the element back inside `AllocError` if there is no free node and the slab could not grow.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = [1].into_iter().collect();
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// assert!(cursor.try_insert(2).is_ok());
+    /// assert_eq!(cursor.current_mut(), (&mut 2, 1));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_insert(&mut self, elem: T) -> Result<(), AllocError<T>> {
+        let elem = self.list.try_reserve_node(elem)?;
+        self.insert(elem);
+        Ok(())
+    }
+
+    /// Removes the element under the cursor and cursor moves to the next element,
+    /// or to the first element when the last one was removed.
+    /// Note: Returns error if the list contain only one element
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (1..4).collect();
+    /// let mut cursor = list.cursor_back_mut().unwrap();
+    /// assert_eq!(cursor.remove(), Ok(3));
+    /// assert_eq!(cursor.current_mut(), (&mut 1, 0));
+    /// assert_eq!(cursor.remove(), Ok(1));
+    /// assert!(cursor.remove().is_err());
+    /// assert!(list.iter().eq([2].iter()));
+    /// ```
+    pub fn remove(&mut self) -> Result<T, RemoveUnderCursorError> {
+        if self.list.len < 2 {
+            return Err(RemoveUnderCursorError);
+        }
+        let node = self.curr;
+        (self.curr, self.index) = self.list.wrapping_next(node, self.index);
+        // the index of the next node shifts down, unless the cursor wrapped to the front
+        if self.index > 0 {
+            self.index -= 1;
+        }
+        Ok(self.list.unlink(node))
+    }
+
+    /// Splits the list after the element under the cursor.
+    /// After split the element under the cursor becomes the last element of the list.
+    /// A new list is generated and returned with all rest of the elements
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = (1..6).collect();
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// let new_list = cursor.split();
+    /// assert!(new_list.iter().eq([2, 3, 4, 5].iter()));
+    /// assert!(list.iter().eq([1].iter()));
+    /// ```
+    pub fn split(&mut self) -> SlabList<T> {
+        self.list.split_after(self.curr)
+    }
+
+    /// Insert the given list into the underlying list.
+    /// Cursor advances until the last element of the other list.
+    /// The elements are moved into the slab of this list one by one.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list = SlabList::from([1, 2, 3, 4, 5]);
+    /// let mut cursor = list.cursor_front_mut().unwrap();
+    /// cursor.step_by(2);
+    /// cursor.splice(SlabList::from([10, 11]));
+    /// assert_eq!(cursor.current(), (&11, 4));
+    /// assert_eq!(cursor.next(), (&4, 5));
+    /// assert!(list.iter().eq([1, 2, 3, 10, 11, 4, 5].iter()));
+    /// ```
+    pub fn splice(&mut self, mut other: SlabList<T>) {
+        self.list.nodes.reserve(other.len);
+        while let Some(elem) = other.pop_front() {
+            self.insert(elem);
+        }
+    }
+}
+
+impl<T> Default for SlabList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug> Debug for SlabList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone> Clone for SlabList<T> {
+    fn clone(&self) -> Self {
+        // collecting compacts the nodes into list order
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: PartialEq> PartialEq for SlabList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for SlabList<T> {}
+
+impl<T: PartialOrd> PartialOrd for SlabList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for SlabList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash> Hash for SlabList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // hashes like `LinkedList`, the length first and then every element
+        self.len.hash(state);
+        for elem in self.iter() {
+            elem.hash(state);
+        }
+    }
+}
+
+impl<T> Extend<T> for SlabList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        // take each element in the interator and push_back into the list
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+impl<'a, T: Clone + 'a> Extend<&'a T> for SlabList<T> {
+    /// Clones every element of the iterator onto the back of the list.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let mut list: SlabList<i32> = SlabList::new();
+    /// list.extend([1, 2, 3].iter());
+    /// assert!(list.iter().eq([1, 2, 3].iter()));
+    /// ```
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push_back(elem.clone());
+        }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for SlabList<T> {
+    /// Returns a new SlabList from the given array
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list = SlabList::from([1, 2, 3]);
+    /// assert!(list.iter().eq([1, 2, 3].iter()));
+    /// ```
+    fn from(arr: [T; N]) -> Self {
+        arr.into_iter().collect()
+    }
+}
+
+impl<T: Display> Display for SlabList<T> {
+    /// Formats the list like `LinkedList` does.
+    /// ```
+    /// use linked_list::SlabList;
+    /// let list = SlabList::from([1, 2, 3]);
+    /// assert_eq!(list.to_string(), "HEAD -> 1 <-> 2 <-> 3 -> END");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "HEAD")?;
+        for (i, elem) in self.iter().enumerate() {
+            let arrow = if i == 0 { "->" } else { "<->" };
+            write!(f, " {} {}", arrow, elem)?;
+        }
+        write!(f, " -> END")
+    }
+}
+
+impl<T> FromIterator<T> for SlabList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut new_list = Self::with_capacity(iter.size_hint().0);
+        new_list.extend(iter);
+        new_list
+    }
+}
@@ -0,0 +1,207 @@
+//! Borrowed view over a contiguous range of nodes of a `LinkedList`.
+//! A `ListSlice` is just the first and last node of the range and its length,
+//! so creating and copying it does not touch the elements.
+
+use crate::combinatorics::Iter;
+use crate::methods::range_indices;
+use crate::Link;
+use crate::LinkedList;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::marker::PhantomData;
+use std::ops::RangeBounds;
+
+/// Borrowed view over a contiguous segment of a `LinkedList`.
+///
+/// Example:
+/// ```
+/// use linked_list::{LinkedList, ListSlice};
+/// fn total(slice: ListSlice<'_, i32>) -> i32 {
+///     slice.iter().sum()
+/// }
+/// let list = LinkedList::from([1, 2, 3, 4]);
+/// assert_eq!(total(list.slice(1..3)), 5);
+/// ```
+pub struct ListSlice<'a, T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    _phantom: PhantomData<&'a T>,
+}
+
+// ListSlice only hands out shared references to the values, like `&LinkedList<T>`.
+unsafe impl<'a, T: Sync> Send for ListSlice<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for ListSlice<'a, T> {}
+
+impl<'a, T> ListSlice<'a, T> {
+    // Creates a slice over the `len` nodes from `head` to `tail`.
+    pub(crate) fn from_range(head: Link<T>, tail: Link<T>, len: usize) -> Self {
+        Self {
+            head,
+            tail,
+            len,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the slice.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// assert_eq!(list.slice(1..).len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the slice is empty.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// assert!(list.slice(1..1).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the reference to the first element of the slice.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// assert_eq!(list.slice(1..).first(), Some(&2));
+    /// ```
+    pub fn first(&self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        unsafe { Some(self.head.val()) }
+    }
+
+    /// Returns the reference to the last element of the slice.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// assert_eq!(list.slice(..2).last(), Some(&2));
+    /// ```
+    pub fn last(&self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        unsafe { Some(self.tail.val()) }
+    }
+
+    /// Returns the reference to the element at the given index of the slice.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3, 4]);
+    /// let slice = list.slice(1..);
+    /// assert_eq!(slice.get(2), Some(&4));
+    /// assert_eq!(slice.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        if index >= self.len {
+            return None;
+        }
+        unsafe { Some(self.node_at(index).val()) }
+    }
+
+    /// Returns true if the slice contains the given value.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// assert!(list.slice(1..).contains(&3));
+    /// assert!(!list.slice(1..).contains(&1));
+    /// ```
+    pub fn contains(&self, item: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|x| x == item)
+    }
+
+    /// Returns an iterator yielding &T over the slice from front to back.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// assert!(list.slice(..2).iter().rev().eq([2, 1].iter()));
+    /// ```
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter::from_range(self.head, self.tail, self.len)
+    }
+
+    /// Returns a slice over the given range of indices of this slice.
+    /// Panics if the range is out of bounds or its start is greater than its end.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3, 4, 5]);
+    /// let inner = list.slice(1..).slice(1..3);
+    /// assert!(inner.iter().eq([3, 4].iter()));
+    /// ```
+    pub fn slice<R>(&self, range: R) -> ListSlice<'a, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = range_indices(range, self.len);
+        if start == end {
+            return ListSlice::from_range(Link::null(), Link::null(), 0);
+        }
+        ListSlice::from_range(self.node_at(start), self.node_at(end - 1), end - start)
+    }
+
+    // Returns the node at the given index of the slice, index must be in range.
+    // Walks from the head or from the tail of the slice, whichever is nearer.
+    fn node_at(&self, index: usize) -> Link<T> {
+        unsafe {
+            if index < self.len / 2 {
+                let mut curr = self.head;
+                (0..index).for_each(|_| curr = curr.next());
+                curr
+            } else {
+                let mut curr = self.tail;
+                (index..self.len - 1).for_each(|_| curr = curr.prev());
+                curr
+            }
+        }
+    }
+}
+
+impl<'a, T> Clone for ListSlice<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for ListSlice<'a, T> {}
+
+impl<'a, T: Debug> Debug for ListSlice<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T> IntoIterator for ListSlice<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Returns a borrowed view over the given range of indices.
+    /// Panics if the range is out of bounds or its start is greater than its end.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3, 4]);
+    /// assert!(list.slice(1..=2).iter().eq([2, 3].iter()));
+    /// assert_eq!(list.slice(..).len(), 4);
+    /// ```
+    pub fn slice<R>(&self, range: R) -> ListSlice<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        ListSlice::from_range(self.head, self.tail, self.len()).slice(range)
+    }
+}
@@ -0,0 +1,379 @@
+//! Linked list with generational keys.
+//! `SlotList` hands out a `ListKey` for every pushed element instead of a node pointer.
+//! A key is a slot index and the generation of that slot, so it is `Copy` and can be stored anywhere.
+//! When an element is removed its slot generation is bumped, every key to it stops resolving
+//! and the slot can be reused by a later push without the old keys seeing the new element.
+
+#[cfg(feature = "fallible-alloc")]
+use crate::AllocError;
+use crate::Link;
+use crate::LinkedList;
+use crate::Node;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+/// Stable identifier of an element in a `SlotList`.
+/// It stays valid until the element is removed, then it never resolves again.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListKey {
+    index: usize,
+    generation: u64,
+}
+
+// element of the list, it remembers its slot so the slot can be freed when the element leaves the list
+struct Entry<T> {
+    slot: usize,
+    val: T,
+}
+
+// node of an element, or null if the slot is free
+struct Slot<T> {
+    node: Link<Entry<T>>,
+    generation: u64,
+}
+
+/// Linked list addressed by generational keys.
+///
+/// Example:
+/// ```
+/// use linked_list::SlotList;
+/// let mut list = SlotList::new();
+/// let a = list.push_back("a");
+/// let b = list.push_back("b");
+/// assert_eq!(list.remove(a), Some("a"));
+/// // the key of a removed element does not resolve anymore, even if its slot is reused
+/// let c = list.push_front("c");
+/// assert_eq!(list.get(a), None);
+/// assert_eq!(list.get(c), Some(&"c"));
+/// assert_eq!(list.get(b), Some(&"b"));
+/// assert!(list.iter().eq(["c", "b"].iter()));
+/// ```
+pub struct SlotList<T> {
+    list: LinkedList<Entry<T>>,
+    slots: Vec<Slot<T>>,
+    // indices of the free slots
+    free: Vec<usize>,
+}
+
+// The slots only point into nodes owned by the list, so it is as thread safe as the list.
+unsafe impl<T: Send> Send for SlotList<T> {}
+unsafe impl<T: Sync> Sync for SlotList<T> {}
+
+impl<T> SlotList<T> {
+    /// Creates a new empty SlotList.
+    /// ```
+    /// use linked_list::SlotList;
+    /// let list: SlotList<i32> = SlotList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            list: LinkedList::new(),
+            slots: Vec::new(),
+            free: Vec::new(),
+        }
+    }
+
+    /// Returns the number of elements in the list. Unlike `LinkedList::len` it takes O(1) time.
+    /// ```
+    /// use linked_list::SlotList;
+    /// let mut list = SlotList::new();
+    /// list.push_back(1); list.push_back(2);
+    /// assert_eq!(list.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.slots.len() - self.free.len()
+    }
+
+    /// Returns true if the list is empty.
+    /// ```
+    /// use linked_list::SlotList;
+    /// let mut list = SlotList::new();
+    /// assert!(list.is_empty());
+    /// list.push_back(1);
+    /// assert!(!list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds an element at the front of the list and returns its key.
+    /// ```
+    /// use linked_list::SlotList;
+    /// let mut list = SlotList::new();
+    /// let key = list.push_front(1);
+    /// list.push_front(2);
+    /// assert_eq!(list.get(key), Some(&1));
+    /// assert!(list.iter().eq([2, 1].iter()));
+    /// ```
+    pub fn push_front(&mut self, elem: T) -> ListKey {
+        let slot = self.alloc_slot();
+        self.list.push_front(Entry { slot, val: elem });
+        self.slots[slot].node = self.list.head;
+        self.key_of(slot)
+    }
+
+    /// Adds an element at the back of the list and returns its key.
+    /// ```
+    /// use linked_list::SlotList;
+    /// let mut list = SlotList::new();
+    /// let key = list.push_back(1);
+    /// list.push_back(2);
+    /// assert_eq!(list.get(key), Some(&1));
+    /// assert!(list.iter().eq([1, 2].iter()));
+    /// ```
+    pub fn push_back(&mut self, elem: T) -> ListKey {
+        let slot = self.alloc_slot();
+        self.list.push_back(Entry { slot, val: elem });
+        self.slots[slot].node = self.list.tail;
+        self.key_of(slot)
+    }
+
+    /// Adds an element at the front of the list and returns its key.
+    /// Returns the element back inside `AllocError` if the node or a new slot could not be allocated.
+    /// ```
+    /// use linked_list::SlotList;
+    /// let mut list = SlotList::new();
+    /// let key = list.try_push_front(1).unwrap();
+    /// assert_eq!(list.get(key), Some(&1));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_push_front(&mut self, elem: T) -> Result<ListKey, AllocError<T>> {
+        let slot = self.try_next_slot(elem)?;
+        self.list
+            .try_push_front(slot)
+            .map_err(|AllocError(entry)| AllocError(entry.val))?;
+        let slot = self.alloc_slot();
+        self.slots[slot].node = self.list.head;
+        Ok(self.key_of(slot))
+    }
+
+    /// Adds an element at the back of the list and returns its key.
+    /// Returns the element back inside `AllocError` if the node or a new slot could not be allocated.
+    /// ```
+    /// use linked_list::SlotList;
+    /// let mut list = SlotList::new();
+    /// let key = list.try_push_back(1).unwrap();
+    /// assert_eq!(list.get(key), Some(&1));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_push_back(&mut self, elem: T) -> Result<ListKey, AllocError<T>> {
+        let slot = self.try_next_slot(elem)?;
+        self.list
+            .try_push_back(slot)
+            .map_err(|AllocError(entry)| AllocError(entry.val))?;
+        let slot = self.alloc_slot();
+        self.slots[slot].node = self.list.tail;
+        Ok(self.key_of(slot))
+    }
+
+    /// Returns true if the key refers to an element of the list.
+    /// ```
+    /// use linked_list::SlotList;
+    /// let mut list = SlotList::new();
+    /// let key = list.push_back(1);
+    /// assert!(list.contains_key(key));
+    /// list.remove(key);
+    /// assert!(!list.contains_key(key));
+    /// ```
+    pub fn contains_key(&self, key: ListKey) -> bool {
+        !self.node_of(key).is_null()
+    }
+
+    /// Returns the reference to the element of the key.
+    /// Returns `None` if the element was removed.
+    /// ```
+    /// use linked_list::SlotList;
+    /// let mut list = SlotList::new();
+    /// let key = list.push_back("x");
+    /// assert_eq!(list.get(key), Some(&"x"));
+    /// ```
+    pub fn get(&self, key: ListKey) -> Option<&T> {
+        let node = self.node_of(key);
+        if node.is_null() {
+            return None;
+        }
+        unsafe { Some(&node.val().val) }
+    }
+
+    /// Returns the mutable reference to the element of the key.
+    /// Returns `None` if the element was removed.
+    /// ```
+    /// use linked_list::SlotList;
+    /// let mut list = SlotList::new();
+    /// let key = list.push_back(1);
+    /// *list.get_mut(key).unwrap() += 1;
+    /// assert_eq!(list.get(key), Some(&2));
+    /// ```
+    pub fn get_mut(&mut self, key: ListKey) -> Option<&mut T> {
+        let node = self.node_of(key);
+        if node.is_null() {
+            return None;
+        }
+        unsafe { Some(&mut node.val_mut().val) }
+    }
+
+    /// Removes the element of the key from the list and returns it.
+    /// Returns `None` if the element was already removed.
+    /// ```
+    /// use linked_list::SlotList;
+    /// let mut list = SlotList::new();
+    /// let a = list.push_back(1);
+    /// list.push_back(2);
+    /// assert_eq!(list.remove(a), Some(1));
+    /// assert_eq!(list.remove(a), None);
+    /// assert!(list.iter().eq([2].iter()));
+    /// ```
+    pub fn remove(&mut self, key: ListKey) -> Option<T> {
+        let node = self.node_of(key);
+        if node.is_null() {
+            return None;
+        }
+        self.free_slot(key.index);
+        unsafe {
+            self.list.unlink_nodes(node, node);
+            Some(Node::free(node).val.val)
+        }
+    }
+
+    /// Removes the first element of the list and returns it.
+    /// ```
+    /// use linked_list::SlotList;
+    /// let mut list = SlotList::new();
+    /// let key = list.push_back(1);
+    /// assert_eq!(list.pop_front(), Some(1));
+    /// assert_eq!(list.get(key), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        let entry = self.list.pop_front()?;
+        self.free_slot(entry.slot);
+        Some(entry.val)
+    }
+
+    /// Removes the last element of the list and returns it.
+    /// ```
+    /// use linked_list::SlotList;
+    /// let mut list = SlotList::new();
+    /// let key = list.push_back(1);
+    /// assert_eq!(list.pop_back(), Some(1));
+    /// assert_eq!(list.get(key), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        let entry = self.list.pop_back()?;
+        self.free_slot(entry.slot);
+        Some(entry.val)
+    }
+
+    /// Returns the key of the first element of the list.
+    /// ```
+    /// use linked_list::SlotList;
+    /// let mut list = SlotList::new();
+    /// let key = list.push_back(1);
+    /// list.push_back(2);
+    /// assert_eq!(list.front_key(), Some(key));
+    /// ```
+    pub fn front_key(&self) -> Option<ListKey> {
+        self.list.peek_front().map(|entry| self.key_of(entry.slot))
+    }
+
+    /// Returns the key of the last element of the list.
+    /// ```
+    /// use linked_list::SlotList;
+    /// let mut list = SlotList::new();
+    /// list.push_back(1);
+    /// let key = list.push_back(2);
+    /// assert_eq!(list.back_key(), Some(key));
+    /// ```
+    pub fn back_key(&self) -> Option<ListKey> {
+        self.list.peek_back().map(|entry| self.key_of(entry.slot))
+    }
+
+    /// Returns an iterator yielding the elements from front to back.
+    /// ```
+    /// use linked_list::SlotList;
+    /// let mut list = SlotList::new();
+    /// list.push_back(1); list.push_front(0);
+    /// assert!(list.iter().eq([0, 1].iter()));
+    /// ```
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.list.iter().map(|entry| &entry.val)
+    }
+
+    /// Returns an iterator yielding the key and the element from front to back.
+    /// ```
+    /// use linked_list::SlotList;
+    /// let mut list = SlotList::new();
+    /// let a = list.push_back('a');
+    /// let b = list.push_back('b');
+    /// assert!(list.iter_with_keys().eq([(a, &'a'), (b, &'b')]));
+    /// ```
+    pub fn iter_with_keys(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (ListKey, &T)> + ExactSizeIterator {
+        self.list
+            .iter()
+            .map(|entry| (self.key_of(entry.slot), &entry.val))
+    }
+
+    // Returns the node of the key, or null if the key does not resolve.
+    fn node_of(&self, key: ListKey) -> Link<Entry<T>> {
+        match self.slots.get(key.index) {
+            Some(slot) if slot.generation == key.generation => slot.node,
+            _ => Link::null(),
+        }
+    }
+
+    // Returns the current key of an occupied slot.
+    fn key_of(&self, slot: usize) -> ListKey {
+        ListKey {
+            index: slot,
+            generation: self.slots[slot].generation,
+        }
+    }
+
+    // Returns a free slot, reusing a freed one if possible.
+    fn alloc_slot(&mut self) -> usize {
+        self.free.pop().unwrap_or_else(|| {
+            self.slots.push(Slot {
+                node: Link::null(),
+                generation: 0,
+            });
+            self.slots.len() - 1
+        })
+    }
+
+    // Makes sure `alloc_slot` will not allocate and returns the entry for the slot it will return.
+    // Returns the element back if a new slot is needed and could not be reserved.
+    #[cfg(feature = "fallible-alloc")]
+    fn try_next_slot(&mut self, elem: T) -> Result<Entry<T>, AllocError<T>> {
+        let slot = match self.free.last() {
+            Some(&slot) => slot,
+            None if self.slots.try_reserve(1).is_ok() => self.slots.len(),
+            None => return Err(AllocError(elem)),
+        };
+        Ok(Entry { slot, val: elem })
+    }
+
+    // Frees the slot of a removed element, the new generation invalidates all its keys.
+    fn free_slot(&mut self, slot: usize) {
+        let slot_ref = &mut self.slots[slot];
+        slot_ref.node = Link::null();
+        slot_ref.generation += 1;
+        self.free.push(slot);
+    }
+}
+
+// Implement Default trait for SlotList
+impl<T> Default for SlotList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Implement Debug trait for SlotList
+impl<T: Debug> Debug for SlotList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,255 @@
+//! Sorted list.
+//! `SortedList` wraps a `LinkedList` and keeps its elements in ascending order.
+//! The smallest element is always at the front and the largest at the back,
+//! so it can be used as a simple priority structure.
+
+use crate::combinatorics::Iter;
+#[cfg(feature = "fallible-alloc")]
+use crate::AllocError;
+use crate::LinkedList;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+/// Linked list which keeps its elements sorted in ascending order.
+///
+/// Example:
+/// ```
+/// use linked_list::SortedList;
+/// let mut list = SortedList::new();
+/// list.insert(3); list.insert(1); list.insert(2);
+/// assert_eq!(list.pop_min(), Some(1));
+/// assert_eq!(list.pop_max(), Some(3));
+/// ```
+pub struct SortedList<T: Ord> {
+    list: LinkedList<T>,
+}
+
+impl<T: Ord> SortedList<T> {
+    /// Creates a new empty SortedList.
+    /// ```
+    /// use linked_list::SortedList;
+    /// let list: SortedList<i32> = SortedList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            list: LinkedList::new(),
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    /// ```
+    /// use linked_list::SortedList;
+    /// let list = SortedList::from_iter([3, 1, 2]);
+    /// assert_eq!(list.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns true if the list is empty.
+    /// ```
+    /// use linked_list::SortedList;
+    /// let mut list = SortedList::new();
+    /// assert!(list.is_empty());
+    /// list.insert(1);
+    /// assert!(!list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Inserts an element at its sorted position.
+    /// Equal elements keep their insertion order, a new element goes after the existing ones.
+    /// ```
+    /// use linked_list::SortedList;
+    /// let mut list = SortedList::new();
+    /// list.insert(5); list.insert(1); list.insert(3); list.insert(4);
+    /// assert!(list.iter().eq([1, 3, 4, 5].iter()));
+    /// ```
+    pub fn insert(&mut self, elem: T) {
+        // element is not smaller than the largest one, it goes to the back
+        if self.list.peek_back().is_none_or(|back| *back <= elem) {
+            return self.list.push_back(elem);
+        }
+        // element is smaller than the smallest one, it goes to the front
+        if self.list.peek_front().is_none_or(|front| *front > elem) {
+            return self.list.push_front(elem);
+        }
+        // otherwise walk from the back until a node not greater than elem is found.
+        // the front node is not greater than elem, so the cursor never wraps around.
+        let mut cursor = self.list.cursor_back_mut().unwrap();
+        while *cursor.current_mut().0 > elem {
+            cursor.move_prev();
+        }
+        cursor.insert(elem);
+    }
+
+    /// Inserts an element at its sorted position.
+    /// Returns the element back inside `AllocError` if the node could not be allocated.
+    /// ```
+    /// use linked_list::SortedList;
+    /// let mut list = SortedList::from_iter([1, 3]);
+    /// assert!(list.try_insert(2).is_ok());
+    /// assert!(list.iter().eq([1, 2, 3].iter()));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_insert(&mut self, elem: T) -> Result<(), AllocError<T>> {
+        if self.list.peek_back().is_none_or(|back| *back <= elem) {
+            return self.list.try_push_back(elem);
+        }
+        if self.list.peek_front().is_none_or(|front| *front > elem) {
+            return self.list.try_push_front(elem);
+        }
+        let mut cursor = self.list.cursor_back_mut().unwrap();
+        while *cursor.current_mut().0 > elem {
+            cursor.move_prev();
+        }
+        cursor.try_insert(elem)
+    }
+
+    /// Removes the smallest element from the list and returns it.
+    /// ```
+    /// use linked_list::SortedList;
+    /// let mut list = SortedList::from_iter([2, 1, 3]);
+    /// assert_eq!(list.pop_min(), Some(1));
+    /// assert_eq!(list.pop_min(), Some(2));
+    /// assert_eq!(list.pop_min(), Some(3));
+    /// assert_eq!(list.pop_min(), None);
+    /// ```
+    pub fn pop_min(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    /// Removes the largest element from the list and returns it.
+    /// ```
+    /// use linked_list::SortedList;
+    /// let mut list = SortedList::from_iter([2, 1, 3]);
+    /// assert_eq!(list.pop_max(), Some(3));
+    /// assert_eq!(list.pop_max(), Some(2));
+    /// assert_eq!(list.pop_max(), Some(1));
+    /// assert_eq!(list.pop_max(), None);
+    /// ```
+    pub fn pop_max(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+
+    /// Returns the reference to the smallest element.
+    /// ```
+    /// use linked_list::SortedList;
+    /// let list = SortedList::from_iter([2, 1, 3]);
+    /// assert_eq!(list.peek_min(), Some(&1));
+    /// ```
+    pub fn peek_min(&self) -> Option<&T> {
+        self.list.peek_front()
+    }
+
+    /// Returns the reference to the largest element.
+    /// ```
+    /// use linked_list::SortedList;
+    /// let list = SortedList::from_iter([2, 1, 3]);
+    /// assert_eq!(list.peek_max(), Some(&3));
+    /// ```
+    pub fn peek_max(&self) -> Option<&T> {
+        self.list.peek_back()
+    }
+
+    /// Returns true if the list contains the given value.
+    /// Search stops as soon as a greater element is found.
+    /// ```
+    /// use linked_list::SortedList;
+    /// let list = SortedList::from_iter([2, 1, 3]);
+    /// assert!(list.contains(&2));
+    /// assert!(!list.contains(&4));
+    /// ```
+    pub fn contains(&self, item: &T) -> bool {
+        self.list
+            .iter()
+            .take_while(|elem| *elem <= item)
+            .any(|elem| elem == item)
+    }
+
+    /// Returns an iterator yielding &T in ascending order.
+    /// ```
+    /// use linked_list::SortedList;
+    /// let list = SortedList::from_iter([2, 1, 3]);
+    /// assert!(list.iter().eq([1, 2, 3].iter()));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+
+    /// Returns the reference to the underlying sorted `LinkedList`.
+    /// ```
+    /// use linked_list::{LinkedList, SortedList};
+    /// let list = SortedList::from_iter([2, 1, 3]);
+    /// assert_eq!(list.as_list(), &LinkedList::from([1, 2, 3]));
+    /// ```
+    pub fn as_list(&self) -> &LinkedList<T> {
+        &self.list
+    }
+
+    /// Consumes the SortedList and returns the underlying sorted `LinkedList`.
+    /// ```
+    /// use linked_list::{LinkedList, SortedList};
+    /// let list = SortedList::from_iter([2, 1, 3]);
+    /// assert_eq!(list.into_list(), LinkedList::from([1, 2, 3]));
+    /// ```
+    pub fn into_list(self) -> LinkedList<T> {
+        self.list
+    }
+}
+
+impl<T: Ord> Default for SortedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Clone> Clone for SortedList<T> {
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list.clone(),
+        }
+    }
+}
+
+impl<T: Ord + Debug> Debug for SortedList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list().entries(self.list.iter()).finish()
+    }
+}
+
+impl<T: Ord> From<LinkedList<T>> for SortedList<T> {
+    /// Creates a SortedList from an unsorted list by sorting its elements.
+    /// Sorting is stable, equal elements keep their relative order.
+    /// ```
+    /// use linked_list::{LinkedList, SortedList};
+    /// let list = SortedList::from(LinkedList::from([3, 1, 2]));
+    /// assert!(list.iter().eq([1, 2, 3].iter()));
+    /// ```
+    fn from(list: LinkedList<T>) -> Self {
+        // move the values out into contiguous storage, sort them there
+        // and move them back into a fresh list
+        let mut elems = list.into_iter().collect::<Vec<T>>();
+        elems.sort();
+        Self {
+            list: elems.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SortedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<LinkedList<T>>())
+    }
+}
+
+impl<T: Ord> Extend<T> for SortedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.insert(elem);
+        }
+    }
+}
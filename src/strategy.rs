@@ -0,0 +1,45 @@
+//! proptest support, enabled with the `proptest` feature.
+//! `any_linked_list` builds a strategy generating lists out of a strategy for the elements,
+//! and `LinkedList<T>` implements `proptest::arbitrary::Arbitrary` so `any::<LinkedList<T>>()` works.
+//! A failing list is shrunk by removing nodes first, then by shrinking the remaining elements.
+
+use crate::LinkedList;
+use proptest::arbitrary::{any_with, Arbitrary};
+use proptest::collection::{vec, SizeRange, VecStrategy};
+use proptest::strategy::{Map, Strategy};
+use std::fmt::Debug;
+
+/// Strategy generating a `LinkedList` returned by `any_linked_list`.
+pub type LinkedListStrategy<S> =
+    Map<VecStrategy<S>, fn(Vec<<S as Strategy>::Value>) -> LinkedList<<S as Strategy>::Value>>;
+
+/// Returns a strategy generating lists with a length in `size`
+/// and elements generated by `element`.
+///
+/// Example:
+/// ```
+/// use linked_list::strategy::any_linked_list;
+/// use proptest::prelude::*;
+/// proptest!(|(list in any_linked_list(0..100u32, 1..10))| {
+///     prop_assert!(!list.is_empty() && list.len() < 10);
+///     prop_assert!(list.iter().all(|&n| n < 100));
+/// });
+/// ```
+pub fn any_linked_list<S>(element: S, size: impl Into<SizeRange>) -> LinkedListStrategy<S>
+where
+    S: Strategy,
+    S::Value: Debug,
+{
+    // the Vec strategy shrinks by removing elements, the list keeps the same order
+    vec(element, size).prop_map(LinkedList::from_iter as fn(_) -> _)
+}
+
+// Implement proptest Arbitrary for LinkedList
+impl<T: Arbitrary> Arbitrary for LinkedList<T> {
+    type Parameters = (SizeRange, T::Parameters);
+    type Strategy = LinkedListStrategy<T::Strategy>;
+
+    fn arbitrary_with((size, args): Self::Parameters) -> Self::Strategy {
+        any_linked_list(any_with::<T>(args), size)
+    }
+}
@@ -1,11 +1,14 @@
 use crate::combinatorics::{IntoIter, Iter, IterMut};
+use crate::Link;
 use crate::LinkedList;
 use std::cmp::Ordering;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
 use std::fmt::{Debug, Display};
 use std::hash::{Hash, Hasher};
+use std::iter::Sum;
 use std::marker::{Send, Sync};
+use std::ops::{Add, AddAssign};
 
 // Implement Send trait for the LinkedList
 // This marker trait indicates that the type
@@ -42,28 +45,72 @@ impl<T: Clone> Clone for LinkedList<T> {
 // Implement Debug trait for LinkedList
 // This will provide the ability to print the list with Debug marker
 impl<T: Debug> Debug for LinkedList<T> {
-    /// Allows the list to be printed with debug marker
+    /// Allows the list to be printed with debug marker.
+    /// Only the values are printed, `{:#?}` prints one value per line.
+    /// Use `debug_structure` to see the nodes and their pointers.
     /// ```
     /// use linked_list::LinkedList;
     /// let mut list = LinkedList::new();
     /// list.push_back(1); list.push_back(2); list.push_back(3);
-    /// println!("{:?}", list);
+    /// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+    /// assert_eq!(format!("{:#?}", list), "[\n    1,\n    2,\n    3,\n]");
     /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Debug adapter printing every node of the list with its address
+/// and its `prev` and `next` pointers.
+/// This struct can be instantiated by calling `debug_structure` method on the LinkedList.
+pub struct DebugStructure<'a, T>(&'a LinkedList<T>);
+
+impl<'a, T: Debug> Debug for DebugStructure<'a, T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         let mut debug_list = f.debug_list();
-        let mut head = self.head;
-        // walk over the entire list and debug print each node.
-        while !head.is_null() {
+        let mut curr = self.0.head;
+        // walk over the entire list and debug print each node with its pointers
+        while !curr.is_null() {
             unsafe {
-                debug_list.entry(&head);
-                debug_list.entry(&*head);
-                head = (*head).next;
+                debug_list.entry(&NodeEntry(curr));
+                curr = curr.next();
             }
         }
         debug_list.finish()
     }
 }
 
+// Debug output of a single node for `DebugStructure`
+struct NodeEntry<T>(Link<T>);
+
+impl<T: Debug> Debug for NodeEntry<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        unsafe {
+            f.debug_struct("Node")
+                .field("addr", &self.0)
+                .field("prev", &self.0.prev())
+                .field("next", &self.0.next())
+                .field("val", self.0.val())
+                .finish()
+        }
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Returns an adapter which debug prints the nodes of the list
+    /// with their addresses and `prev` and `next` pointers.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2]);
+    /// let structure = format!("{:?}", list.debug_structure());
+    /// assert!(structure.starts_with("[Node { addr: 0x"));
+    /// assert!(structure.contains("prev: 0x0"));
+    /// ```
+    pub fn debug_structure(&self) -> DebugStructure<'_, T> {
+        DebugStructure(self)
+    }
+}
+
 // Implement Display trait for LinkedList.
 // This will provide the ability to convert list into String.
 impl<T: Display> Display for LinkedList<T> {
@@ -85,6 +132,55 @@ impl<T: Display> Display for LinkedList<T> {
     }
 }
 
+/// Display adapter rendering the elements between a prefix and a suffix,
+/// separated by a separator.
+/// This struct can be instantiated by calling `display_with` method on the LinkedList.
+pub struct DisplayWith<'a, T> {
+    list: &'a LinkedList<T>,
+    separator: &'a str,
+    prefix: &'a str,
+    suffix: &'a str,
+}
+
+impl<'a, T: Display> Display for DisplayWith<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.prefix)?;
+        for (i, elem) in self.list.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", self.separator)?;
+            }
+            // forward the formatter so width and precision apply to every element
+            Display::fmt(elem, f)?;
+        }
+        write!(f, "{}", self.suffix)
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Returns an adapter which displays the elements with the given separator,
+    /// prefix and suffix instead of the default `HEAD -> a <-> b -> END` format.
+    /// Formatting flags like width and precision are applied to every element.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1.0, 2.5, 3.0]);
+    /// assert_eq!(list.display_with(", ", "", "").to_string(), "1, 2.5, 3");
+    /// assert_eq!(format!("{:.1}", list.display_with(" | ", "[", "]")), "[1.0 | 2.5 | 3.0]");
+    /// ```
+    pub fn display_with<'a>(
+        &'a self,
+        separator: &'a str,
+        prefix: &'a str,
+        suffix: &'a str,
+    ) -> DisplayWith<'a, T> {
+        DisplayWith {
+            list: self,
+            separator,
+            prefix,
+            suffix,
+        }
+    }
+}
+
 // Implement Default trait for LinkedList
 
 impl<T> Default for LinkedList<T> {
@@ -193,6 +289,74 @@ impl<T: PartialEq> PartialEq for LinkedList<T> {
     }
 }
 
+// Implement PartialEq<[U]> for LinkedList
+impl<T: PartialEq<U>, U> PartialEq<[U]> for LinkedList<T> {
+    /// Allow to compare a list with a slice element by element
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// assert_eq!(list, [1, 2, 3][..]);
+    /// assert_ne!(list, [1, 2][..]);
+    /// ```
+    fn eq(&self, other: &[U]) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+// Implement PartialEq<[U; N]> for LinkedList
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for LinkedList<T> {
+    /// Allow to compare a list with an array element by element
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// assert_eq!(list, [1, 2, 3]);
+    /// assert_ne!(list, [3, 2, 1]);
+    /// ```
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+// Implement PartialEq<Vec<U>> for LinkedList
+impl<T: PartialEq<U>, U> PartialEq<Vec<U>> for LinkedList<T> {
+    /// Allow to compare a list with a vector element by element
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from(["a".to_string(), "b".to_string()]);
+    /// assert_eq!(list, vec!["a", "b"]);
+    /// ```
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+// Implement PartialEq<LinkedList<U>> for slices, arrays and vectors
+impl<T: PartialEq<U>, U> PartialEq<LinkedList<U>> for [T] {
+    /// Allow to compare a slice, an array or a vector with a list element by element
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// assert_eq!([1, 2, 3], list);
+    /// assert_eq!([1, 2, 3][..], list);
+    /// assert_eq!(vec![1, 2, 3], list);
+    /// ```
+    fn eq(&self, other: &LinkedList<U>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<LinkedList<U>> for [T; N] {
+    fn eq(&self, other: &LinkedList<U>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<LinkedList<U>> for Vec<T> {
+    fn eq(&self, other: &LinkedList<U>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
 // Implement Eq for LinkedList
 impl<T: Eq> Eq for LinkedList<T> {}
 
@@ -307,6 +471,70 @@ impl<T> Extend<T> for LinkedList<T> {
     }
 }
 
+// Implement Extend<LinkedList<T>> for LinkedList<T>
+impl<T> Extend<LinkedList<T>> for LinkedList<T> {
+    /// Moves the nodes of every given list to the back of this list.
+    /// No element is cloned or reallocated, every list is linked in O(1) after it is yielded.
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2]);
+    /// list.extend([LinkedList::from([3]), LinkedList::new(), LinkedList::from([4, 5])]);
+    /// assert!(list.iter().eq([1, 2, 3, 4, 5].iter()));
+    /// ```
+    fn extend<I: IntoIterator<Item = LinkedList<T>>>(&mut self, iter: I) {
+        // relink each list behind the current tail
+        for mut other in iter.into_iter() {
+            self.append(&mut other);
+        }
+    }
+}
+
+// Implement Add for LinkedList
+impl<T> Add for LinkedList<T> {
+    type Output = Self;
+
+    /// Concatenates two lists by moving the nodes of the right list behind the left one in O(1).
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2]) + LinkedList::from([3]) + LinkedList::new();
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    fn add(mut self, mut rhs: Self) -> Self {
+        self.append(&mut rhs);
+        self
+    }
+}
+
+// Implement AddAssign for LinkedList
+impl<T> AddAssign for LinkedList<T> {
+    /// Moves the nodes of the right list behind this list in O(1).
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let mut list = LinkedList::from([1, 2]);
+    /// list += LinkedList::from([3, 4]);
+    /// assert_eq!(list, [1, 2, 3, 4]);
+    /// ```
+    fn add_assign(&mut self, mut rhs: Self) {
+        self.append(&mut rhs);
+    }
+}
+
+// Implement Sum<LinkedList<T>> for LinkedList<T>
+impl<T> Sum for LinkedList<T> {
+    /// Concatenates all the lists of the iterator, each one is linked in O(1).
+    /// ```
+    /// use linked_list::LinkedList;
+    /// let chunks = vec![LinkedList::from([1, 2]), LinkedList::new(), LinkedList::from([3])];
+    /// let list: LinkedList<i32> = chunks.into_iter().sum();
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let mut new_list = Self::new();
+        new_list.extend(iter);
+        new_list
+    }
+}
+
 // Implement Extend<T> for LinkedList<T>
 impl<'a, T: Clone + 'a> Extend<&'a T> for LinkedList<T> {
     /// Returns a new LinkedList from the given array
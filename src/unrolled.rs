@@ -0,0 +1,844 @@
+//! Unrolled linked list.
+//! Every node (chunk) in the list holds a small inline array of up to `CHUNK` elements
+//! alongside the `prev` and `next` pointers. Walking over the elements inside a chunk
+//! does not chase any pointers, which gives much better iteration throughput than
+//! the one element per node `LinkedList`.
+//! Elements of a chunk are always kept contiguous at the start of its array.
+
+#[cfg(feature = "fallible-alloc")]
+use crate::AllocError;
+use crate::IndexOutOfBounds;
+#[cfg(feature = "fallible-alloc")]
+use std::alloc::{alloc, Layout};
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ptr;
+
+type ChunkLink<T, const CHUNK: usize> = *const Chunk<T, CHUNK>;
+type ChunkLinkMut<T, const CHUNK: usize> = *mut Chunk<T, CHUNK>;
+
+// Chunk struct represents each node in the unrolled list
+// contains up to CHUNK values owned by the node, the number of
+// initialized values and two pointers to previous and next chunk
+struct Chunk<T, const CHUNK: usize> {
+    elems: [MaybeUninit<T>; CHUNK],
+    len: usize,
+    prev: ChunkLink<T, CHUNK>,
+    next: ChunkLink<T, CHUNK>,
+}
+
+impl<T, const CHUNK: usize> Chunk<T, CHUNK> {
+    // creates a new empty chunk and returns the raw pointer to it
+    // prev and next pointers are initialized with null values
+    fn new_raw() -> ChunkLinkMut<T, CHUNK> {
+        Box::into_raw(Box::new(Self {
+            // an array of `MaybeUninit` does not require initialization
+            elems: unsafe { MaybeUninit::<[MaybeUninit<T>; CHUNK]>::uninit().assume_init() },
+            len: 0,
+            prev: ptr::null(),
+            next: ptr::null(),
+        }))
+    }
+
+    // allocates a new empty chunk without aborting on failure,
+    // memory comes from the global allocator with the layout of the chunk
+    // so it can be freed with `Box::from_raw` like every other chunk.
+    #[cfg(feature = "fallible-alloc")]
+    fn try_new_raw() -> Option<ChunkLinkMut<T, CHUNK>> {
+        let layout = Layout::new::<Self>();
+        unsafe {
+            let new_chunk = alloc(layout) as ChunkLinkMut<T, CHUNK>;
+            if new_chunk.is_null() {
+                return None;
+            }
+            // elems are left uninitialized, only the bookkeeping fields are written
+            ptr::addr_of_mut!((*new_chunk).len).write(0);
+            ptr::addr_of_mut!((*new_chunk).prev).write(ptr::null());
+            ptr::addr_of_mut!((*new_chunk).next).write(ptr::null());
+            Some(new_chunk)
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.len == CHUNK
+    }
+}
+
+/// Unrolled (chunked) doubly linked list.
+/// Each node holds up to `CHUNK` elements.
+///
+/// Example:
+/// ```
+/// use linked_list::UnrolledList;
+/// let list: UnrolledList<u32, 8> = UnrolledList::new();
+/// ```
+pub struct UnrolledList<T, const CHUNK: usize> {
+    head: ChunkLink<T, CHUNK>,
+    tail: ChunkLink<T, CHUNK>,
+    _phantom: PhantomData<T>,
+}
+
+fn to_mut_chunk<T, const CHUNK: usize>(ptr: ChunkLink<T, CHUNK>) -> ChunkLinkMut<T, CHUNK> {
+    ptr as ChunkLinkMut<T, CHUNK>
+}
+
+impl<T, const CHUNK: usize> UnrolledList<T, CHUNK> {
+    /// Creates a new instance of the UnrolledList.
+    /// Panics if `CHUNK` is zero.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let list: UnrolledList<i32, 4> = UnrolledList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        assert!(
+            CHUNK > 0,
+            "UnrolledList chunk size must be greater than zero"
+        );
+        Self {
+            head: ptr::null(),
+            tail: ptr::null(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    /// This walks over the chunks, not over every element.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let mut list: UnrolledList<i32, 2> = UnrolledList::new();
+    /// assert_eq!(list.len(), 0);
+    /// list.push_back(1); list.push_back(2); list.push_back(3);
+    /// assert_eq!(list.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        let mut count = 0;
+        let mut curr = self.head;
+        while !curr.is_null() {
+            unsafe {
+                count += (*curr).len;
+                curr = (*curr).next;
+            }
+        }
+        count
+    }
+
+    /// Returns true if the list is empty.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let mut list: UnrolledList<char, 4> = UnrolledList::new();
+    /// assert!(list.is_empty());
+    /// list.push_front('a');
+    /// assert!(!list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        // empty chunks are always freed, so a non null head means elements are present
+        self.head.is_null()
+    }
+
+    /// Removes all elements from the list.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let mut list: UnrolledList<i32, 2> = (0..5).collect();
+    /// list.clear();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        let mut curr = self.head;
+        while !curr.is_null() {
+            unsafe {
+                // take out the chunk and drop every initialized element in it
+                let mut chunk = Box::from_raw(to_mut_chunk(curr));
+                curr = chunk.next;
+                for elem in &mut chunk.elems[..chunk.len] {
+                    elem.assume_init_drop();
+                }
+            }
+        }
+        self.head = ptr::null();
+        self.tail = ptr::null();
+    }
+
+    /// Adds an element onto the front of the list.
+    /// A new chunk is allocated only when the first chunk is full.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let mut list: UnrolledList<i32, 2> = UnrolledList::new();
+    /// list.push_front(1); list.push_front(2); list.push_front(3);
+    /// assert_eq!(list.peek_front(), Some(&3));
+    /// assert_eq!(list.peek_back(), Some(&1));
+    /// ```
+    pub fn push_front(&mut self, elem: T) {
+        // allocate a new head chunk if there is no room in the current one
+        if self.head_is_full() {
+            self.link_front_chunk(Chunk::new_raw());
+        }
+        unsafe { self.write_front(elem) }
+    }
+
+    /// Adds an element onto the front of the list.
+    /// Returns the element back inside `AllocError` if a new chunk was needed but could not be allocated.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let mut list: UnrolledList<i32, 2> = UnrolledList::new();
+    /// assert!(list.try_push_front(1).is_ok());
+    /// assert_eq!(list.peek_front(), Some(&1));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_push_front(&mut self, elem: T) -> Result<(), AllocError<T>> {
+        if self.head_is_full() {
+            match Chunk::try_new_raw() {
+                Some(new_chunk) => self.link_front_chunk(new_chunk),
+                None => return Err(AllocError(elem)),
+            }
+        }
+        unsafe { self.write_front(elem) }
+        Ok(())
+    }
+
+    // returns true if there is no room left at the front of the list
+    fn head_is_full(&self) -> bool {
+        self.head.is_null() || unsafe { (*self.head).is_full() }
+    }
+
+    // links an already allocated empty chunk in front of the current head
+    fn link_front_chunk(&mut self, new_chunk: ChunkLinkMut<T, CHUNK>) {
+        unsafe {
+            (*new_chunk).next = self.head;
+            if self.head.is_null() {
+                self.tail = new_chunk;
+            } else {
+                (*to_mut_chunk(self.head)).prev = new_chunk;
+            }
+        }
+        self.head = new_chunk;
+    }
+
+    // writes the element at the front of the head chunk,
+    // head chunk must exist and must have room for one more element
+    unsafe fn write_front(&mut self, elem: T) {
+        let head = &mut *to_mut_chunk(self.head);
+        // shift existing elements one slot to the right to make room at the front
+        let base = head.elems.as_mut_ptr();
+        ptr::copy(base, base.add(1), head.len);
+        head.elems[0].write(elem);
+        head.len += 1;
+    }
+
+    /// Adds an element onto the back of the list.
+    /// A new chunk is allocated only when the last chunk is full.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let mut list: UnrolledList<i32, 2> = UnrolledList::new();
+    /// list.push_back(1); list.push_back(2); list.push_back(3);
+    /// assert_eq!(list.peek_front(), Some(&1));
+    /// assert_eq!(list.peek_back(), Some(&3));
+    /// ```
+    pub fn push_back(&mut self, elem: T) {
+        // allocate a new tail chunk if there is no room in the current one
+        if self.tail_is_full() {
+            self.link_back_chunk(Chunk::new_raw());
+        }
+        unsafe { self.write_back(elem) }
+    }
+
+    /// Adds an element onto the back of the list.
+    /// Returns the element back inside `AllocError` if a new chunk was needed but could not be allocated.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let mut list: UnrolledList<i32, 2> = UnrolledList::new();
+    /// assert!(list.try_push_back(1).is_ok());
+    /// assert_eq!(list.peek_back(), Some(&1));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_push_back(&mut self, elem: T) -> Result<(), AllocError<T>> {
+        if self.tail_is_full() {
+            match Chunk::try_new_raw() {
+                Some(new_chunk) => self.link_back_chunk(new_chunk),
+                None => return Err(AllocError(elem)),
+            }
+        }
+        unsafe { self.write_back(elem) }
+        Ok(())
+    }
+
+    // returns true if there is no room left at the back of the list
+    fn tail_is_full(&self) -> bool {
+        self.tail.is_null() || unsafe { (*self.tail).is_full() }
+    }
+
+    // links an already allocated empty chunk after the current tail
+    fn link_back_chunk(&mut self, new_chunk: ChunkLinkMut<T, CHUNK>) {
+        unsafe {
+            (*new_chunk).prev = self.tail;
+            if self.tail.is_null() {
+                self.head = new_chunk;
+            } else {
+                (*to_mut_chunk(self.tail)).next = new_chunk;
+            }
+        }
+        self.tail = new_chunk;
+    }
+
+    // writes the element at the back of the tail chunk,
+    // tail chunk must exist and must have room for one more element
+    unsafe fn write_back(&mut self, elem: T) {
+        let tail = &mut *to_mut_chunk(self.tail);
+        tail.elems[tail.len].write(elem);
+        tail.len += 1;
+    }
+
+    /// Removes the first element of the list and returns it.
+    /// Returns `None` if the list is empty.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let mut list: UnrolledList<i32, 2> = (1..=3).collect();
+    /// assert_eq!(list.pop_front(), Some(1));
+    /// assert_eq!(list.pop_front(), Some(2));
+    /// assert_eq!(list.pop_front(), Some(3));
+    /// assert_eq!(list.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        // if head is null that means list is empty return None
+        if self.head.is_null() {
+            return None;
+        }
+        unsafe {
+            let head = &mut *to_mut_chunk(self.head);
+            let elem = head.elems[0].assume_init_read();
+            head.len -= 1;
+            // shift the remaining elements one slot to the left
+            let base = head.elems.as_mut_ptr();
+            ptr::copy(base.add(1), base, head.len);
+            if head.len == 0 {
+                self.unlink_head();
+            }
+            Some(elem)
+        }
+    }
+
+    /// Removes the last element of the list and returns it.
+    /// Returns `None` if the list is empty.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let mut list: UnrolledList<i32, 2> = (1..=3).collect();
+    /// assert_eq!(list.pop_back(), Some(3));
+    /// assert_eq!(list.pop_back(), Some(2));
+    /// assert_eq!(list.pop_back(), Some(1));
+    /// assert_eq!(list.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        // if tail is null that means list is empty return None
+        if self.tail.is_null() {
+            return None;
+        }
+        unsafe {
+            let tail = &mut *to_mut_chunk(self.tail);
+            tail.len -= 1;
+            let elem = tail.elems[tail.len].assume_init_read();
+            if tail.len == 0 {
+                self.unlink_tail();
+            }
+            Some(elem)
+        }
+    }
+
+    // frees the empty head chunk and moves head one chunk towards back
+    unsafe fn unlink_head(&mut self) {
+        let chunk = Box::from_raw(to_mut_chunk(self.head));
+        self.head = chunk.next;
+        if self.head.is_null() {
+            self.tail = ptr::null();
+        } else {
+            (*to_mut_chunk(self.head)).prev = ptr::null();
+        }
+    }
+
+    // frees the empty tail chunk and moves tail one chunk towards front
+    unsafe fn unlink_tail(&mut self) {
+        let chunk = Box::from_raw(to_mut_chunk(self.tail));
+        self.tail = chunk.prev;
+        if self.tail.is_null() {
+            self.head = ptr::null();
+        } else {
+            (*to_mut_chunk(self.tail)).next = ptr::null();
+        }
+    }
+
+    /// Returns the reference to the first element of the list.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let mut list: UnrolledList<i32, 2> = UnrolledList::new();
+    /// assert_eq!(list.peek_front(), None);
+    /// list.push_back(1); list.push_back(2);
+    /// assert_eq!(list.peek_front(), Some(&1));
+    /// ```
+    pub fn peek_front(&self) -> Option<&T> {
+        if self.head.is_null() {
+            return None;
+        }
+        unsafe { Some((*self.head).elems[0].assume_init_ref()) }
+    }
+
+    /// Returns the reference to the last element of the list.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let mut list: UnrolledList<i32, 2> = UnrolledList::new();
+    /// assert_eq!(list.peek_back(), None);
+    /// list.push_back(1); list.push_back(2);
+    /// assert_eq!(list.peek_back(), Some(&2));
+    /// ```
+    pub fn peek_back(&self) -> Option<&T> {
+        if self.tail.is_null() {
+            return None;
+        }
+        unsafe {
+            let tail = &*self.tail;
+            Some(tail.elems[tail.len - 1].assume_init_ref())
+        }
+    }
+
+    /// Returns the reference to the element at the given index.
+    /// Whole chunks are skipped while seeking, so this takes O(n / CHUNK) time.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let list: UnrolledList<i32, 3> = (0..10).collect();
+    /// assert_eq!(list.get(0), Some(&0));
+    /// assert_eq!(list.get(7), Some(&7));
+    /// assert_eq!(list.get(10), None);
+    /// ```
+    pub fn get(&self, mut index: usize) -> Option<&T> {
+        let mut curr = self.head;
+        while !curr.is_null() {
+            unsafe {
+                let chunk = &*curr;
+                if index < chunk.len {
+                    return Some(chunk.elems[index].assume_init_ref());
+                }
+                index -= chunk.len;
+                curr = chunk.next;
+            }
+        }
+        None
+    }
+
+    /// Insert an element at a given index, shifting the following elements towards back.
+    /// When the target chunk is full it is split in half to make room.
+    /// Note: Final index at the list will wrap around when length of the list is lesser.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let mut list: UnrolledList<i32, 2> = (0..4).collect();
+    /// list.insert_at(10, 1);
+    /// list.insert_at(20, 5);
+    /// list.insert_at(30, 8);
+    /// assert!(list.iter().eq([0, 10, 30, 1, 2, 3, 20].iter()));
+    /// ```
+    pub fn insert_at(&mut self, elem: T, index: usize) {
+        let index = self.wrap_insert_index(index);
+        let Some((chunk, offset)) = self.locate(index) else {
+            // index is right after the last element
+            return self.push_back(elem);
+        };
+        unsafe {
+            let (chunk, offset) = if (*chunk).is_full() {
+                self.split_chunk(chunk, offset, Chunk::new_raw())
+            } else {
+                (chunk, offset)
+            };
+            Self::write_at(chunk, offset, elem);
+        }
+    }
+
+    /// Insert an element at a given index.
+    /// Unlike `insert_at` the index does not wrap around,
+    /// an index equal to the length of the list pushes the element to the back.
+    /// Returns error if the index is greater than the length of the list.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let mut list: UnrolledList<i32, 2> = [1, 2, 4].into_iter().collect();
+    /// assert!(list.try_insert_at(3, 2).is_ok());
+    /// assert!(list.try_insert_at(5, 4).is_ok());
+    /// assert!(list.try_insert_at(7, 6).is_err());
+    /// assert!(list.iter().eq([1, 2, 3, 4, 5].iter()));
+    /// ```
+    pub fn try_insert_at(&mut self, elem: T, index: usize) -> Result<(), IndexOutOfBounds> {
+        if index > self.len() {
+            return Err(IndexOutOfBounds);
+        }
+        // `insert_at` does not wrap around for indices up to the length
+        self.insert_at(elem, index);
+        Ok(())
+    }
+
+    /// Insert an element at a given index, like `insert_at`.
+    /// Note: Final index at the list will wrap around when length of the list is lesser.
+    /// Returns the element back inside `AllocError` if the target chunk was full
+    /// and the chunk needed to split it could not be allocated.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let mut list: UnrolledList<i32, 2> = (0..2).collect();
+    /// assert!(list.try_alloc_insert_at(10, 1).is_ok());
+    /// assert!(list.iter().eq([0, 10, 1].iter()));
+    /// ```
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_alloc_insert_at(&mut self, elem: T, index: usize) -> Result<(), AllocError<T>> {
+        let index = self.wrap_insert_index(index);
+        let Some((chunk, offset)) = self.locate(index) else {
+            return self.try_push_back(elem);
+        };
+        unsafe {
+            let (chunk, offset) = if (*chunk).is_full() {
+                match Chunk::try_new_raw() {
+                    Some(new_chunk) => self.split_chunk(chunk, offset, new_chunk),
+                    None => return Err(AllocError(elem)),
+                }
+            } else {
+                (chunk, offset)
+            };
+            Self::write_at(chunk, offset, elem);
+        }
+        Ok(())
+    }
+
+    // the position an element inserted at `index` ends up at, wrapping around like `LinkedList::insert_at`:
+    // it goes after the element at `index - 1`, counted modulo the length
+    fn wrap_insert_index(&self, index: usize) -> usize {
+        if self.is_empty() || index == 0 {
+            return 0;
+        }
+        (index - 1) % self.len() + 1
+    }
+
+    // writes the element at the offset of a chunk which has room for one more element,
+    // the elements from offset onwards are shifted one slot to the right
+    unsafe fn write_at(chunk: ChunkLinkMut<T, CHUNK>, offset: usize, elem: T) {
+        let chunk = &mut *chunk;
+        let base = chunk.elems.as_mut_ptr();
+        ptr::copy(base.add(offset), base.add(offset + 1), chunk.len - offset);
+        chunk.elems[offset].write(elem);
+        chunk.len += 1;
+    }
+
+    /// Removes the element at the given index and returns it.
+    /// Returns `None` if the index does not exist.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let mut list: UnrolledList<i32, 2> = (0..5).collect();
+    /// assert_eq!(list.remove(2), Some(2));
+    /// assert_eq!(list.remove(4), None);
+    /// assert!(list.iter().eq([0, 1, 3, 4].iter()));
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let (chunk, offset) = self.locate(index)?;
+        unsafe {
+            let chunk = &mut *chunk;
+            let elem = chunk.elems[offset].assume_init_read();
+            chunk.len -= 1;
+            // shift the following elements one slot to the left
+            let base = chunk.elems.as_mut_ptr();
+            ptr::copy(base.add(offset + 1), base.add(offset), chunk.len - offset);
+            if chunk.len == 0 {
+                self.unlink_chunk(chunk);
+            }
+            Some(elem)
+        }
+    }
+
+    // returns the chunk holding the element at the given index
+    // and the offset of the element inside that chunk
+    fn locate(&self, mut index: usize) -> Option<(ChunkLinkMut<T, CHUNK>, usize)> {
+        let mut curr = self.head;
+        while !curr.is_null() {
+            unsafe {
+                if index < (*curr).len {
+                    return Some((to_mut_chunk(curr), index));
+                }
+                index -= (*curr).len;
+                curr = (*curr).next;
+            }
+        }
+        None
+    }
+
+    // moves the upper half of a full chunk into the allocated empty chunk and links it after it.
+    // returns the chunk and offset where the element at `offset` now lives,
+    // the returned chunk always has room for one more element.
+    unsafe fn split_chunk(
+        &mut self,
+        chunk: ChunkLinkMut<T, CHUNK>,
+        offset: usize,
+        new_chunk: ChunkLinkMut<T, CHUNK>,
+    ) -> (ChunkLinkMut<T, CHUNK>, usize) {
+        let mid = CHUNK / 2;
+        self.move_into_chunk(chunk, mid, new_chunk);
+        if offset <= mid {
+            (chunk, offset)
+        } else {
+            (new_chunk, offset - mid)
+        }
+    }
+
+    // moves the elements of the chunk from `from` onwards into the allocated empty chunk
+    // and links it right after the chunk
+    unsafe fn move_into_chunk(
+        &mut self,
+        chunk: ChunkLinkMut<T, CHUNK>,
+        from: usize,
+        new_chunk: ChunkLinkMut<T, CHUNK>,
+    ) {
+        let moved = (*chunk).len - from;
+        let base = (*chunk).elems.as_ptr();
+        ptr::copy_nonoverlapping(base.add(from), (*new_chunk).elems.as_mut_ptr(), moved);
+        (*new_chunk).len = moved;
+        (*chunk).len = from;
+        // link the new chunk between chunk and its next chunk
+        (*new_chunk).prev = chunk;
+        (*new_chunk).next = (*chunk).next;
+        if (*chunk).next.is_null() {
+            self.tail = new_chunk;
+        } else {
+            (*to_mut_chunk((*chunk).next)).prev = new_chunk;
+        }
+        (*chunk).next = new_chunk;
+    }
+
+    // unlinks and frees an empty chunk from anywhere in the list
+    unsafe fn unlink_chunk(&mut self, chunk: ChunkLinkMut<T, CHUNK>) {
+        let chunk = Box::from_raw(chunk);
+        if chunk.prev.is_null() {
+            self.head = chunk.next;
+        } else {
+            (*to_mut_chunk(chunk.prev)).next = chunk.next;
+        }
+        if chunk.next.is_null() {
+            self.tail = chunk.prev;
+        } else {
+            (*to_mut_chunk(chunk.next)).prev = chunk.prev;
+        }
+    }
+
+    /// Moves all elements from `other` to the end of the list.
+    /// The chunks of `other` are linked in as they are, so this takes O(1) time.
+    /// After this operation, other becomes empty.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let mut list1: UnrolledList<i32, 2> = (0..3).collect();
+    /// let mut list2: UnrolledList<i32, 2> = (3..5).collect();
+    /// list1.append(&mut list2);
+    /// assert!(list2.is_empty());
+    /// assert!(list1.iter().eq((0..5).collect::<Vec<_>>().iter()));
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        // if other is empty nothing to be done
+        if other.is_empty() {
+            return;
+        }
+        if self.tail.is_null() {
+            self.head = other.head;
+        } else {
+            unsafe {
+                (*to_mut_chunk(self.tail)).next = other.head;
+                (*to_mut_chunk(other.head)).prev = self.tail;
+            }
+        }
+        self.tail = other.tail;
+        other.head = ptr::null();
+        other.tail = ptr::null();
+    }
+
+    /// Splits the list into two at the given index.
+    /// Returns a new list holding the elements from `at` onwards.
+    /// Whole chunks are relinked as they are, only the elements following `at`
+    /// inside its own chunk are moved, so no more than `CHUNK` elements are copied.
+    /// Panics if `at > len`.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let mut list: UnrolledList<i32, 4> = (0..10).collect();
+    /// let tail = list.split_off(6);
+    /// assert!(list.iter().eq((0..6).collect::<Vec<_>>().iter()));
+    /// assert!(tail.iter().eq((6..10).collect::<Vec<_>>().iter()));
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let Some((chunk, offset)) = self.locate(at) else {
+            // index is right after the last element
+            assert!(at == self.len(), "split index out of bounds");
+            return Self::new();
+        };
+        unsafe {
+            // first chunk of the new list, a chunk is split only if `at` falls inside it
+            let first = if offset == 0 {
+                chunk
+            } else {
+                let new_chunk = Chunk::new_raw();
+                self.move_into_chunk(chunk, offset, new_chunk);
+                new_chunk
+            };
+            let other = Self {
+                head: first,
+                tail: self.tail,
+                _phantom: PhantomData,
+            };
+            self.tail = (*first).prev;
+            if self.tail.is_null() {
+                self.head = ptr::null();
+            } else {
+                (*to_mut_chunk(self.tail)).next = ptr::null();
+            }
+            (*first).prev = ptr::null();
+            other
+        }
+    }
+
+    /// Returns an iterator yielding &T from front to back.
+    /// ```
+    /// use linked_list::UnrolledList;
+    /// let list: UnrolledList<i32, 2> = (1..=3).collect();
+    /// let mut iter = list.iter();
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next_back(), Some(&3));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter(&self) -> UnrolledIter<'_, T, CHUNK> {
+        UnrolledIter {
+            head: self.head,
+            head_index: 0,
+            tail: self.tail,
+            tail_index: if self.tail.is_null() {
+                0
+            } else {
+                unsafe { (*self.tail).len }
+            },
+            size: self.len(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// Iterator over the elements of an `UnrolledList`.
+/// This struct can be instantiated by calling `iter` method in the UnrolledList.
+pub struct UnrolledIter<'a, T, const CHUNK: usize> {
+    head: ChunkLink<T, CHUNK>,
+    // index of the next element to be yielded from the head chunk
+    head_index: usize,
+    tail: ChunkLink<T, CHUNK>,
+    // one past the index of the next element to be yielded from the tail chunk
+    tail_index: usize,
+    size: usize,
+    _phantom: PhantomData<&'a T>,
+}
+
+impl<'a, T, const CHUNK: usize> Iterator for UnrolledIter<'a, T, CHUNK> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // size keeps track of the elements not yet yielded from either end
+        if self.size == 0 {
+            return None;
+        }
+        unsafe {
+            let chunk = &*self.head;
+            let elem = chunk.elems[self.head_index].assume_init_ref();
+            self.head_index += 1;
+            self.size -= 1;
+            // move on to the next chunk when this one is exhausted
+            if self.head_index == chunk.len {
+                self.head = chunk.next;
+                self.head_index = 0;
+            }
+            Some(elem)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.size, Some(self.size))
+    }
+}
+
+impl<'a, T, const CHUNK: usize> DoubleEndedIterator for UnrolledIter<'a, T, CHUNK> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.size == 0 {
+            return None;
+        }
+        unsafe {
+            let chunk = &*self.tail;
+            self.tail_index -= 1;
+            self.size -= 1;
+            let elem = chunk.elems[self.tail_index].assume_init_ref();
+            // move on to the previous chunk when this one is exhausted
+            if self.tail_index == 0 && !chunk.prev.is_null() {
+                self.tail = chunk.prev;
+                self.tail_index = (*self.tail).len;
+            }
+            Some(elem)
+        }
+    }
+}
+
+impl<'a, T, const CHUNK: usize> ExactSizeIterator for UnrolledIter<'a, T, CHUNK> {}
+impl<'a, T, const CHUNK: usize> FusedIterator for UnrolledIter<'a, T, CHUNK> {}
+
+impl<'a, T, const CHUNK: usize> IntoIterator for &'a UnrolledList<T, CHUNK> {
+    type Item = &'a T;
+    type IntoIter = UnrolledIter<'a, T, CHUNK>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+unsafe impl<T: Send, const CHUNK: usize> Send for UnrolledList<T, CHUNK> {}
+unsafe impl<T: Sync, const CHUNK: usize> Sync for UnrolledList<T, CHUNK> {}
+
+impl<T, const CHUNK: usize> Default for UnrolledList<T, CHUNK> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const CHUNK: usize> Drop for UnrolledList<T, CHUNK> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Debug, const CHUNK: usize> Debug for UnrolledList<T, CHUNK> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone, const CHUNK: usize> Clone for UnrolledList<T, CHUNK> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: PartialEq, const CHUNK: usize> PartialEq for UnrolledList<T, CHUNK> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const CHUNK: usize> Eq for UnrolledList<T, CHUNK> {}
+
+impl<T, const CHUNK: usize> Extend<T> for UnrolledList<T, CHUNK> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        // take each element in the interator and push_back into the list
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+impl<T, const CHUNK: usize> FromIterator<T> for UnrolledList<T, CHUNK> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut new_list = Self::new();
+        new_list.extend(iter);
+        new_list
+    }
+}
@@ -0,0 +1,312 @@
+#![cfg(feature = "fallible-alloc")]
+//! Runs the `try_` inserting methods against a global allocator which can be told to fail.
+
+use linked_list::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+thread_local! {
+    static FAIL_ALLOC: Cell<bool> = const { Cell::new(false) };
+    // allocations which still succeed while FAIL_ALLOC is set
+    static ALLOWED_ALLOCS: Cell<usize> = const { Cell::new(0) };
+}
+
+struct FailingAlloc;
+
+unsafe impl GlobalAlloc for FailingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if FAIL_ALLOC.with(|fail| fail.get()) {
+            let allowed = ALLOWED_ALLOCS.with(|allowed| allowed.get());
+            if allowed == 0 {
+                return std::ptr::null_mut();
+            }
+            ALLOWED_ALLOCS.with(|cell| cell.set(allowed - 1));
+        }
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: FailingAlloc = FailingAlloc;
+
+fn failing<R>(f: impl FnOnce() -> R) -> R {
+    failing_after(0, f)
+}
+
+// lets the first `allocs` allocations succeed, for methods which allocate scratch space before the nodes
+fn failing_after<R>(allocs: usize, f: impl FnOnce() -> R) -> R {
+    ALLOWED_ALLOCS.with(|allowed| allowed.set(allocs));
+    FAIL_ALLOC.with(|fail| fail.set(true));
+    let result = f();
+    FAIL_ALLOC.with(|fail| fail.set(false));
+    result
+}
+
+#[test]
+fn test_try_push() {
+    let mut list = LinkedList::from([2]);
+    assert!(list.try_push_front(1).is_ok());
+    assert!(list.try_push_back(3).is_ok());
+    assert_eq!(failing(|| list.try_push_front(0)), Err(AllocError(0)));
+    assert_eq!(failing(|| list.try_push_back(4)), Err(AllocError(4)));
+    let mut cursor = list.cursor_front_mut().unwrap();
+    assert_eq!(failing(|| cursor.try_insert(5)), Err(AllocError(5)));
+    assert!(list.iter().eq([1, 2, 3].iter()));
+    assert_eq!(list.pop_back(), Some(3));
+    assert_eq!(list.pop_front(), Some(1));
+}
+
+#[test]
+fn test_try_insert_sorted() {
+    let mut list = SortedList::from_iter([1, 3]);
+    assert_eq!(failing(|| list.try_insert(2)), Err(AllocError(2)));
+    assert!(list.try_insert(2).is_ok());
+    assert!(list.iter().eq([1, 2, 3].iter()));
+}
+
+#[test]
+fn test_try_push_unrolled() {
+    let mut list: UnrolledList<i32, 2> = UnrolledList::new();
+    assert_eq!(failing(|| list.try_push_back(1)), Err(AllocError(1)));
+    assert!(list.try_push_back(1).is_ok());
+    // there is still room in the chunk, no allocation is needed
+    assert!(failing(|| list.try_push_back(2)).is_ok());
+    assert_eq!(failing(|| list.try_push_back(3)), Err(AllocError(3)));
+    assert_eq!(failing(|| list.try_push_front(0)), Err(AllocError(0)));
+    assert!(list.iter().eq([1, 2].iter()));
+}
+
+#[test]
+fn test_try_extend_and_splice() {
+    let mut list = LinkedList::from([1, 2]);
+    assert_eq!(failing(|| list.try_extend([3, 4])), Err(AllocError(3)));
+    assert_eq!(failing(|| list.try_extend_front([0])), Err(AllocError(0)));
+    assert_eq!(
+        failing(|| list.try_extend_from_slice(&[3])),
+        Err(AllocError(3))
+    );
+    assert_eq!(
+        failing(|| list.try_splice_range(..1, [9])),
+        Err(AllocError(9))
+    );
+    assert_eq!(
+        failing(|| list.try_alloc_insert_at(9, 1)),
+        Err(AllocError(9))
+    );
+    assert!(list.iter().eq([1, 2].iter()));
+    assert!(list.try_extend([3]).is_ok());
+    assert!(list.try_extend_front([0]).is_ok());
+    assert!(list.try_extend_from_slice(&[4]).is_ok());
+    assert!(list.try_alloc_insert_at(5, 5).is_ok());
+    assert_eq!(list.try_splice_range(..1, [-1]), Ok(LinkedList::from([0])));
+    assert!(list.iter().eq([-1, 1, 2, 3, 4, 5].iter()));
+}
+
+#[test]
+fn test_try_extend_cloned() {
+    let mut list = LinkedList::from([1]);
+    assert_eq!(
+        failing(|| list.try_extend_cloned(&[2, 3])),
+        Err(AllocError(2))
+    );
+    assert!(list.try_extend_cloned(&[2, 3]).is_ok());
+    assert_eq!(list, [1, 2, 3]);
+    let mut list = SlabList::with_capacity(1);
+    list.push_back(1);
+    assert_eq!(failing(|| list.try_extend_cloned(&[2])), Err(AllocError(2)));
+    assert!(list.try_extend_cloned(&[2]).is_ok());
+    assert!(list.iter().eq([1, 2].iter()));
+}
+
+#[test]
+fn test_try_find_or_insert_by() {
+    let mut list = LinkedList::from([1, 4]);
+    // an equal element is found without allocating
+    assert_eq!(
+        failing(|| list.try_find_or_insert_by(|x| x.cmp(&4), || 4)),
+        Ok(&mut 4)
+    );
+    for key in [0, 2, 5] {
+        assert_eq!(
+            failing(|| list.try_find_or_insert_by(|x| x.cmp(&key), || key)),
+            Err(AllocError(key))
+        );
+        assert_eq!(
+            list.try_find_or_insert_by(|x| x.cmp(&key), || key),
+            Ok(&mut key.clone())
+        );
+    }
+    assert_eq!(list, [0, 1, 2, 4, 5]);
+}
+
+#[test]
+fn test_try_replace_sublist_and_apply_patch() {
+    let mut list = LinkedList::from([1, 2, 3, 1, 2]);
+    let replacement = LinkedList::from([0]);
+    // the needle is collected first, the copy of the first replacement fails
+    assert_eq!(
+        failing_after(1, || list.try_replace_sublist([1, 2], &replacement)),
+        Err(AllocError(0))
+    );
+    assert_eq!(list, [1, 2, 3, 1, 2]);
+    // the first replacement succeeds, the second one fails
+    assert_eq!(
+        failing_after(2, || list.try_replace_sublist([1, 2], &replacement)),
+        Err(AllocError(0))
+    );
+    assert_eq!(list, [0, 3, 1, 2]);
+    assert_eq!(list.try_replace_sublist([1, 2], &replacement), Ok(1));
+    assert_eq!(list, [0, 3, 0]);
+
+    let target = LinkedList::from([1, 3, 4]);
+    let script = list.diff(&target);
+    let copy = script.clone();
+    // the script is reused in place, only the list of steps is allocated before the nodes
+    assert_eq!(
+        failing_after(1, || list.try_apply_patch(copy)),
+        Err(AllocError(1))
+    );
+    assert_eq!(list, [0, 3, 0]);
+    assert_eq!(list.try_apply_patch([Edit::Keep]), Ok(Err(PatchError)));
+    assert_eq!(list.try_apply_patch(script), Ok(Ok(())));
+    assert_eq!(list, target);
+}
+
+#[test]
+fn test_try_push_with() {
+    let mut list = LinkedList::new();
+    let called = Cell::new(false);
+    let result = failing(|| {
+        list.try_push_back_with(|| {
+            called.set(true);
+            1
+        })
+    });
+    assert!(result.is_err());
+    assert!(!called.get());
+    assert!(failing(|| list.try_push_front_with(|| 1)).is_err());
+    assert!(list.try_push_back_with(|| 2).is_ok());
+    assert!(list.try_push_front_with(|| 1).is_ok());
+    assert!(list.iter().eq([1, 2].iter()));
+}
+
+#[test]
+fn test_try_insert_cursors() {
+    let mut cursor = LinkedList::from([1]).into_cursor_front().unwrap();
+    assert_eq!(failing(|| cursor.try_insert(2)), Err(AllocError(2)));
+    assert!(cursor.try_insert(2).is_ok());
+    let mut list = cursor.into_list();
+    let mut cursors = list.cursors();
+    let id = cursors.add_front().unwrap();
+    assert_eq!(
+        failing(|| cursors.try_insert_after(id, 0)),
+        Err(AllocError(0))
+    );
+    assert_eq!(
+        failing(|| cursors.try_insert_before(id, 0)),
+        Err(AllocError(0))
+    );
+    assert_eq!(cursors.try_insert_before(id, 0), Ok(true));
+    assert!(list.iter().eq([0, 1, 2].iter()));
+
+    let mut list = PinnedList::new();
+    list.push_back(1);
+    let mut cursor = list.cursor_front_mut().unwrap();
+    assert!(failing(|| cursor.try_insert(2).is_err()));
+    assert_eq!(*cursor.try_insert(2).unwrap(), 2);
+    assert!(failing(|| list.try_push_front(0).is_err()));
+    assert!(failing(|| list.try_push_back(3).is_err()));
+    assert_eq!(*list.try_push_back(3).unwrap(), 3);
+    assert!(list.iter().eq([1, 2, 3].iter()));
+}
+
+#[test]
+fn test_try_alloc_insert_at_unrolled() {
+    let mut list: UnrolledList<i32, 2> = (0..2).collect();
+    // the only chunk is full and has to be split
+    assert_eq!(
+        failing(|| list.try_alloc_insert_at(10, 1)),
+        Err(AllocError(10))
+    );
+    assert!(list.try_alloc_insert_at(10, 1).is_ok());
+    // the chunk holding index 2 has room left
+    assert!(failing(|| list.try_alloc_insert_at(20, 2)).is_ok());
+    assert!(list.iter().eq([0, 10, 20, 1].iter()));
+}
+
+#[test]
+fn test_try_push_keyed_and_slot() {
+    let mut keyed = KeyedList::new();
+    assert_eq!(
+        failing(|| keyed.try_push_back(1, 'a')),
+        Err(AllocError((1, 'a')))
+    );
+    assert_eq!(
+        failing(|| keyed.try_push_front(1, 'a')),
+        Err(AllocError((1, 'a')))
+    );
+    assert_eq!(keyed.try_push_back(1, 'a'), Ok(None));
+    // replacing the value of a present key does not allocate
+    assert_eq!(failing(|| keyed.try_push_front(1, 'b')), Ok(Some('a')));
+    assert_eq!(keyed.len(), 1);
+
+    let mut slots = SlotList::new();
+    assert_eq!(failing(|| slots.try_push_back(1)), Err(AllocError(1)));
+    assert_eq!(failing(|| slots.try_push_front(1)), Err(AllocError(1)));
+    let key = slots.try_push_back(1).unwrap();
+    assert_eq!(slots.remove(key), Some(1));
+    // the node still has to be allocated when a free slot is reused
+    assert_eq!(failing(|| slots.try_push_front(2)), Err(AllocError(2)));
+    let key = slots.try_push_front(2).unwrap();
+    assert_eq!(slots.get(key), Some(&2));
+    assert_eq!(slots.len(), 1);
+}
+
+#[cfg(feature = "slab")]
+#[test]
+fn test_try_push_slab() {
+    let mut list = SlabList::with_capacity(1);
+    // there is still room in the slab, no allocation is needed
+    assert!(failing(|| list.try_push_back(1)).is_ok());
+    assert_eq!(failing(|| list.try_push_front(0)), Err(AllocError(0)));
+    assert_eq!(list.pop_back(), Some(1));
+    // the freed node is reused without growing the slab
+    assert!(failing(|| list.try_push_front(2)).is_ok());
+    assert_eq!(
+        failing(|| list.try_alloc_insert_at(3, 1)),
+        Err(AllocError(3))
+    );
+    assert!(list.try_alloc_insert_at(3, 1).is_ok());
+    assert!(list.iter().eq([2, 3].iter()));
+
+    let mut list = SlabList::with_capacity(1);
+    list.push_back(1);
+    let mut cursor = list.cursor_front_mut().unwrap();
+    assert_eq!(failing(|| cursor.try_insert(2)), Err(AllocError(2)));
+    assert!(cursor.try_insert(2).is_ok());
+    assert!(list.iter().eq([1, 2].iter()));
+
+    let mut list = SlabList::with_capacity(1);
+    assert_eq!(failing(|| list.try_extend([1, 2])), Err(AllocError(2)));
+    assert_eq!(
+        failing(|| list.try_extend_from_slice(&[3])),
+        Err(AllocError(3))
+    );
+    assert!(list.iter().eq([1].iter()));
+}
+
+#[test]
+fn test_try_push_mpsc() {
+    let queue = mpsc::Queue::new();
+    let producer = queue.producer();
+    assert_eq!(failing(|| queue.try_push(1)), Err(AllocError(1)));
+    assert_eq!(failing(|| producer.try_push(1)), Err(AllocError(1)));
+    assert!(producer.try_push(1).is_ok());
+    assert!(queue.try_push(2).is_ok());
+    assert_eq!(queue.pop(), Some(1));
+    assert_eq!(queue.pop(), Some(2));
+}
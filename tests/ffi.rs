@@ -0,0 +1,41 @@
+#![cfg(feature = "ffi")]
+//! Drives the C interface from Rust the way a C caller would.
+
+use linked_list::ffi::*;
+
+#[test]
+fn test_ffi_list() {
+    unsafe {
+        let list = ll_new();
+        ll_push_back(list, 2);
+        ll_push_back(list, 3);
+        ll_push_front(list, 1);
+        assert_eq!(ll_len(list), 3);
+
+        let iter = ll_iter_new(list);
+        let mut out = 0;
+        let mut seen = Vec::new();
+        while ll_iter_next(iter, &mut out) {
+            seen.push(out);
+        }
+        assert_eq!(seen, [1, 2, 3]);
+        // an exhausted iterator stays exhausted
+        assert!(!ll_iter_next(iter, &mut out));
+        ll_iter_free(iter);
+
+        assert!(ll_pop_front(list, &mut out));
+        assert_eq!(out, 1);
+        assert!(ll_pop_back(list, &mut out));
+        assert_eq!(out, 3);
+        assert!(ll_pop_back(list, &mut out));
+        assert!(!ll_pop_front(list, &mut out));
+        assert_eq!(out, 2);
+        assert_eq!(ll_len(list), 0);
+
+        // the elements left in the list are freed with it
+        ll_push_back(list, 4);
+        ll_free(list);
+        ll_free(std::ptr::null_mut());
+        ll_iter_free(std::ptr::null_mut());
+    }
+}
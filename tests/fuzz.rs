@@ -0,0 +1,52 @@
+#![cfg(feature = "arbitrary")]
+//! Feeds the `Arbitrary` implementation of `LinkedList` and sequences of `Op` with pseudo random bytes.
+
+use arbitrary::{Arbitrary, Unstructured};
+use linked_list::fuzz::Op;
+use linked_list::LinkedList;
+
+// bytes of a xorshift generator, deterministic so failures can be reproduced
+fn bytes(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect()
+}
+
+#[test]
+fn test_arbitrary_list() {
+    let data = bytes(1, 256);
+    let list = LinkedList::<u32>::arbitrary_take_rest(Unstructured::new(&data)).unwrap();
+    // the same bytes make the same elements as a Vec
+    let expected = Vec::<u32>::arbitrary_take_rest(Unstructured::new(&data)).unwrap();
+    assert!(!expected.is_empty());
+    assert_eq!(list, expected);
+    list.debug_validate();
+
+    let empty = LinkedList::<u32>::arbitrary(&mut Unstructured::new(&[])).unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_arbitrary_ops() {
+    for seed in 1..200 {
+        let data = bytes(seed, 512);
+        let mut u = Unstructured::new(&data);
+        let mut list = LinkedList::<u8>::arbitrary(&mut u).unwrap();
+        let ops = Vec::<Op<u8>>::arbitrary_take_rest(u).unwrap();
+        for op in ops {
+            let len = list.len();
+            let taken = matches!(op, Op::PopFront | Op::PopBack | Op::RemoveAt(_));
+            let removed = op.apply(&mut list);
+            list.debug_validate();
+            if taken {
+                assert_eq!(removed.is_some(), len > 0);
+            }
+        }
+    }
+}
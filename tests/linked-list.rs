@@ -105,24 +105,24 @@ fn test_push_pop_mixup() {
 #[test]
 fn test_push_front_no_pop() {
     let mut list = LinkedList::new();
-    (0..4).into_iter().for_each(|n| list.push_front(n));
+    (0..4).for_each(|n| list.push_front(n));
     assert_eq!(list.len(), 4);
 }
 
 #[test]
 fn test_push_back_no_pop() {
     let mut list = LinkedList::new();
-    (0..4).into_iter().for_each(|n| list.push_back(n));
+    (0..4).for_each(|n| list.push_back(n));
     assert_eq!(list.len(), 4);
 }
 
 #[test]
 fn test_list_clear() {
-    let mut list = (0..10).into_iter().collect::<LinkedList<u32>>();
+    let mut list = (0..10).collect::<LinkedList<u32>>();
     assert_eq!(list.len(), 10);
     list.clear();
     assert_eq!(list.len(), 0);
-    assert_eq!(list.is_empty(), true);
+    assert!(list.is_empty());
     assert_eq!(list.peek_back(), None);
     assert_eq!(list.peek_front(), None);
 }
@@ -130,19 +130,19 @@ fn test_list_clear() {
 #[test]
 fn test_list_contains() {
     let mut list = LinkedList::new();
-    assert_eq!(list.contains(&1), false);
+    assert!(!list.contains(&1));
     (0..10).for_each(|n| list.push_back(n));
-    assert_eq!(list.contains(&0), true);
-    assert_eq!(list.contains(&7), true);
-    assert_eq!(list.contains(&9), true);
-    assert_eq!(list.contains(&10), false);
+    assert!(list.contains(&0));
+    assert!(list.contains(&7));
+    assert!(list.contains(&9));
+    assert!(!list.contains(&10));
     list.clear();
-    assert_eq!(list.contains(&1), false);
+    assert!(!list.contains(&1));
 }
 
 #[test]
 fn test_list_append() {
-    let mut list = (0..10).into_iter().collect::<LinkedList<u32>>();
+    let mut list = (0..10).collect::<LinkedList<u32>>();
     let old_list = list.clone();
     list.append(&mut LinkedList::new());
     assert_eq!(list, old_list);
@@ -151,7 +151,7 @@ fn test_list_append() {
     let old_other = other.clone();
     list.append(&mut other);
     assert_eq!(list, old_other);
-    assert_eq!(other.is_empty(), true);
+    assert!(other.is_empty());
     let mut list = LinkedList::new();
     list.push_front(1);
     list.append(&mut LinkedList::from([2, 3]));
@@ -173,14 +173,14 @@ fn test_list_insert_at() {
     assert_eq!(list.pop_back(), Some(2));
     assert_eq!(list.pop_back(), Some(3));
     assert_eq!(list.pop_back(), None);
-    let mut list = (0..10).into_iter().collect::<LinkedList<u32>>();
+    let mut list = (0..10).collect::<LinkedList<u32>>();
     (0..10).rev().for_each(|n| list.insert_at(n, 5));
     assert_eq!(list.peek_front(), Some(&0));
     assert_eq!(list.peek_back(), Some(&9));
     let expected = (0..5).chain(0..10).chain(5..10);
     assert!(list.iter().cloned().eq(expected));
     let mut list = LinkedList::new();
-    list.extend((0..5).into_iter());
+    list.extend(0..5);
     list.insert_at(5, 10);
     assert_eq!(list.pop_back(), Some(5));
 }
@@ -208,7 +208,7 @@ fn test_memory_cleanup() {
 #[test]
 fn test_remove_at() {
     let mut list = LinkedList::new();
-    assert_eq!(list.remove_at(0).is_err(), true);
+    assert!(list.remove_at(0).is_err());
     (0..4).for_each(|n| list.insert_at(n, 0));
     (0..4).for_each(|n| assert_eq!(list.remove_at(0).unwrap(), 3 - n));
     assert_eq!(list.remove_at(0), Err(RemoveUnderCursorError));
@@ -247,4 +247,2059 @@ fn test_splice_at() {
     let other = LinkedList::from([9, 8, 7]);
     list.splice_at(other, 0);
     assert_eq!(list.len(), 8);
+    assert!(list.iter().rev().eq([4, 3, 2, 1, 7, 8, 9, 0].iter()));
+}
+
+#[test]
+fn test_unrolled_list() {
+    let mut list: UnrolledList<u32, 3> = UnrolledList::new();
+    assert!(list.is_empty());
+    (0..5).for_each(|n| list.push_back(n));
+    (5..10).for_each(|n| list.push_front(n));
+    assert_eq!(list.len(), 10);
+    let expected = (5..10).rev().chain(0..5);
+    assert!(list.iter().cloned().eq(expected.clone()));
+    assert!(list.iter().rev().cloned().eq(expected.clone().rev()));
+    assert!(expected
+        .clone()
+        .enumerate()
+        .all(|(i, n)| list.get(i) == Some(&n)));
+    let mut other: UnrolledList<u32, 3> = (10..12).collect();
+    list.append(&mut other);
+    assert!(other.is_empty());
+    assert_eq!(list.pop_back(), Some(11));
+    assert_eq!(list.pop_back(), Some(10));
+    for n in expected {
+        assert_eq!(list.pop_front(), Some(n));
+    }
+    assert_eq!(list.pop_front(), None);
+    assert_eq!(list.pop_back(), None);
+}
+
+#[test]
+fn test_unrolled_split_off() {
+    for at in 0..=10 {
+        let mut list: UnrolledList<u32, 3> = (0..10).collect();
+        let mut tail = list.split_off(at);
+        assert!(list.iter().cloned().eq(0..at as u32));
+        assert!(tail.iter().cloned().eq(at as u32..10));
+        assert!(tail.iter().rev().cloned().eq((at as u32..10).rev()));
+        list.push_back(100);
+        tail.push_front(200);
+        assert_eq!(list.peek_back(), Some(&100));
+        assert_eq!(tail.peek_front(), Some(&200));
+        list.append(&mut tail);
+        assert_eq!(list.len(), 12);
+    }
+}
+
+#[test]
+fn test_unrolled_insert_at() {
+    // insert_at wraps around like LinkedList::insert_at, try_insert_at does not
+    for index in 0..12 {
+        let mut list: UnrolledList<u32, 3> = (0..5).collect();
+        let mut expected: LinkedList<u32> = (0..5).collect();
+        list.insert_at(100, index);
+        expected.insert_at(100, index);
+        assert!(list.iter().eq(expected.iter()));
+        let mut list: UnrolledList<u32, 3> = (0..5).collect();
+        assert_eq!(list.try_insert_at(100, index).is_ok(), index <= 5);
+    }
+    let mut list: UnrolledList<u32, 3> = UnrolledList::new();
+    list.insert_at(1, 7);
+    assert!(list.iter().eq([1].iter()));
+}
+
+#[test]
+fn test_unrolled_list_memory_cleanup() {
+    use std::cell::Cell;
+    struct DropCounter<'a>(&'a Cell<usize>);
+    impl<'a> Drop for DropCounter<'a> {
+        fn drop(&mut self) {
+            let num = self.0.get();
+            self.0.set(num + 1);
+        }
+    }
+    let counter = Cell::new(0);
+    let mut list: UnrolledList<_, 4> = (0..10).map(|_| DropCounter(&counter)).collect();
+    drop(list.pop_front());
+    drop(list.pop_back());
+    assert_eq!(counter.get(), 2);
+    drop(list);
+    assert_eq!(counter.get(), 10);
+}
+
+#[test]
+fn test_sorted_list() {
+    let mut list = SortedList::new();
+    [5, 3, 8, 1, 9, 3, 7]
+        .into_iter()
+        .for_each(|n| list.insert(n));
+    assert_eq!(list.len(), 7);
+    assert!(list.iter().eq([1, 3, 3, 5, 7, 8, 9].iter()));
+    assert_eq!(list.peek_min(), Some(&1));
+    assert_eq!(list.peek_max(), Some(&9));
+    assert_eq!(list.pop_min(), Some(1));
+    assert_eq!(list.pop_max(), Some(9));
+    list.insert(4);
+    assert!(list.iter().eq([3, 3, 4, 5, 7, 8].iter()));
+    let list = SortedList::from(LinkedList::from([4, 2, 6, 0]));
+    assert_eq!(list.into_list(), LinkedList::from([0, 2, 4, 6]));
+}
+
+#[test]
+fn test_update_at() {
+    let mut list: LinkedList<u32> = LinkedList::new();
+    assert!(!list.update_at(0, |n| *n += 1));
+    list.extend(0..9);
+    (0..9).for_each(|i| assert!(list.update_at(i, |n| *n *= 2)));
+    assert!(!list.update_at(9, |n| *n += 1));
+    assert!(list.iter().cloned().eq((0..9).map(|n| n * 2)));
+}
+
+#[test]
+fn test_concurrent_list() {
+    use std::sync::Arc;
+    use std::thread;
+    let list = Arc::new(ConcurrentLinkedList::new());
+    let producers = (0..4)
+        .map(|t| {
+            let list = Arc::clone(&list);
+            thread::spawn(move || {
+                for n in 0..100 {
+                    if n % 2 == 0 {
+                        list.push_back(t * 100 + n);
+                    } else {
+                        list.push_front(t * 100 + n);
+                    }
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    let consumer = {
+        let list = Arc::clone(&list);
+        thread::spawn(move || (0..100).filter_map(|_| list.pop_front()).count())
+    };
+    producers.into_iter().for_each(|h| h.join().unwrap());
+    let consumed = consumer.join().unwrap();
+    assert_eq!(list.len() + consumed, 400);
+    assert_eq!(list.approx_len(), list.len());
+    let mut rest = Vec::new();
+    while let Some(n) = list.pop_front() {
+        rest.push(n);
+    }
+    assert_eq!(rest.len() + consumed, 400);
+    assert!(list.is_empty());
+}
+
+#[test]
+fn test_mpsc_queue() {
+    use linked_list::mpsc::Queue;
+    use std::thread;
+    let queue = Queue::new();
+    let producers = (0..4)
+        .map(|t| {
+            let producer = queue.producer();
+            thread::spawn(move || (0..1000).for_each(|n| producer.push(t * 1000 + n)))
+        })
+        .collect::<Vec<_>>();
+    let mut popped = Vec::new();
+    while popped.len() < 4000 {
+        if let Some(n) = queue.pop() {
+            popped.push(n);
+        }
+    }
+    producers.into_iter().for_each(|h| h.join().unwrap());
+    assert!(queue.is_empty());
+    // elements from the same producer come out in the order they were pushed
+    for t in 0..4 {
+        let from_t = popped.iter().filter(|n| **n / 1000 == t);
+        assert!(from_t.cloned().eq(t * 1000..(t + 1) * 1000));
+    }
+}
+
+#[test]
+fn test_mpsc_notify_on_push() {
+    use linked_list::mpsc::Queue;
+    use std::future::Future;
+    use std::pin::pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake};
+    use std::thread::{self, Thread};
+
+    // wakes the consumer thread parked in block_on
+    struct Unpark(Thread);
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Arc::new(Unpark(thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(future);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+            thread::park();
+        }
+    }
+
+    let mut queue = Queue::new();
+    let producer = queue.producer();
+    let handle = thread::spawn(move || (0..100).for_each(|n| producer.push(n)));
+    let mut popped = Vec::new();
+    while popped.len() < 100 {
+        block_on(queue.notify_on_push());
+        while let Some(n) = queue.pop() {
+            popped.push(n);
+        }
+    }
+    handle.join().unwrap();
+    assert!(popped.into_iter().eq(0..100));
+    // resolves right away while there is an element
+    queue.push(1);
+    block_on(queue.notify_on_push());
+    assert_eq!(queue.pop(), Some(1));
+}
+
+#[test]
+fn test_iter_both_ends() {
+    let list = LinkedList::from([1, 2, 3, 4]);
+    let mut iter = list.iter();
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next_back(), Some(&4));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next(), Some(&2));
+    assert_eq!(iter.next_back(), Some(&3));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    let mut list = LinkedList::from([1, 2, 3]);
+    let mut iter = list.iter_mut();
+    assert_eq!(iter.next_back(), Some(&mut 3));
+    assert_eq!(iter.next(), Some(&mut 1));
+    assert_eq!(iter.next(), Some(&mut 2));
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn test_iter_and_cursor_across_threads() {
+    use std::thread;
+    fn is_send<S: Send>(_: &S) {}
+    fn is_sync<S: Sync>(_: &S) {}
+    let mut list = LinkedList::from([1, 2, 3, 4]);
+    is_send(&list.iter());
+    is_sync(&list.iter());
+    is_send(&list.iter_mut());
+    is_sync(&list.iter_mut());
+    is_send(&list.cursor_front().unwrap());
+    is_sync(&list.cursor_front().unwrap());
+    is_send(&list.cursor_front_mut().unwrap());
+    is_sync(&list.cursor_front_mut().unwrap());
+    is_send(&list.clone().into_iter());
+    is_sync(&list.clone().into_iter());
+
+    thread::scope(|s| {
+        let mut iter = list.iter();
+        let back = s.spawn(move || iter.next_back().copied());
+        let cursor = list.cursor_back().unwrap();
+        let last = s.spawn(move || *cursor.current().0);
+        assert_eq!(back.join().unwrap(), Some(4));
+        assert_eq!(last.join().unwrap(), 4);
+    });
+    thread::scope(|s| {
+        let iter = list.iter_mut();
+        s.spawn(move || iter.for_each(|x| *x *= 10));
+    });
+    thread::scope(|s| {
+        let mut cursor = list.cursor_front_mut().unwrap();
+        s.spawn(move || {
+            cursor.move_next();
+            *cursor.current_mut().0 += 1;
+        });
+    });
+    assert_eq!(list, LinkedList::from([10, 21, 30, 40]));
+}
+
+#[test]
+fn test_reverse_range() {
+    // rotate left by 2 via three reversals
+    let mut list = (1..=7).collect::<LinkedList<i32>>();
+    list.reverse_range(..2);
+    list.reverse_range(2..);
+    list.reverse_range(..);
+    assert!(list.iter().eq([3, 4, 5, 6, 7, 1, 2].iter()));
+    assert!(list.iter().rev().eq([2, 1, 7, 6, 5, 4, 3].iter()));
+    assert_eq!(list.peek_front(), Some(&3));
+    assert_eq!(list.peek_back(), Some(&2));
+
+    list.reverse_range(3..3);
+    list.reverse_range(6..=6);
+    assert!(list.iter().eq([3, 4, 5, 6, 7, 1, 2].iter()));
+
+    // 2-opt move between positions 1 and 4
+    let mut cursor = list.cursor_front_mut().unwrap();
+    cursor.move_next();
+    cursor.reverse_next_n(4);
+    assert_eq!(cursor.current_mut(), (&mut 7, 1));
+    cursor.move_prev();
+    cursor.reverse_next_n(usize::MAX);
+    assert_eq!(cursor.current_mut(), (&mut 2, 0));
+    assert!(list.iter().eq([2, 1, 4, 5, 6, 7, 3].iter()));
+    assert!(list.iter().rev().eq([3, 7, 6, 5, 4, 1, 2].iter()));
+}
+
+#[test]
+#[should_panic]
+fn test_reverse_range_out_of_bounds() {
+    let mut list = LinkedList::from([1, 2, 3]);
+    list.reverse_range(1..4);
+}
+
+#[test]
+fn test_extend_front() {
+    let mut list = LinkedList::new();
+    list.extend_front([3, 4]);
+    list.extend_front(std::iter::empty());
+    list.extend_front([1, 2]);
+    list.extend([5]);
+    assert!(list.iter().eq([1, 2, 3, 4, 5].iter()));
+    assert!(list.iter().rev().eq([5, 4, 3, 2, 1].iter()));
+}
+
+#[test]
+fn test_list_ops() {
+    fn exercise<L: ListOps<i32> + Default>() {
+        let mut list = L::default();
+        assert!(list.is_empty());
+        for n in 0..10 {
+            list.push_back(n);
+        }
+        list.push_front(-1);
+        list.insert_at(-2, 0);
+        list.insert_at(10, 12);
+        list.insert_at(100, 5);
+        list.insert_at(200, 5);
+        assert_eq!(list.len(), 15);
+        assert_eq!(list.peek_front(), Some(&-2));
+        assert_eq!(list.peek_back(), Some(&10));
+        assert!(list
+            .iter()
+            .eq([-2, -1, 0, 1, 2, 200, 100, 3, 4, 5, 6, 7, 8, 9, 10].iter()));
+        assert_eq!(list.remove(6), Some(100));
+        assert_eq!(list.remove(5), Some(200));
+        assert_eq!(list.remove(13), None);
+        assert_eq!(list.remove(12), Some(10));
+        assert_eq!(list.remove(0), Some(-2));
+        assert_eq!(list.pop_front(), Some(-1));
+        assert_eq!(list.pop_back(), Some(9));
+        assert!(list
+            .iter()
+            .rev()
+            .eq((0..9).rev().collect::<Vec<_>>().iter()));
+        // the index wraps around like LinkedList::insert_at
+        list.insert_at(50, 10);
+        assert_eq!(list.iter().nth(1), Some(&50));
+        while list.remove(0).is_some() {}
+        assert!(list.is_empty());
+    }
+    exercise::<LinkedList<i32>>();
+    exercise::<UnrolledList<i32, 1>>();
+    exercise::<UnrolledList<i32, 3>>();
+    exercise::<UnrolledList<i32, 16>>();
+    #[cfg(feature = "slab")]
+    exercise::<SlabList<i32>>();
+}
+
+#[test]
+fn test_into_split() {
+    use std::thread;
+    let list = b"GET /\r\nHost: a\r\n\r\n"
+        .iter()
+        .copied()
+        .collect::<LinkedList<u8>>();
+    let pieces = list
+        .clone()
+        .into_split(|b| *b == b'\n', false)
+        .collect::<Vec<_>>();
+    assert_eq!(pieces.len(), 4);
+    assert!(pieces[3].is_empty());
+    let handles = pieces
+        .into_iter()
+        .map(|piece| thread::spawn(move || piece.into_iter().filter(|b| *b != b'\r').count()))
+        .collect::<Vec<_>>();
+    let lens = handles
+        .into_iter()
+        .map(|h| h.join().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(lens, [5, 7, 0, 0]);
+
+    // split_by forwards to into_split
+    assert!(list
+        .clone()
+        .split_by(|b| *b == b'\n', true)
+        .eq(list.clone().into_split(|b| *b == b'\n', true)));
+    let pieces = list.into_split(|b| *b == b'\n', true).collect::<Vec<_>>();
+    assert_eq!(pieces.len(), 3);
+    assert!(pieces.iter().all(|p| p.peek_back() == Some(&b'\n')));
+    assert!(pieces[1].iter().rev().eq(b"\n\ra :tsoH".iter()));
+
+    let mut empty = LinkedList::<i32>::new().into_split(|_| true, false);
+    assert_eq!(empty.next(), Some(LinkedList::new()));
+    assert_eq!(empty.next(), None);
+    assert_eq!(
+        LinkedList::<i32>::new().into_split(|_| true, true).next(),
+        None
+    );
+}
+
+#[test]
+fn test_try_insert_at() {
+    let mut list = LinkedList::new();
+    assert!(list.try_insert_at(1, 1).is_err());
+    assert!(list.try_insert_at(2, 0).is_ok());
+    assert!(list.try_insert_at(0, 0).is_ok());
+    assert!(list.try_insert_at(3, 2).is_ok());
+    assert!(list.try_insert_at(1, 1).is_ok());
+    assert!(list.try_insert_at(9, 5).is_err());
+    assert!(list.iter().eq([0, 1, 2, 3].iter()));
+    assert!(list.iter().rev().eq([3, 2, 1, 0].iter()));
+    let err = list.try_insert_at(9, 9).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "IndexOutOfBounds: Index is greater than the length of the list."
+    );
+}
+
+#[test]
+fn test_remove_range() {
+    let mut list = (0..10).collect::<LinkedList<i32>>();
+    let middle = list.remove_range(3..=5);
+    assert!(middle.iter().eq([3, 4, 5].iter()));
+    assert!(middle.iter().rev().eq([5, 4, 3].iter()));
+    let front = list.remove_range(..2);
+    assert!(front.iter().eq([0, 1].iter()));
+    let back = list.remove_range(3..);
+    assert!(back.iter().eq([8, 9].iter()));
+    assert!(list.remove_range(1..1).is_empty());
+    assert!(list.iter().eq([2, 6, 7].iter()));
+    assert!(list.iter().rev().eq([7, 6, 2].iter()));
+    let all = list.remove_range(..);
+    assert!(list.is_empty());
+    assert_eq!(list.peek_back(), None);
+    assert_eq!(all, LinkedList::from([2, 6, 7]));
+}
+
+#[test]
+fn test_drain() {
+    use std::rc::Rc;
+    let counter = Rc::new(());
+    let mut list = (0..6)
+        .map(|n| (n, Rc::clone(&counter)))
+        .collect::<LinkedList<_>>();
+    {
+        let mut drain = list.drain(1..5);
+        assert_eq!(drain.len(), 4);
+        assert_eq!(drain.next().map(|(n, _)| n), Some(1));
+        assert_eq!(drain.next_back().map(|(n, _)| n), Some(4));
+        // the remaining two elements are dropped along with the iterator
+    }
+    assert_eq!(Rc::strong_count(&counter), 3);
+    assert!(list.iter().map(|(n, _)| n).eq([0, 5].iter()));
+    assert!(list.iter().rev().map(|(n, _)| n).eq([5, 0].iter()));
+    list.push_back((6, Rc::clone(&counter)));
+    assert_eq!(list.drain(2..).count(), 1);
+    assert_eq!(list.drain(..=0).count(), 1);
+    assert!(list.iter().map(|(n, _)| n).eq([5].iter()));
+    assert_eq!(Rc::strong_count(&counter), 2);
+}
+
+#[test]
+fn test_splice_range() {
+    let mut list = (0..5).collect::<LinkedList<i32>>();
+    assert!(list.splice_range(0..0, [-2, -1]).is_empty());
+    assert_eq!(list.splice_range(5.., []), LinkedList::from([3, 4]));
+    assert_eq!(list.splice_range(2..=3, [9]), LinkedList::from([0, 1]));
+    assert!(list.iter().eq([-2, -1, 9, 2].iter()));
+    assert!(list.iter().rev().eq([2, 9, -1, -2].iter()));
+    assert_eq!(
+        list.splice_range(.., 7..9),
+        LinkedList::from([-2, -1, 9, 2])
+    );
+    assert_eq!(list, LinkedList::from([7, 8]));
+}
+
+#[test]
+fn test_binary_search() {
+    use std::cell::Cell;
+    let list = (0..100).map(|n| n * 2).collect::<LinkedList<i32>>();
+    for n in 0..100 {
+        assert_eq!(list.binary_search(&(n * 2)), Ok(n as usize));
+        assert_eq!(list.binary_search(&(n * 2 + 1)), Err(n as usize + 1));
+    }
+    assert_eq!(list.binary_search(&-1), Err(0));
+    assert_eq!(LinkedList::<i32>::new().binary_search(&1), Err(0));
+    let comparisons = Cell::new(0);
+    let _ = list.binary_search_by(|x| {
+        comparisons.set(comparisons.get() + 1);
+        x.cmp(&101)
+    });
+    assert!(comparisons.get() <= 7);
+}
+
+#[test]
+fn test_borrowed_lookups() {
+    let mut list = ["x", "y", "z", "y"]
+        .into_iter()
+        .map(String::from)
+        .collect::<LinkedList<String>>();
+    assert!(list.contains("y"));
+    assert!(!list.contains("w"));
+    assert_eq!(list.position("z"), Some(2));
+    assert_eq!(list.remove_item("y").as_deref(), Some("y"));
+    assert_eq!(list.position("y"), Some(2));
+    assert_eq!(list.remove_item("x").as_deref(), Some("x"));
+    assert_eq!(list.remove_item("y").as_deref(), Some("y"));
+    assert_eq!(list.remove_item("y"), None);
+    assert_eq!(list.peek_front().map(String::as_str), Some("z"));
+    assert_eq!(list.peek_back().map(String::as_str), Some("z"));
+    assert_eq!(list.remove_item("z").as_deref(), Some("z"));
+    assert!(list.is_empty());
+}
+
+#[test]
+fn test_windows() {
+    let list = LinkedList::from([1, 3, 2, 5, 4]);
+    let windows = list.windows(2);
+    assert_eq!(windows.len(), 4);
+    let rising = windows.map(|mut w| w.next() < w.next()).collect::<Vec<_>>();
+    assert_eq!(rising, [true, false, true, false]);
+    let last = list.windows(3).last().unwrap();
+    assert!(last.rev().eq([4, 5, 2].iter()));
+    assert_eq!(list.windows(5).count(), 1);
+    assert_eq!(list.windows(6).count(), 0);
+    assert_eq!(LinkedList::<i32>::new().windows(1).count(), 0);
+}
+
+#[test]
+fn test_chunks() {
+    use std::thread;
+    let list = (1..=10).collect::<LinkedList<u64>>();
+    let chunks = list.chunks(3);
+    assert_eq!(chunks.len(), 4);
+    assert!(chunks.last().unwrap().eq([10].iter()));
+    assert!(list.chunks(3).nth(1).unwrap().rev().eq([6, 5, 4].iter()));
+    assert_eq!(list.chunks(10).count(), 1);
+    assert_eq!(LinkedList::<u64>::new().chunks(2).count(), 0);
+
+    let handles = list
+        .into_chunks(4)
+        .map(|chunk| thread::spawn(move || chunk.into_iter().sum::<u64>()))
+        .collect::<Vec<_>>();
+    let sums = handles
+        .into_iter()
+        .map(|h| h.join().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(sums, [10, 26, 19]);
+    let mut chunks = LinkedList::from([1, 2, 3]).into_chunks(2);
+    let first = chunks.next().unwrap();
+    assert!(first.iter().rev().eq([2, 1].iter()));
+    drop(chunks);
+    assert_eq!(first.peek_back(), Some(&2));
+}
+
+#[test]
+fn test_zip_with() {
+    let names = LinkedList::from([String::from("a"), String::from("b"), String::from("c")]);
+    let counts = LinkedList::from([1, 2]);
+    let zipped = names.zip_with(counts, |name, n| name.repeat(n));
+    assert!(zipped.iter().eq(["a", "bb"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<_>>()
+        .iter()));
+    let empty = LinkedList::<i32>::new().zip_with(LinkedList::from([1]), |a, b| a + b);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_unzip() {
+    let list = (0..4)
+        .map(|n| (n, n.to_string()))
+        .collect::<LinkedList<(i32, String)>>();
+    let (nums, strings) = list.unzip();
+    assert!(nums.iter().rev().eq([3, 2, 1, 0].iter()));
+    assert_eq!(strings.peek_back().map(String::as_str), Some("3"));
+    assert_eq!(strings.len(), 4);
+    let (a, b) = LinkedList::<(i32, i32)>::new().unzip();
+    assert!(a.is_empty() && b.is_empty());
+}
+
+#[test]
+fn test_partition() {
+    let mut list = (0..10).collect::<LinkedList<i32>>();
+    let rest = list.partition(|x| *x % 3 == 0);
+    assert!(list.iter().eq([0, 3, 6, 9].iter()));
+    assert!(list.iter().rev().eq([9, 6, 3, 0].iter()));
+    assert!(rest.iter().eq([1, 2, 4, 5, 7, 8].iter()));
+    assert!(rest.iter().rev().eq([8, 7, 5, 4, 2, 1].iter()));
+    let all = list.partition(|_| false);
+    assert!(list.is_empty());
+    assert_eq!(list.peek_back(), None);
+    assert_eq!(all.len(), 4);
+}
+
+#[test]
+fn test_chunk_by() {
+    let list = LinkedList::from(['a', 'a', 'a', 'b', 'c', 'c']);
+    let encoded = list
+        .chunk_by(|a, b| a == b)
+        .map(|mut run| (run.len(), *run.next().unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(encoded, [(3, 'a'), (1, 'b'), (2, 'c')]);
+    assert!(list
+        .chunk_by(|a, b| a == b)
+        .last()
+        .unwrap()
+        .rev()
+        .eq(['c', 'c'].iter()));
+    assert_eq!(LinkedList::<char>::new().chunk_by(|a, b| a == b).count(), 0);
+
+    let runs = list.into_chunk_by(|a, b| a == b).collect::<Vec<_>>();
+    assert_eq!(runs.len(), 3);
+    assert!(runs[0].iter().rev().eq(['a', 'a', 'a'].iter()));
+    assert_eq!(runs[2].peek_front(), Some(&'c'));
+    assert_eq!(runs[2].peek_back(), Some(&'c'));
+}
+
+#[test]
+fn test_count_of() {
+    let list = "the cat and the hat"
+        .split(' ')
+        .map(String::from)
+        .collect::<LinkedList<String>>();
+    assert_eq!(list.count_of("the"), 2);
+    assert_eq!(list.count_of("dog"), 0);
+    assert_eq!(list.count_by(|w| w.ends_with("at")), 2);
+    assert_eq!(LinkedList::<String>::new().count_by(|_| true), 0);
+}
+
+#[test]
+fn test_linked_list_macro() {
+    let list = linked_list![String::from("a"), String::from("b"),];
+    assert_eq!(list.len(), 2);
+    let list = linked_list![vec![0u8; 2]; 3];
+    assert!(list.iter().all(|v| v == &[0, 0]));
+    assert_eq!(list.len(), 3);
+    let list: LinkedList<Vec<u8>> = linked_list![Vec::new(); 0];
+    assert!(list.is_empty());
+    assert_eq!(linked_list![1, 2, 3], (1..=3).collect::<LinkedList<_>>());
+}
+
+#[test]
+fn test_display_with() {
+    let list = linked_list!["a", "b", "c"];
+    assert_eq!(list.display_with(", ", "", "").to_string(), "a, b, c");
+    assert_eq!(
+        format!("{:>2}", list.display_with("", "<", ">")),
+        "< a b c>"
+    );
+    let empty: LinkedList<i32> = linked_list![];
+    assert_eq!(empty.display_with(", ", "[", "]").to_string(), "[]");
+    assert_eq!(list.to_string(), "HEAD -> a <-> b <-> c -> END");
+}
+
+#[test]
+fn test_debug() {
+    let list = linked_list![String::from("a"), String::from("b")];
+    assert_eq!(format!("{:?}", list), r#"["a", "b"]"#);
+    assert_eq!(format!("{:#?}", list), "[\n    \"a\",\n    \"b\",\n]");
+    assert_eq!(format!("{:?}", LinkedList::<i32>::new()), "[]");
+    let structure = format!("{:#?}", list.debug_structure());
+    assert_eq!(structure.matches("Node {").count(), 2);
+    assert!(structure.contains("val: \"b\""));
+}
+
+#[test]
+fn test_to_dot() {
+    let list = linked_list!["a", "b\"c", "d"];
+    let dot = list.to_dot();
+    assert!(dot.contains(r#"n1 [label="\"b\\\"c\""];"#));
+    assert!(dot.contains("head -> n0;"));
+    assert!(dot.contains("tail -> n2;"));
+    assert_eq!(dot.matches("[label=\"next\"]").count(), 2);
+    assert_eq!(dot.matches("style=dashed").count(), 2);
+    assert!(!dot.contains("color=red"));
+    assert!(dot.trim_end().ends_with('}'));
+    let mut out = Vec::new();
+    list.write_dot(&mut out).unwrap();
+    assert_eq!(out, dot.as_bytes());
+    let empty = LinkedList::<i32>::new().to_dot();
+    assert!(!empty.contains("->"));
+}
+
+#[test]
+fn test_heap_size() {
+    let mut list = LinkedList::new();
+    assert_eq!(list.heap_size(), 0);
+    list.push_back(vec![1u8; 100]);
+    list.push_back(vec![2u8; 50]);
+    let nodes = list.heap_size();
+    assert!(nodes >= 2 * std::mem::size_of::<Vec<u8>>());
+    assert_eq!(list.heap_size_with(|v| v.capacity()), nodes + 150);
+    list.pop_back();
+    assert_eq!(list.heap_size(), nodes / 2);
+}
+
+#[test]
+fn test_pinned_list() {
+    use std::cell::Cell;
+    use std::marker::PhantomPinned;
+    use std::pin::Pin;
+
+    // remembers its own address once pinned and checks it when dropped in place
+    struct Registered<'a> {
+        addr: Cell<usize>,
+        drops: &'a Cell<usize>,
+        _pin: PhantomPinned,
+    }
+    impl<'a> Registered<'a> {
+        fn register(self: Pin<&mut Self>) {
+            self.addr.set(&*self as *const Self as usize);
+        }
+    }
+    impl Drop for Registered<'_> {
+        fn drop(&mut self) {
+            assert_eq!(self.addr.get(), self as *const Self as usize);
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    let new = || Registered {
+        addr: Cell::new(0),
+        drops: &drops,
+        _pin: PhantomPinned,
+    };
+    let mut list = PinnedList::new();
+    for _ in 0..3 {
+        list.push_back(new()).register();
+        list.push_front(new()).register();
+    }
+    list.get_mut(2).unwrap().register();
+    assert_eq!(list.len(), 6);
+    assert!(list.drop_front());
+    assert!(list.drop_back());
+    assert_eq!(drops.get(), 2);
+    assert!(list.iter().all(|r| r.addr.get() != 0));
+    drop(list);
+    assert_eq!(drops.get(), 6);
+
+    // values inserted and dropped through the cursor stay in place as well
+    let drops = Cell::new(0);
+    let new = || Registered {
+        addr: Cell::new(0),
+        drops: &drops,
+        _pin: PhantomPinned,
+    };
+    let mut list = PinnedList::new();
+    list.push_back(new()).register();
+    let mut cursor = list.cursor_front_mut().unwrap();
+    for _ in 0..4 {
+        cursor.insert(new()).register();
+    }
+    assert_eq!(cursor.current().1, 4);
+    cursor.move_prev();
+    assert!(cursor.drop_current());
+    assert_eq!(cursor.current().1, 3);
+    cursor.move_next();
+    cursor.current_mut().0.register();
+    assert!(cursor.drop_current());
+    assert_eq!(drops.get(), 2);
+    assert_eq!(list.len(), 3);
+    assert!(list.iter().all(|r| r.addr.get() != 0));
+    drop(list);
+    assert_eq!(drops.get(), 5);
+}
+
+#[test]
+fn test_list_slice() {
+    let list = LinkedList::from([1, 2, 3, 4, 5, 6]);
+    let slice = list.slice(1..5);
+    assert_eq!(slice.len(), 4);
+    assert!(slice.iter().eq([2, 3, 4, 5].iter()));
+    assert!(slice.iter().rev().eq([5, 4, 3, 2].iter()));
+    assert!(slice.contains(&5));
+    assert!(!slice.contains(&6));
+    assert_eq!(slice.first(), Some(&2));
+    assert_eq!(slice.last(), Some(&5));
+    assert_eq!(slice.get(3), Some(&5));
+    assert_eq!(slice.get(4), None);
+    let inner = slice.slice(1..=2);
+    assert!(inner.iter().eq([3, 4].iter()));
+    assert!(inner.slice(2..).is_empty());
+    assert_eq!(format!("{:?}", inner), "[3, 4]");
+    assert!(list.slice(6..).is_empty());
+    let mut cursor = list.cursor_back().unwrap();
+    cursor.move_prev();
+    assert!(cursor.slice_next(5).iter().eq([5, 6].iter()));
+    // the list itself is left untouched
+    assert_eq!(list.len(), 6);
+}
+
+#[test]
+fn test_iter_mut_pairs() {
+    let mut list = LinkedList::from([1, 2, 3, 4]);
+    let mut pairs = list.iter_mut_pairs();
+    while let Some((a, b)) = pairs.next() {
+        std::mem::swap(a, b);
+    }
+    // the first element bubbles to the back
+    assert!(list.iter().eq([2, 3, 4, 1].iter()));
+    assert!(list.iter().rev().eq([1, 4, 3, 2].iter()));
+    let mut single = LinkedList::from([1]);
+    assert!(single.iter_mut_pairs().next().is_none());
+    let mut empty: LinkedList<i32> = LinkedList::new();
+    assert!(empty.iter_mut_pairs().next().is_none());
+}
+
+#[test]
+fn test_pop_if() {
+    let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+    let mut drained = Vec::new();
+    while let Some(x) = list.pop_front_if(|x| *x < 3) {
+        drained.push(x);
+    }
+    assert_eq!(drained, [1, 2]);
+    assert_eq!(list.pop_back_if(|x| *x % 2 == 0), None);
+    assert_eq!(list.pop_back_if(|x| *x % 2 == 1), Some(5));
+    // the predicate may update the element it keeps
+    assert_eq!(
+        list.pop_front_if(|x| {
+            *x *= 10;
+            false
+        }),
+        None
+    );
+    assert!(list.iter().eq([30, 4].iter()));
+    assert!(list.iter().rev().eq([4, 30].iter()));
+    let mut empty: LinkedList<i32> = LinkedList::new();
+    assert_eq!(empty.pop_front_if(|_| true), None);
+    assert_eq!(empty.pop_back_if(|_| true), None);
+}
+
+#[test]
+fn test_find_or_insert_by() {
+    let mut list: LinkedList<i32> = LinkedList::new();
+    for x in [5, 1, 3, 5, 9, 1, 4] {
+        list.find_or_insert_by(|e| e.cmp(&x), || x);
+    }
+    // behaves like an ordered set
+    assert!(list.iter().eq([1, 3, 4, 5, 9].iter()));
+    assert!(list.iter().rev().eq([9, 5, 4, 3, 1].iter()));
+    *list.find_or_insert_by(|e| e.cmp(&4), || unreachable!()) = 4;
+    let mut counts: LinkedList<(char, usize)> = LinkedList::new();
+    for c in "hello".chars() {
+        counts.find_or_insert_by(|(k, _)| k.cmp(&c), || (c, 0)).1 += 1;
+    }
+    assert!(counts
+        .iter()
+        .eq([('e', 1), ('h', 1), ('l', 2), ('o', 1)].iter()));
+}
+
+#[test]
+fn test_extend_lists() {
+    let mut list: LinkedList<String> = LinkedList::new();
+    let parts = vec![
+        LinkedList::from(["a".to_string(), "b".to_string()]),
+        LinkedList::new(),
+        LinkedList::from(["c".to_string()]),
+    ];
+    // the nodes are moved over, the elements are not cloned
+    list.extend(parts);
+    assert!(list.iter().eq(["a", "b", "c"].iter()));
+    assert!(list.iter().rev().eq(["c", "b", "a"].iter()));
+    list.extend([LinkedList::from(["d".to_string()])]);
+    assert_eq!(list.peek_back().map(|s| s.as_str()), Some("d"));
+    assert_eq!(list.len(), 4);
+}
+
+#[test]
+fn test_flatten() {
+    let lists = LinkedList::from([
+        LinkedList::new(),
+        LinkedList::from([1, 2]),
+        LinkedList::from([3]),
+        LinkedList::new(),
+        LinkedList::from([4, 5]),
+    ]);
+    let flat = lists.flatten();
+    assert!(flat.iter().eq([1, 2, 3, 4, 5].iter()));
+    assert!(flat.iter().rev().eq([5, 4, 3, 2, 1].iter()));
+    let empty: LinkedList<LinkedList<i32>> = LinkedList::new();
+    assert!(empty.flatten().is_empty());
+}
+
+#[test]
+fn test_cursor_iter() {
+    let list = LinkedList::from([1, 2, 3, 4, 5]);
+    let mut cursor = list.cursor_front().unwrap();
+    assert!(cursor.iter().eq(list.iter()));
+    assert!(cursor.iter_wrapping().eq(list.iter()));
+    cursor.step_by(3);
+    assert_eq!(cursor.iter().len(), 2);
+    assert!(cursor.iter().rev().eq([5, 4].iter()));
+    assert!(cursor.iter_wrapping().eq([4, 5, 1, 2, 3].iter()));
+    cursor.move_prev();
+    cursor.move_prev();
+    cursor.move_prev();
+    cursor.move_prev();
+    // wrapped around to the back
+    assert_eq!(cursor.current(), (&5, 4));
+    assert!(cursor.iter().eq([5].iter()));
+    assert!(cursor.iter_wrapping().rev().eq([4, 3, 2, 1, 5].iter()));
+}
+
+#[test]
+fn test_partial_eq_sequences() {
+    let list = LinkedList::from([1, 2, 3]);
+    assert_eq!(list, [1, 2, 3]);
+    assert_eq!(list, vec![1, 2, 3]);
+    assert_eq!(list, [1, 2, 3][..]);
+    assert_ne!(list, [1, 2]);
+    assert_ne!(list, vec![1, 2, 3, 4]);
+    assert_eq!([1, 2, 3], list);
+    assert_eq!(vec![1, 2, 3], list);
+    assert_ne!([0; 3][..], list);
+    let empty: LinkedList<i32> = LinkedList::new();
+    assert_eq!(empty, []);
+    assert_eq!(empty, Vec::<i32>::new());
+    // elements of different types can be compared
+    let strings = LinkedList::from(["a".to_string(), "b".to_string()]);
+    assert_eq!(strings, ["a", "b"]);
+}
+
+#[test]
+fn test_iter_nth_last() {
+    let mut list = LinkedList::from([1, 2, 3, 4, 5, 6]);
+    let mut iter = list.iter();
+    assert_eq!(iter.nth(1), Some(&2));
+    assert_eq!(iter.nth_back(1), Some(&5));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next(), Some(&3));
+    assert_eq!(iter.nth(1), None);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(list.iter().last(), Some(&6));
+    assert_eq!(list.iter().nth(6), None);
+    assert_eq!(list.iter().nth_back(5), Some(&1));
+
+    let mut iter = list.iter_mut();
+    *iter.nth(2).unwrap() *= 10;
+    *iter.nth_back(0).unwrap() *= 10;
+    assert_eq!(iter.len(), 2);
+    *iter.last().unwrap() *= 10;
+    assert_eq!(list, [1, 2, 30, 4, 50, 60]);
+
+    assert_eq!(list.clone().into_iter().last(), Some(60));
+    assert_eq!(list.into_iter().nth(2), Some(30));
+    let empty: LinkedList<i32> = LinkedList::new();
+    assert_eq!(empty.iter().last(), None);
+    assert_eq!(empty.iter().nth(1), None);
+}
+
+#[test]
+fn test_cursor_find() {
+    let mut list = LinkedList::from([5, 3, 8, 3, 1]);
+    let mut cursor = list.cursor_find(|x| *x == 3).unwrap();
+    assert_eq!(cursor.current(), (&3, 1));
+    cursor.step_by(4);
+    // the cursor knows the full length, so it wraps to the front
+    assert_eq!(cursor.current(), (&5, 0));
+    assert!(list.cursor_find(|x| *x == 0).is_none());
+
+    let mut cursor = list.cursor_find_mut(|x| *x > 5).unwrap();
+    assert_eq!(cursor.current_mut(), (&mut 8, 2));
+    *cursor.current_mut().0 = 0;
+    assert_eq!(cursor.remove(), Ok(0));
+    assert_eq!(list, [5, 3, 3, 1]);
+    let mut cursor = list.cursor_find_mut(|x| *x == 1).unwrap();
+    cursor.insert(2);
+    assert_eq!(list, [5, 3, 3, 1, 2]);
+    assert_eq!(list.peek_back(), Some(&2));
+    let mut empty: LinkedList<i32> = LinkedList::new();
+    assert!(empty.cursor_find_mut(|_| true).is_none());
+}
+
+#[test]
+fn test_cursor_replace() {
+    // no Clone needed, the old value is moved out
+    struct Token(u32);
+    let mut list = LinkedList::new();
+    list.push_back(Token(1));
+    list.push_back(Token(2));
+    let mut cursor = list.cursor_back_mut().unwrap();
+    let old = cursor.replace(Token(3));
+    assert_eq!(old.0, 2);
+    cursor.move_next();
+    assert_eq!(cursor.replace(Token(4)).0, 1);
+    assert!(list.iter().map(|t| t.0).eq([4, 3]));
+    assert_eq!(list.len(), 2);
+}
+
+#[test]
+fn test_is_permutation_of() {
+    let list = LinkedList::from(["a", "b", "b", "c"]);
+    let mut shuffled = LinkedList::from(["b", "c", "a", "b"]);
+    assert!(list.is_permutation_of(&shuffled));
+    assert!(list.is_permutation_of_by_eq(&shuffled));
+    shuffled.pop_back();
+    assert!(!list.is_permutation_of(&shuffled));
+    assert!(!list.is_permutation_of_by_eq(&shuffled));
+    shuffled.push_back("c");
+    // same length but different counts
+    assert!(!list.is_permutation_of(&shuffled));
+    assert!(!shuffled.is_permutation_of(&list));
+    assert!(!list.is_permutation_of_by_eq(&shuffled));
+    assert!(!shuffled.is_permutation_of_by_eq(&list));
+    let empty: LinkedList<&str> = LinkedList::new();
+    assert!(empty.is_permutation_of(&LinkedList::new()));
+    assert!(!empty.is_permutation_of(&list));
+}
+
+#[test]
+fn test_find_sublist() {
+    let list = LinkedList::from([1, 2, 1, 2, 1, 3]);
+    assert_eq!(list.find_sublist([1, 2, 1, 3]), Some(2));
+    assert_eq!(list.find_sublist([2, 1].as_slice()), Some(1));
+    let needle = LinkedList::from([1, 3]);
+    assert_eq!(list.find_sublist(&needle), Some(4));
+    assert_eq!(list.find_sublist([3]), Some(5));
+    // the needle may not run past the tail
+    assert_eq!(list.find_sublist([3, 1]), None);
+    assert_eq!(list.find_sublist(Vec::<i32>::new()), Some(0));
+    assert!(list.contains_sublist(&list));
+    assert!(!list.contains_sublist([2, 2]));
+    let empty: LinkedList<i32> = LinkedList::new();
+    assert_eq!(empty.find_sublist([1]), None);
+    assert_eq!(empty.find_sublist(&empty), Some(0));
+}
+
+#[test]
+fn test_replace_sublist() {
+    let mut list = LinkedList::from(["a", "b", "a", "b", "a"]);
+    // occurrences do not overlap
+    assert_eq!(
+        list.replace_sublist(["a", "b", "a"], &LinkedList::from(["x"])),
+        1
+    );
+    assert_eq!(list, ["x", "b", "a"]);
+    // the replacement is not searched again
+    assert_eq!(
+        list.replace_sublist(["x"], &LinkedList::from(["x", "x"])),
+        1
+    );
+    assert_eq!(list, ["x", "x", "b", "a"]);
+    // replacing at the back and removing with an empty replacement
+    assert_eq!(list.replace_sublist(["b", "a"], &LinkedList::new()), 1);
+    assert_eq!(list, ["x", "x"]);
+    assert!(list.iter().rev().eq(["x", "x"].iter()));
+    assert_eq!(list.replace_sublist(["x"], &LinkedList::new()), 2);
+    assert!(list.is_empty());
+    assert_eq!(list.peek_back(), None);
+    assert_eq!(
+        list.replace_sublist(Vec::<&str>::new(), &LinkedList::from(["y"])),
+        0
+    );
+    let mut list = LinkedList::from([1, 2, 3]);
+    assert_eq!(list.replace_sublist([1], &LinkedList::from([7, 8])), 1);
+    assert_eq!(list, [7, 8, 2, 3]);
+    assert!(list.iter().rev().eq([3, 2, 8, 7].iter()));
+}
+
+#[test]
+fn test_rotate_to_front() {
+    let mut list = LinkedList::from([1, 2, 3, 4]);
+    let mut cursor = list.cursor_back_mut().unwrap();
+    cursor.rotate_to_front();
+    assert_eq!(cursor.current_mut(), (&mut 4, 0));
+    assert_eq!(cursor.prev_mut(), (&mut 3, 3));
+    cursor.move_next();
+    cursor.rotate_to_front();
+    assert_eq!(list, [1, 2, 3, 4]);
+    assert!(list.iter().rev().eq([4, 3, 2, 1].iter()));
+    // bring the first match to the front
+    list.cursor_find_mut(|x| *x == 3).unwrap().rotate_to_front();
+    assert_eq!(list, [3, 4, 1, 2]);
+    assert_eq!(list.peek_back(), Some(&2));
+    let mut single = LinkedList::from([1]);
+    single.cursor_front_mut().unwrap().rotate_to_front();
+    assert_eq!(single, [1]);
+}
+
+#[test]
+fn test_split_into() {
+    let parts = LinkedList::from_iter(0..10).split_into(4);
+    let sizes = parts.iter().map(|p| p.len()).collect::<Vec<_>>();
+    assert_eq!(sizes, [3, 3, 2, 2]);
+    assert_eq!(parts[1], [3, 4, 5]);
+    assert!(parts[3].iter().rev().eq([9, 8].iter()));
+    assert_eq!(
+        parts.into_iter().collect::<LinkedList<_>>().flatten(),
+        LinkedList::from_iter(0..10)
+    );
+    let parts = LinkedList::from([1, 2]).split_into(3);
+    assert_eq!(
+        parts,
+        [
+            LinkedList::from([1]),
+            LinkedList::from([2]),
+            LinkedList::new()
+        ]
+    );
+    assert_eq!(LinkedList::<i32>::new().split_into(2).len(), 2);
+}
+
+#[test]
+fn test_keyed_list() {
+    let mut list = KeyedList::new();
+    for (i, key) in ["a", "b", "c", "d"].into_iter().enumerate() {
+        assert_eq!(list.push_back(key.to_string(), i), None);
+    }
+    assert_eq!(list.len(), 4);
+    assert_eq!(list.get_by_key("c"), Some(&2));
+    *list.get_by_key_mut("c").unwrap() = 20;
+    assert_eq!(list.push_back("a".to_string(), 10), Some(0));
+    assert_eq!(list.remove_by_key("b"), Some(1));
+    assert_eq!(list.remove_by_key("b"), None);
+    assert!(list.move_key_to_front("d"));
+    assert!(list.move_key_to_back("a"));
+    assert!(!list.move_key_to_front("z"));
+    let order = list
+        .iter()
+        .map(|(k, v)| (k.as_str(), *v))
+        .collect::<Vec<_>>();
+    assert_eq!(order, [("d", 3), ("c", 20), ("a", 10)]);
+    // moving the only front or back node keeps the ends consistent
+    assert!(list.move_key_to_front("d"));
+    assert!(list.move_key_to_back("a"));
+    assert_eq!(list.pop_back(), Some(("a".to_string(), 10)));
+    assert_eq!(list.pop_front(), Some(("d".to_string(), 3)));
+    assert!(!list.contains_key("a"));
+    assert_eq!(list.len(), 1);
+    assert_eq!(format!("{:?}", list), r#"{"c": 20}"#);
+    let list = list.into_list();
+    assert!(list.iter().rev().eq([("c".to_string(), 20)].iter()));
+}
+
+#[test]
+fn test_slot_list() {
+    let mut list = SlotList::new();
+    let keys = (0..5).map(|i| list.push_back(i)).collect::<Vec<ListKey>>();
+    assert_eq!(list.len(), 5);
+    assert_eq!(list.remove(keys[2]), Some(2));
+    assert_eq!(list.pop_front(), Some(0));
+    assert_eq!(list.pop_back(), Some(4));
+    assert_eq!(list.len(), 2);
+    // freed slots are reused, stale keys stay dead
+    let new_keys = [list.push_front(10), list.push_back(11), list.push_back(12)];
+    for key in [keys[0], keys[2], keys[4]] {
+        assert_eq!(list.get(key), None);
+        assert_eq!(list.remove(key), None);
+        assert!(!list.contains_key(key));
+        assert!(!new_keys.contains(&key));
+    }
+    *list.get_mut(keys[1]).unwrap() += 100;
+    assert!(list.iter().eq([10, 101, 3, 11, 12].iter()));
+    assert!(list.iter().rev().eq([12, 11, 3, 101, 10].iter()));
+    assert_eq!(list.front_key(), Some(new_keys[0]));
+    assert_eq!(list.back_key(), Some(new_keys[2]));
+    let (key, _) = list.iter_with_keys().nth(2).unwrap();
+    assert_eq!(key, keys[3]);
+    assert_eq!(format!("{:?}", list), "[10, 101, 3, 11, 12]");
+    while list.pop_front().is_some() {}
+    assert!(list.is_empty());
+    assert_eq!(list.front_key(), None);
+}
+
+#[test]
+fn test_middle() {
+    for len in 1..10 {
+        let list = LinkedList::from_iter(0..len);
+        let mid = (len / 2) as usize;
+        assert_eq!(list.middle(), Some((&(len / 2), mid)));
+    }
+    let list = LinkedList::from(["a"]);
+    assert_eq!(list.middle(), Some((&"a", 0)));
+    let empty: LinkedList<i32> = LinkedList::new();
+    assert_eq!(empty.middle(), None);
+}
+
+#[test]
+fn test_is_palindrome() {
+    assert!(LinkedList::from([1]).is_palindrome());
+    assert!(LinkedList::from([1, 1]).is_palindrome());
+    assert!(LinkedList::from([1, 2, 1]).is_palindrome());
+    assert!(LinkedList::from([1, 2, 2, 1]).is_palindrome());
+    assert!(!LinkedList::from([1, 2, 3, 1]).is_palindrome());
+    assert!(!LinkedList::from([1, 2, 1, 2]).is_palindrome());
+    let words = LinkedList::from(["step", "on", "no", "pets"]);
+    assert!(!words.is_palindrome());
+    let letters = words
+        .iter()
+        .flat_map(|w| w.chars())
+        .collect::<LinkedList<char>>();
+    assert!(letters.is_palindrome());
+}
+
+#[test]
+fn test_raw_parts() {
+    let list = LinkedList::from(["a".to_string(), "b".to_string()]);
+    let (head, tail, len) = list.into_raw_parts();
+    assert_eq!(len, 2);
+    assert_ne!(head, tail);
+    // the parts can be stored as plain addresses, e.g. on the other side of an FFI boundary
+    let parts = (head as usize, tail as usize, len);
+    let mut list = unsafe {
+        LinkedList::<String>::from_raw_parts(parts.0 as *mut _, parts.1 as *mut _, parts.2)
+    };
+    list.push_back("c".to_string());
+    assert_eq!(list, ["a", "b", "c"]);
+    assert!(list.iter().rev().eq(["c", "b", "a"].iter()));
+}
+
+#[test]
+fn test_debug_validate() {
+    let mut list: LinkedList<i32> = LinkedList::new();
+    list.debug_validate();
+    list.push_back(1);
+    list.debug_validate();
+    list.extend(2..10);
+    let mut back = list.split_at(4);
+    list.debug_validate();
+    back.debug_validate();
+    list.splice_at(LinkedList::from([20, 21]), 2);
+    list.reverse_range(1..5);
+    list.append(&mut back);
+    list.debug_validate();
+    back.debug_validate();
+    list.remove_at(3).unwrap();
+    list.insert_at(30, 0);
+    list.debug_validate();
+    let mut cursor = list.cursor_front_mut().unwrap();
+    cursor.move_next();
+    let tail = cursor.split();
+    list.debug_validate();
+    tail.debug_validate();
+    while list.pop_back().is_some() {}
+    list.debug_validate();
+}
+
+#[test]
+fn test_clone_iters() {
+    let list = LinkedList::from([1, 2, 3, 4]);
+    let mut iter = list.iter();
+    iter.next();
+    iter.next_back();
+    let copy = iter.clone();
+    assert_eq!(copy.len(), 2);
+    assert!(iter.eq(copy));
+    // every pair of elements, the inner walk starts from a copy of the outer one
+    let mut outer = list.iter();
+    let mut pairs = Vec::new();
+    while let Some(a) = outer.next() {
+        pairs.extend(outer.clone().map(|b| (*a, *b)));
+    }
+    assert_eq!(pairs, [(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)]);
+    assert!(list.iter().cycle().skip(3).take(3).eq([4, 1, 2].iter()));
+
+    let mut into_iter = LinkedList::from([String::from("a"), String::from("b")]).into_iter();
+    let mut copy = into_iter.clone();
+    assert_eq!(into_iter.next_back().as_deref(), Some("b"));
+    assert_eq!(copy.next().as_deref(), Some("a"));
+    assert_eq!(copy.next().as_deref(), Some("b"));
+    assert_eq!(into_iter.len(), 1);
+}
+
+#[test]
+fn test_debug_iters_and_cursors() {
+    // iterators and cursors can be fields of a struct deriving Debug
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Walk<'a> {
+        iter: linked_list::Iter<'a, i32>,
+        cursor: linked_list::Cursor<'a, i32>,
+    }
+    let mut list = LinkedList::from([1, 2, 3, 4]);
+    let mut iter = list.iter();
+    iter.next();
+    let mut cursor = list.cursor_back().unwrap();
+    cursor.move_prev();
+    let walk = Walk { iter, cursor };
+    assert_eq!(
+        format!("{:?}", walk),
+        "Walk { iter: Iter { len: 3 }, cursor: Cursor { index: 2, len: 4 } }"
+    );
+    let mut cursor = list.cursor_front_mut().unwrap();
+    // the cursor moves onto the inserted element
+    cursor.insert(10);
+    assert_eq!(format!("{:?}", cursor), "CursorMut { index: 1, len: 5 }");
+    let mut iter_mut = list.iter_mut();
+    iter_mut.next_back();
+    assert_eq!(format!("{:?}", iter_mut), "IterMut { len: 4 }");
+    assert_eq!(format!("{:?}", list.into_iter()), "IntoIter { len: 5 }");
+}
+
+#[test]
+fn test_cursor_owned() {
+    // a traversal kept across calls, without borrowing the list
+    struct RoundRobin {
+        cursor: CursorOwned<&'static str>,
+    }
+    impl RoundRobin {
+        fn next_task(&mut self) -> &'static str {
+            let task = *self.cursor.current().0;
+            self.cursor.move_next();
+            task
+        }
+    }
+    let list = LinkedList::from(["a", "b", "c"]);
+    let mut tasks = RoundRobin {
+        cursor: list.into_cursor_front().unwrap(),
+    };
+    let order: Vec<_> = (0..4).map(|_| tasks.next_task()).collect();
+    assert_eq!(order, ["a", "b", "c", "a"]);
+    // the cursor is on "b", the finished task is removed
+    assert_eq!(tasks.cursor.remove(), Ok("b"));
+    assert_eq!(tasks.cursor.current(), (&"c", 1));
+    tasks.cursor.insert("d");
+    assert_eq!(tasks.cursor.list().len(), 3);
+    tasks.cursor.step_by_backward(2);
+    *tasks.cursor.current_mut().0 = "e";
+    let list = tasks.cursor.into_list();
+    assert_eq!(list, ["e", "c", "d"]);
+    assert!(LinkedList::<i32>::new().into_cursor_back().is_none());
+}
+
+#[test]
+fn test_split() {
+    let line = LinkedList::from(*b"ab cd  e ");
+    let words: Vec<Vec<u8>> = line
+        .split(|c| *c == b' ')
+        .map(|word| word.iter().copied().collect())
+        .collect();
+    let expected: Vec<&[u8]> = vec![b"ab", b"cd", b"", b"e", b""];
+    assert_eq!(words, expected);
+    // segments are views into the list, they agree with slice::split
+    let bytes: Vec<u8> = line.iter().copied().collect();
+    assert!(line
+        .split(|c| *c == b' ')
+        .map(|word| word.len())
+        .eq(bytes.split(|c| *c == b' ').map(|word| word.len())));
+    let first = line.split(|c| *c == b'c').next().unwrap();
+    assert_eq!((first.first(), first.last()), (Some(&b'a'), Some(&b' ')));
+    assert_eq!(LinkedList::<u8>::new().split(|_| true).count(), 1);
+    assert_eq!(LinkedList::from([0, 0]).split(|x| *x == 0).count(), 3);
+}
+
+#[test]
+fn test_extend_from_slice() {
+    let mut list = LinkedList::new();
+    list.extend_from_slice(&[]);
+    assert!(list.is_empty());
+    list.extend_from_slice(&[1, 2]);
+    list.extend_from_slice(&[3]);
+    list.push_back(4);
+    assert_eq!(list, [1, 2, 3, 4]);
+    assert!(list.iter().rev().eq([4, 3, 2, 1].iter()));
+    list.debug_validate();
+
+    // a panicking clone leaves the elements cloned so far in the list
+    struct Fragile(bool);
+    impl Clone for Fragile {
+        fn clone(&self) -> Self {
+            assert!(!self.0, "fragile element cloned");
+            Fragile(false)
+        }
+    }
+    let mut list = LinkedList::new();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        list.extend_from_slice(&[Fragile(false), Fragile(true), Fragile(false)])
+    }));
+    assert!(result.is_err());
+    assert_eq!(list.len(), 1);
+    list.debug_validate();
+}
+
+#[test]
+fn test_index_of() {
+    let list = LinkedList::from(["x", "y", "x", "z", "y"]);
+    assert_eq!(list.index_of("x"), Some(0));
+    assert_eq!(list.rindex_of("x"), Some(2));
+    assert_eq!(list.rindex_of("y"), Some(4));
+    assert_eq!(list.rindex_of("z"), Some(3));
+    assert_eq!(list.rindex_of("w"), None);
+    assert_eq!(LinkedList::<i32>::new().rindex_of(&1), None);
+    let list = LinkedList::from([String::from("a")]);
+    assert_eq!(list.rindex_of("a"), Some(0));
+}
+
+#[test]
+fn test_remove_all() {
+    let mut list = LinkedList::from([0, 0, 1, 0, 2, 0]);
+    assert_eq!(list.remove_all(&0), 4);
+    assert_eq!(list, [1, 2]);
+    list.debug_validate();
+    assert_eq!(list.remove_all(&0), 0);
+    let mut list: LinkedList<String> = ["a", "a"].map(String::from).into();
+    assert_eq!(list.remove_all("a"), 2);
+    assert!(list.is_empty());
+    list.debug_validate();
+}
+
+#[test]
+fn test_replace_all() {
+    let mut list = LinkedList::from([1, 0, 1, 1]);
+    assert_eq!(list.replace_all(&1, 5), 3);
+    assert_eq!(list, [5, 0, 5, 5]);
+    assert_eq!(list.replace_all(&1, 5), 0);
+    // replacing a value with itself still counts the matches
+    assert_eq!(list.replace_all(&5, 5), 3);
+    assert_eq!(LinkedList::<i32>::new().replace_all(&1, 2), 0);
+}
+
+#[test]
+fn test_filter_map() {
+    let list = LinkedList::from([String::from("a"), String::new(), String::from("bc")]);
+    let lens = list.filter_map(|s| (!s.is_empty()).then_some(s.len()));
+    assert_eq!(lens, [1, 2]);
+    let none = LinkedList::from([1, 2]).filter_map(|_| None::<u8>);
+    assert!(none.is_empty());
+}
+
+#[test]
+fn test_interleave() {
+    let a = LinkedList::from([1, 3]);
+    let b = LinkedList::from([2, 4, 6, 8]);
+    let merged = a.interleave(b);
+    assert_eq!(merged, [1, 2, 3, 4, 6, 8]);
+    merged.debug_validate();
+    let merged = LinkedList::from([1, 2]).interleave(LinkedList::new());
+    assert_eq!(merged, [1, 2]);
+    let merged = LinkedList::new().interleave(LinkedList::from([1, 2]));
+    assert_eq!(merged, [1, 2]);
+    assert!(merged.iter().rev().eq([2, 1].iter()));
+}
+
+#[test]
+fn test_take_front() {
+    let mut list: LinkedList<i32> = (0..7).collect();
+    assert!(list.take_front(0).is_empty());
+    let batch = list.take_front(3);
+    assert_eq!(batch, [0, 1, 2]);
+    batch.debug_validate();
+    list.debug_validate();
+    assert_eq!(list.take_front(4), [3, 4, 5, 6]);
+    assert!(list.is_empty());
+    assert!(list.take_front(1).is_empty());
+    list.push_back(7);
+    assert_eq!(list.take_front(5), [7]);
+    list.debug_validate();
+}
+
+#[test]
+fn test_take_back() {
+    let mut list: LinkedList<i32> = (0..7).collect();
+    assert!(list.take_back(0).is_empty());
+    let recent = list.take_back(3);
+    assert_eq!(recent, [4, 5, 6]);
+    recent.debug_validate();
+    list.debug_validate();
+    assert_eq!(list.take_back(4), [0, 1, 2, 3]);
+    assert!(list.is_empty());
+    assert!(list.take_back(1).is_empty());
+    list.push_front(7);
+    assert_eq!(list.take_back(5), [7]);
+    list.debug_validate();
+}
+
+#[test]
+fn test_peek_nth() {
+    let mut list: LinkedList<i32> = (0..5).collect();
+    assert_eq!(list.peek_nth(0), list.peek_front());
+    assert_eq!(list.peek_nth_back(0), list.peek_back());
+    assert_eq!(list.peek_nth(3), Some(&3));
+    assert_eq!(list.peek_nth_back(3), Some(&1));
+    assert_eq!(list.peek_nth(5), None);
+    assert_eq!(list.peek_nth_back(usize::MAX), None);
+    *list.peek_nth_mut(4).unwrap() += 10;
+    *list.peek_nth_back_mut(4).unwrap() -= 10;
+    assert_eq!(list, [-10, 1, 2, 3, 14]);
+    assert_eq!(LinkedList::<i32>::new().peek_nth(0), None);
+}
+
+#[test]
+fn test_cursor_try_move() {
+    let mut list = LinkedList::from([1, 2, 3, 4]);
+    // scan backward from the tail and stop at the front
+    let mut cursor = list.cursor_back().unwrap();
+    let mut sum = *cursor.current().0;
+    while cursor.try_move_prev() {
+        sum += cursor.current().0;
+    }
+    assert_eq!(sum, 10);
+    assert_eq!(cursor.current(), (&1, 0));
+    // double every element with a mutable cursor
+    let mut cursor = list.cursor_front_mut().unwrap();
+    loop {
+        *cursor.current_mut().0 *= 2;
+        if !cursor.try_move_next() {
+            break;
+        }
+    }
+    assert_eq!(cursor.current_mut(), (&mut 8, 3));
+    assert!(!cursor.try_move_next());
+    assert_eq!(list, [2, 4, 6, 8]);
+    let single = LinkedList::from([1]);
+    let mut cursor = single.cursor_front().unwrap();
+    assert!(!cursor.try_move_next() && !cursor.try_move_prev());
+}
+
+#[test]
+fn test_cursor_split_at_mut() {
+    let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+    let mut cursor = list.cursor_back_mut().unwrap();
+    cursor.move_prev();
+    let (front, back) = cursor.split_at_mut();
+    assert_eq!((front.len(), back.len()), (3, 2));
+    // swap the halves element by element
+    for (a, b) in front.skip(1).zip(back) {
+        std::mem::swap(a, b);
+    }
+    assert_eq!(list, [1, 4, 5, 2, 3]);
+    let mut cursor = list.cursor_front_mut().unwrap();
+    let (mut front, back) = cursor.split_at_mut();
+    assert!(front.next().is_none());
+    assert_eq!(back.count(), 5);
+}
+
+#[test]
+fn test_multi_cursors() {
+    // edit both ends of a list at once: drop the first two and last two elements
+    // and put markers in their place
+    let mut list: LinkedList<i32> = (0..8).collect();
+    let mut cursors = list.cursors();
+    let front = cursors.add_front().unwrap();
+    let back = cursors.add_back().unwrap();
+    let watcher = cursors.duplicate(back).unwrap();
+    cursors.move_prev(back);
+    assert_eq!(cursors.remove(front), Some(0));
+    assert_eq!(cursors.remove(front), Some(1));
+    assert_eq!(cursors.remove(back), Some(6));
+    // back moved on to the tail node, which the watcher is still on
+    assert_eq!(cursors.current(back), Some(&7));
+    assert_eq!(cursors.remove(watcher), Some(7));
+    assert!(!cursors.is_valid(back) && !cursors.is_valid(watcher));
+    assert!(!cursors.insert_after(back, 100));
+    assert_eq!(cursors.remove(back), None);
+    cursors.insert_before(front, -1);
+    let last = cursors.add_back().unwrap();
+    cursors.insert_after(last, -2);
+    assert_eq!(cursors.current(front), Some(&2));
+    list.debug_validate();
+    assert_eq!(list, [-1, 2, 3, 4, 5, -2]);
+
+    let mut empty = LinkedList::<i32>::new();
+    let mut cursors = empty.cursors();
+    assert!(cursors.add_front().is_none() && cursors.add_back().is_none());
+}
+
+#[test]
+fn test_diff() {
+    use linked_list::Edit::{Delete, Insert, Keep};
+    let old: LinkedList<char> = "ABCABBA".chars().collect();
+    let new: LinkedList<char> = "CBABAC".chars().collect();
+    let script = old.diff(&new);
+    // the longest common subsequence has 4 elements
+    assert_eq!(script.iter().filter(|edit| **edit == Keep).count(), 4);
+    assert_eq!(script.iter().filter(|edit| **edit == Delete).count(), 3);
+    // replaying the script by hand gives the new list
+    let mut old_iter = old.iter();
+    let mut replayed = Vec::new();
+    for edit in &script {
+        match edit {
+            Keep => replayed.push(*old_iter.next().unwrap()),
+            Delete => drop(old_iter.next().unwrap()),
+            Insert(c) => replayed.push(*c),
+        }
+    }
+    assert!(old_iter.next().is_none());
+    assert_eq!(new, replayed);
+
+    assert_eq!(old.diff(&old), vec![Keep; 7]);
+    assert_eq!(LinkedList::new().diff(&LinkedList::from([1])), [Insert(1)]);
+    assert_eq!(LinkedList::from([1]).diff(&LinkedList::new()), [Delete]);
+    assert!(LinkedList::<i32>::new().diff(&LinkedList::new()).is_empty());
+}
+
+#[test]
+fn test_apply_patch() {
+    use linked_list::Edit::{Delete, Insert, Keep};
+    let cases = [
+        ("ABCABBA", "CBABAC"),
+        ("", "XYZ"),
+        ("XYZ", ""),
+        ("same", "same"),
+        ("kitten", "sitting"),
+        ("abc", "xyz"),
+    ];
+    for (from, to) in cases {
+        let mut list: LinkedList<char> = from.chars().collect();
+        let target: LinkedList<char> = to.chars().collect();
+        list.apply_patch(list.diff(&target)).unwrap();
+        list.debug_validate();
+        assert_eq!(list, target);
+    }
+
+    // kept nodes stay in place, so references taken before are still good
+    let mut list = LinkedList::from([String::from("a"), String::from("b")]);
+    let kept: *const String = list.peek_back().unwrap();
+    list.apply_patch([
+        Delete,
+        Insert(String::from("x")),
+        Keep,
+        Insert(String::from("y")),
+    ])
+    .unwrap();
+    assert_eq!(list, ["x", "b", "y"].map(String::from));
+    assert!(std::ptr::eq(kept, list.peek_nth(1).unwrap()));
+
+    // a script which does not fit leaves the list alone
+    assert!(list.apply_patch([Keep, Delete]).is_err());
+    assert!(list.apply_patch([Keep, Keep, Keep, Keep]).is_err());
+    assert_eq!(list, ["x", "b", "y"].map(String::from));
+}
+
+#[test]
+fn test_cow_list() {
+    use linked_list::CowList;
+    let mut list: CowList<String> = ["a", "b", "c", "d"].iter().map(|s| s.to_string()).collect();
+    let snapshots: Vec<CowList<String>> = (0..3).map(|_| list.snapshot()).collect();
+    list.get_mut(1).unwrap().push('!');
+    assert_eq!(list.remove_at(2).as_deref(), Ok("c"));
+    list.push_back("e".to_string());
+    list.insert_at("z".to_string(), 0);
+    assert_eq!(list.pop_front().as_deref(), Some("z"));
+    assert!(list.iter().eq(["a", "b!", "d", "e"].iter()));
+    for snapshot in &snapshots {
+        assert!(snapshot.iter().eq(["a", "b", "c", "d"].iter()));
+        assert!(snapshot.ptr_eq(&snapshots[0]));
+    }
+    assert_eq!(list.iter().len(), 4);
+    assert_eq!(format!("{:?}", snapshots[0]), r#"["a", "b", "c", "d"]"#);
+
+    // insert_at and remove_at wrap around like the LinkedList methods
+    let mut list: CowList<u32> = (0..4).collect();
+    let mut expected: LinkedList<u32> = (0..4).collect();
+    for index in [9, 0, 4, 6] {
+        list.insert_at(10 + index as u32, index);
+        expected.insert_at(10 + index as u32, index);
+        assert!(list.iter().eq(expected.iter()));
+    }
+    assert!(list.try_insert_at(0, 9).is_err());
+    for index in [11, 0, 3] {
+        assert_eq!(list.remove_at(index), expected.remove_at(index));
+    }
+    assert!(list.iter().eq(expected.iter()));
+    assert!(CowList::<u32>::new().remove_at(0).is_err());
+
+    // a unique list is changed in place and dropping a long one does not overflow the stack
+    let mut long: CowList<u32> = (0..200_000).collect();
+    let keep = long.snapshot();
+    long.clear();
+    assert_eq!(keep.len(), 200_000);
+    drop(keep);
+    let mut long: CowList<u32> = (0..200_000).collect();
+    *long.get_mut(199_999).unwrap() = 0;
+    assert_eq!(long.get(199_999), Some(&0));
+}
+
+#[test]
+fn test_node_box() {
+    use linked_list::NodeBox;
+    // round robin scheduler moving tasks between queues without reallocating them
+    let mut ready: LinkedList<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+    let mut blocked = LinkedList::new();
+    let addresses: Vec<*const String> = ready.iter().map(|s| s as *const String).collect();
+    while let Some(mut task) = ready.pop_back_node() {
+        task.push('!');
+        blocked.push_front_node(task);
+    }
+    assert!(ready.is_empty());
+    ready.debug_validate();
+    blocked.debug_validate();
+    assert_eq!(blocked, ["a!", "b!", "c!"].map(String::from));
+    assert!(blocked
+        .iter()
+        .zip(&addresses)
+        .all(|(s, &a)| std::ptr::eq(s, a)));
+
+    let node = blocked.pop_front_node().unwrap();
+    assert_eq!(format!("{:?}", node), r#"NodeBox("a!")"#);
+    assert_eq!(node.into_inner(), "a!");
+    blocked.push_back_node(NodeBox::new("d".to_string()));
+    assert_eq!(blocked, ["b!", "c!", "d"].map(String::from));
+    assert!(LinkedList::<u8>::new().pop_front_node().is_none());
+}
+
+#[test]
+fn test_push_with() {
+    let mut list = LinkedList::new();
+    list.push_back_with(|| vec![2; 3]);
+    list.push_front_with(|| vec![1; 2]);
+    list.push_back_with(Vec::new);
+    assert_eq!(list, [vec![1, 1], vec![2, 2, 2], vec![]]);
+    list.debug_validate();
+
+    // a panicking constructor leaves the list untouched
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        list.push_back_with(|| panic!("no value"));
+    }));
+    assert!(result.is_err());
+    assert_eq!(list.len(), 3);
+    list.debug_validate();
+}
+
+#[test]
+fn test_cursor_remove_neighbours() {
+    // merge runs of equal elements into their first element, summing them up
+    let mut list = LinkedList::from([(1, 1), (1, 2), (2, 3), (3, 4), (3, 5), (3, 6)]);
+    let mut cursor = list.cursor_front_mut().unwrap();
+    loop {
+        while cursor.next_mut().1 != 0 && cursor.next_mut().0 .0 == cursor.current_mut().0 .0 {
+            let (_, value) = cursor.remove_next().unwrap();
+            cursor.current_mut().0 .1 += value;
+        }
+        if !cursor.try_move_next() {
+            break;
+        }
+    }
+    assert_eq!(cursor.current_mut(), (&mut (3, 15), 2));
+    assert_eq!(cursor.remove_next(), None);
+    assert_eq!(cursor.remove_prev(), Some((2, 3)));
+    assert_eq!(cursor.current_mut(), (&mut (3, 15), 1));
+    assert_eq!(cursor.remove_prev(), Some((1, 3)));
+    assert_eq!(cursor.remove_prev(), None);
+    assert_eq!(cursor.current_mut(), (&mut (3, 15), 0));
+    list.debug_validate();
+    assert_eq!(list, [(3, 15)]);
+}
+
+#[test]
+fn test_cursor_cut() {
+    // cut and paste a paragraph within a document
+    let mut doc: LinkedList<&str> = ["title", "p1", "p2", "p3", "end"].into_iter().collect();
+    let addresses: Vec<*const &str> = doc.iter().map(|s| s as *const &str).collect();
+    let mut cursor = doc.cursor_front_mut().unwrap();
+    let cut = cursor.cut_until(2);
+    assert_eq!(cut, ["p1"]);
+    assert_eq!(cursor.current_mut(), (&mut "title", 0));
+    cursor.step_by(2);
+    cursor.splice(cut);
+    assert_eq!(doc, ["title", "p2", "p3", "p1", "end"]);
+    // the nodes are the same, only relinked
+    assert!(std::ptr::eq(doc.iter().nth(3).unwrap(), addresses[1]));
+    doc.debug_validate();
+
+    let mut cursor = doc.cursor_back_mut().unwrap();
+    assert!(cursor.cut_next(3).is_empty());
+    assert!(cursor.cut_until(usize::MAX).is_empty());
+    let mut cursor = doc.cursor_front_mut().unwrap();
+    let rest = cursor.cut_until(usize::MAX);
+    assert_eq!(cursor.current_mut(), (&mut "title", 0));
+    assert_eq!(cursor.next_mut(), (&mut "title", 0));
+    rest.debug_validate();
+    assert_eq!(rest.len(), 4);
+    doc.debug_validate();
+    assert_eq!(doc, ["title"]);
+}
+
+#[test]
+fn test_cursor_distance() {
+    use std::cmp::Ordering;
+    let list: LinkedList<u32> = (0..10).collect();
+    let mut start = list.cursor_front().unwrap();
+    let mut end = list.cursor_back().unwrap();
+    start.step_by(2);
+    end.step_by_backward(3);
+    // validate the endpoints before taking the range between them
+    assert_eq!(start.cmp_position(&end), Some(Ordering::Less));
+    assert_eq!(end.cmp_position(&start), Some(Ordering::Greater));
+    let len = start.distance(&end).unwrap();
+    assert_eq!(len, 4);
+    assert!(start.slice_next(len + 1).iter().eq([2, 3, 4, 5, 6].iter()));
+
+    // wrapping moves are reflected as well
+    end.step_by(5);
+    assert_eq!(end.current(), (&1, 1));
+    assert_eq!(start.cmp_position(&end), Some(Ordering::Greater));
+    assert_eq!(end.distance(&start), Some(1));
+
+    let same = list.clone();
+    let other = same.cursor_front().unwrap();
+    assert_eq!(start.cmp_position(&other), None);
+    assert_eq!(other.distance(&start), None);
+}
+
+#[test]
+fn test_cursor_seek_to() {
+    let mut list: LinkedList<usize> = (0..20).collect();
+    let mut cursor = list.cursor_front().unwrap();
+    // every index reached from every position lands on the right node
+    for from in 0..20 {
+        for to in 0..20 {
+            cursor.seek_to(from).unwrap();
+            cursor.seek_to(to).unwrap();
+            assert_eq!(cursor.current(), (&to, to));
+        }
+    }
+    assert!(cursor.seek_to(20).is_err());
+    assert_eq!(cursor.current(), (&19, 19));
+
+    let mut cursor = list.cursor_front_mut().unwrap();
+    for to in [7, 18, 2, 0, 19, 10] {
+        cursor.seek_to(to).unwrap();
+        assert_eq!(*cursor.current_mut().0, to);
+    }
+    cursor.remove().unwrap();
+    cursor.seek_to(18).unwrap();
+    assert_eq!(cursor.current_mut(), (&mut 19, 18));
+    assert!(cursor.seek_to(19).is_err());
+}
+
+#[test]
+fn test_remove_by() {
+    let mut list: LinkedList<u32> = (1..=10).collect();
+    let mut visited = 0;
+    let found = list.remove_by(|&n| {
+        visited += 1;
+        n % 4 == 0
+    });
+    // the predicate is not called past the first match
+    assert_eq!((found, visited), (Some(4), 4));
+    assert_eq!(list.remove_by(|&n| n > 9), Some(10));
+    assert_eq!(list.remove_by(|&n| n < 2), Some(1));
+    assert_eq!(list.remove_by(|&n| n > 100), None);
+    list.debug_validate();
+    assert_eq!(list, [2, 3, 5, 6, 7, 8, 9]);
+    assert_eq!(LinkedList::<u32>::new().remove_by(|_| true), None);
+}
+
+#[test]
+fn test_add() {
+    let evens: LinkedList<u32> = (0..10).filter(|n| n % 2 == 0).collect();
+    let odds: LinkedList<u32> = (0..10).filter(|n| n % 2 == 1).collect();
+    let first_odd: *const u32 = odds.peek_front().unwrap();
+    let mut all = evens + odds;
+    // the nodes are moved, not copied
+    assert!(std::ptr::eq(all.iter().nth(5).unwrap(), first_odd));
+    all += LinkedList::new();
+    all += LinkedList::from([10]);
+    all.debug_validate();
+    assert_eq!(all, [0, 2, 4, 6, 8, 1, 3, 5, 7, 9, 10]);
+    let mut empty = LinkedList::new();
+    empty += LinkedList::from([1]);
+    assert_eq!(LinkedList::new() + empty, [1]);
+}
+
+#[test]
+fn test_sum() {
+    // concatenate the results of processing chunks on several threads
+    let handles: Vec<_> = (0..4u32)
+        .map(|chunk| {
+            std::thread::spawn(move || {
+                (chunk * 5..chunk * 5 + 5)
+                    .map(|n| n * n)
+                    .collect::<LinkedList<u32>>()
+            })
+        })
+        .collect();
+    let squares: LinkedList<u32> = handles.into_iter().map(|h| h.join().unwrap()).sum();
+    squares.debug_validate();
+    assert!(squares.iter().copied().eq((0..20).map(|n| n * n)));
+    let none: LinkedList<u32> = std::iter::empty().sum();
+    assert!(none.is_empty());
+}
+
+#[test]
+fn test_leak() {
+    use std::sync::OnceLock;
+    // registries built once and kept for the rest of the program,
+    // the leaked nodes stay reachable from the statics
+    static NAMES: OnceLock<Vec<&'static String>> = OnceLock::new();
+    static COUNTERS: OnceLock<Vec<&'static mut u32>> = OnceLock::new();
+    let names = NAMES.get_or_init(|| {
+        let names = LinkedList::from([String::from("alpha"), String::from("beta")]);
+        names.leak().map(|name| &*name).collect()
+    });
+    assert_eq!(names, &["alpha", "beta"]);
+
+    let counters = COUNTERS.get_or_init(|| {
+        let mut iter = LinkedList::from([1, 2, 3]).leak();
+        assert_eq!(iter.len(), 3);
+        let last = iter.next_back().unwrap();
+        *last += 10;
+        let mut counters: Vec<&'static mut u32> = iter.collect();
+        counters.push(last);
+        counters
+    });
+    assert!(counters.iter().map(|c| **c).eq([1, 2, 13]));
+    assert_eq!(LinkedList::<u8>::new().leak().next(), None);
+}
+
+#[test]
+fn test_into_vec() {
+    let list: LinkedList<String> = (0..5).map(|n| n.to_string()).collect();
+    let vec = list.clone().into_vec();
+    assert_eq!(vec.capacity(), 5);
+    assert_eq!(vec, ["0", "1", "2", "3", "4"]);
+    let slice = list.into_boxed_slice();
+    assert_eq!(slice.join(","), "0,1,2,3,4");
+    assert!(LinkedList::<u8>::new().into_vec().is_empty());
+    assert!(LinkedList::<u8>::new().into_boxed_slice().is_empty());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_shuffle() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    let mut rng = StdRng::seed_from_u64(42);
+    let sorted = LinkedList::from_iter(0..100);
+    let mut list = sorted.clone();
+    list.shuffle(&mut rng);
+    assert_ne!(list, sorted);
+    assert!(list.is_permutation_of(&sorted));
+    // both directions see the same order
+    let forward = list.iter().copied().collect::<Vec<_>>();
+    let mut backward = list.iter().rev().copied().collect::<Vec<_>>();
+    backward.reverse();
+    assert_eq!(forward, backward);
+    assert_eq!(list.peek_front(), forward.first());
+    assert_eq!(list.peek_back(), forward.last());
+    let mut single = LinkedList::from([1]);
+    single.shuffle(&mut rng);
+    assert_eq!(single, [1]);
+    let mut empty: LinkedList<i32> = LinkedList::new();
+    empty.shuffle(&mut rng);
+    assert!(empty.is_empty());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_choose() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    let mut rng = StdRng::seed_from_u64(1);
+    let list = LinkedList::from_iter(0..4);
+    let mut seen = [0; 4];
+    for _ in 0..400 {
+        seen[*list.choose(&mut rng).unwrap()] += 1;
+    }
+    // every element gets picked
+    assert!(seen.iter().all(|&count| count > 50));
+    let mut list = LinkedList::from([0; 5]);
+    for _ in 0..10 {
+        *list.choose_mut(&mut rng).unwrap() += 1;
+    }
+    assert_eq!(list.iter().sum::<i32>(), 10);
+    let mut empty: LinkedList<i32> = LinkedList::new();
+    assert_eq!(empty.choose(&mut rng), None);
+    assert_eq!(empty.choose_mut(&mut rng), None);
+}
+
+#[cfg(feature = "slab")]
+#[test]
+fn test_slab_list() {
+    let mut list: SlabList<u32> = (0..6).collect();
+    let capacity = list.capacity();
+    assert_eq!(list.remove_at(2), Ok(2));
+    assert_eq!(list.pop_front(), Some(0));
+    assert_eq!(list.pop_back(), Some(5));
+    // freed nodes are reused, the slab does not grow
+    list.push_front(10);
+    list.push_back(20);
+    list.insert_at(30, 2);
+    assert_eq!(list.capacity(), capacity);
+    assert_eq!(list.len(), 6);
+    assert!(list.iter().eq([10, 1, 30, 3, 4, 20].iter()));
+    assert!(list.iter().rev().eq([20, 4, 3, 30, 1, 10].iter()));
+    assert_eq!(list.peek_nth(4), Some(&4));
+    *list.peek_back_mut().unwrap() += 1;
+    assert_eq!(list.clone(), [10, 1, 30, 3, 4, 21].into_iter().collect());
+    assert_eq!(format!("{:?}", list), "[10, 1, 30, 3, 4, 21]");
+    while list.pop_back().is_some() {}
+    assert!(list.is_empty());
+    assert_eq!(list.peek_front(), None);
+    list.push_back(1);
+    assert!(list.iter().eq([1].iter()));
+}
+
+#[cfg(feature = "slab")]
+#[test]
+fn test_slab_list_api() {
+    let mut list: SlabList<u32> = (0..4).collect();
+    let mut linked: LinkedList<u32> = (0..4).collect();
+    // the index wraps around like it does for LinkedList
+    list.insert_at(10, 6);
+    linked.insert_at(10, 6);
+    assert!(list.iter().eq(linked.iter()));
+    assert_eq!(list.remove_at(9), linked.remove_at(9));
+    assert!(list.try_insert_at(20, 6).is_err());
+    assert!(list.contains(&10));
+    list.iter_mut().for_each(|x| *x += 1);
+    assert!(list.iter().eq([1, 2, 11, 3].iter()));
+
+    let mut cursor = list.cursor_front_mut().unwrap();
+    cursor.move_prev();
+    assert_eq!(cursor.current_mut(), (&mut 3, 3));
+    cursor.insert(4);
+    assert_eq!(cursor.next_mut(), (&mut 1, 0));
+    cursor.seek_to(2).unwrap();
+    assert_eq!(cursor.remove(), Ok(11));
+    assert_eq!(cursor.current_mut(), (&mut 3, 2));
+    let mut tail = cursor.split();
+    assert!(tail.iter().eq([4].iter()));
+    assert_eq!(list.cursor_back().unwrap().current(), (&3, 2));
+
+    list.append(&mut tail);
+    assert!(tail.is_empty());
+    let mut rest = list.split_at(1);
+    assert!(list.iter().eq([1, 2].iter()));
+    assert!(rest.iter().eq([3, 4].iter()));
+    rest.append(&mut list);
+    assert!(rest.into_iter().rev().eq([2, 1, 4, 3]));
+
+    let small: SlabList<u32> = (0..2).collect();
+    let large: SlabList<u32> = (1..3).collect();
+    assert!(small < large);
+    let mut set = std::collections::HashSet::new();
+    set.insert(small.clone());
+    assert!(set.contains(&small));
+    assert!(!set.contains(&large));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_iter() {
+    use rayon::prelude::*;
+    let mut list = (0..1000).collect::<LinkedList<u64>>();
+    let expected: u64 = (0..1000).sum();
+    assert_eq!(list.par_iter().sum::<u64>(), expected);
+    list.par_iter_mut().for_each(|n| *n += 1);
+    assert!(list.iter().cloned().eq(1..1001));
+    let indexed = list.par_iter().enumerate().map(|(i, n)| *n - i as u64);
+    assert!(indexed.all(|n| n == 1));
+    let collected = list.par_iter().rev().cloned().collect::<Vec<_>>();
+    assert!(collected.into_iter().eq((1..1001).rev()));
 }
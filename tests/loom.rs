@@ -0,0 +1,172 @@
+#![cfg(loom)]
+//! Model checks for `ConcurrentLinkedList`.
+//! `RUSTFLAGS="--cfg loom" cargo test --test loom --release`
+
+use linked_list::*;
+use loom::sync::Arc;
+use loom::thread;
+
+#[test]
+fn loom_push_back_pop_front() {
+    loom::model(|| {
+        let list = Arc::new(ConcurrentLinkedList::new());
+        let producer = {
+            let list = Arc::clone(&list);
+            thread::spawn(move || {
+                list.push_back(1);
+                list.push_back(2);
+            })
+        };
+        let first = list.pop_front();
+        producer.join().unwrap();
+        let mut popped = first.into_iter().collect::<Vec<_>>();
+        popped.extend(std::iter::from_fn(|| list.pop_front()));
+        assert_eq!(popped, vec![1, 2]);
+    });
+}
+
+#[test]
+fn loom_push_front_push_back() {
+    loom::model(|| {
+        let list = Arc::new(ConcurrentLinkedList::new());
+        let front = {
+            let list = Arc::clone(&list);
+            thread::spawn(move || list.push_front(1))
+        };
+        list.push_back(2);
+        front.join().unwrap();
+        let elems = list.to_list();
+        assert_eq!(elems.len(), 2);
+        assert!(elems.contains(&1) && elems.contains(&2));
+        // tail must point to the real last node, a push_back after it ends up at the back
+        list.push_back(3);
+        assert_eq!(list.to_list().peek_back(), Some(&3));
+    });
+}
+
+#[test]
+fn loom_concurrent_pops() {
+    loom::model(|| {
+        let list = Arc::new(ConcurrentLinkedList::new());
+        list.push_back(1);
+        list.push_back(2);
+        let consumer = {
+            let list = Arc::clone(&list);
+            thread::spawn(move || list.pop_front())
+        };
+        let a = list.pop_front();
+        let b = consumer.join().unwrap();
+        let mut popped = [a.unwrap(), b.unwrap()];
+        popped.sort();
+        assert_eq!(popped, [1, 2]);
+        assert!(list.is_empty());
+    });
+}
+
+#[test]
+fn loom_for_each_while_popping() {
+    loom::model(|| {
+        let list = Arc::new(ConcurrentLinkedList::new());
+        list.push_back(1);
+        list.push_back(2);
+        let consumer = {
+            let list = Arc::clone(&list);
+            thread::spawn(move || list.pop_front())
+        };
+        let mut seen = Vec::new();
+        list.for_each(|x| seen.push(*x));
+        assert!(seen == [1, 2] || seen == [2]);
+        assert_eq!(consumer.join().unwrap(), Some(1));
+    });
+}
+
+#[test]
+fn loom_approx_len() {
+    loom::model(|| {
+        let list = Arc::new(ConcurrentLinkedList::new());
+        let producer = {
+            let list = Arc::clone(&list);
+            thread::spawn(move || list.push_back(1))
+        };
+        let popped = list.pop_front();
+        // the counter never underflows, even when the pop wins the race
+        assert!(list.approx_len() <= 1);
+        producer.join().unwrap();
+        assert_eq!(list.approx_len(), 1 - popped.iter().count());
+        assert_eq!(list.approx_len(), list.len());
+    });
+}
+
+#[test]
+fn loom_mpsc_queue() {
+    use linked_list::mpsc::Queue;
+    loom::model(|| {
+        let queue = Queue::new();
+        let producers = (0..2)
+            .map(|n| {
+                let producer = queue.producer();
+                thread::spawn(move || producer.push(n))
+            })
+            .collect::<Vec<_>>();
+        let mut popped = Vec::new();
+        while popped.len() < 2 {
+            match queue.pop() {
+                Some(n) => popped.push(n),
+                None => thread::yield_now(),
+            }
+        }
+        producers.into_iter().for_each(|h| h.join().unwrap());
+        popped.sort();
+        assert_eq!(popped, vec![0, 1]);
+        assert_eq!(queue.pop(), None);
+    });
+}
+
+#[test]
+fn loom_mpsc_queue_drop_with_pending() {
+    use linked_list::mpsc::Queue;
+    loom::model(|| {
+        let queue = Queue::new();
+        let producer = queue.producer();
+        let handle = thread::spawn(move || {
+            producer.push(1);
+            producer.push(2);
+        });
+        let first = queue.pop();
+        assert!(first.is_none() || first == Some(1));
+        drop(queue);
+        handle.join().unwrap();
+    });
+}
+
+#[test]
+fn loom_mpsc_notify_on_push() {
+    use linked_list::mpsc::Queue;
+    use loom::sync::atomic::{AtomicBool, Ordering};
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Wake};
+
+    struct Flag(AtomicBool);
+    impl Wake for Flag {
+        fn wake(self: std::sync::Arc<Self>) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+    loom::model(|| {
+        let mut queue = Queue::new();
+        let producer = queue.producer();
+        let handle = thread::spawn(move || producer.push(1));
+        let woken = std::sync::Arc::new(Flag(AtomicBool::new(false)));
+        let waker = woken.clone().into();
+        let mut cx = Context::from_waker(&waker);
+        let mut notified = pin!(queue.notify_on_push());
+        let pending = notified.as_mut().poll(&mut cx).is_pending();
+        handle.join().unwrap();
+        // a push racing with the waker being stored must not be missed
+        if pending {
+            assert!(woken.0.load(Ordering::SeqCst));
+        }
+        assert_eq!(notified.as_mut().poll(&mut cx), Poll::Ready(()));
+    });
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "proptest")]
+//! Generates and shrinks lists with the proptest strategies.
+
+use linked_list::strategy::any_linked_list;
+use linked_list::LinkedList;
+use proptest::prelude::*;
+use proptest::test_runner::{Config, TestError, TestRunner};
+
+proptest! {
+    #[test]
+    fn test_any_linked_list(list in any_linked_list(any::<i16>(), 0..20)) {
+        prop_assert!(list.len() < 20);
+        let reversed: LinkedList<i16> = list.iter().rev().copied().collect();
+        prop_assert!(reversed.iter().rev().eq(list.iter()));
+    }
+
+    #[test]
+    fn test_any_arbitrary_list(list in any::<LinkedList<u8>>()) {
+        list.debug_validate();
+    }
+}
+
+#[test]
+fn test_linked_list_shrinking() {
+    let mut runner = TestRunner::new(Config::default());
+    let result = runner.run(&any_linked_list(0..1000u32, 0..50), |list| {
+        prop_assert!(list.iter().all(|&n| n < 500));
+        Ok(())
+    });
+    // every node but one offending element is removed, which is shrunk down to the bound
+    match result {
+        Err(TestError::Fail(_, list)) => assert_eq!(list, [500]),
+        result => panic!("expected a failing list, got {:?}", result),
+    }
+}
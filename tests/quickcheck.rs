@@ -0,0 +1,34 @@
+#![cfg(feature = "quickcheck")]
+//! Generates and shrinks lists with quickcheck.
+
+use linked_list::LinkedList;
+use quickcheck::{Arbitrary, Gen, QuickCheck};
+
+#[test]
+fn test_quickcheck_list() {
+    fn reverse_twice(list: LinkedList<i32>) -> bool {
+        list.debug_validate();
+        let reversed: LinkedList<i32> = list.iter().rev().copied().collect();
+        reversed.iter().rev().eq(list.iter())
+    }
+    QuickCheck::new().quickcheck(reverse_twice as fn(LinkedList<i32>) -> bool);
+}
+
+#[test]
+fn test_quickcheck_shrink() {
+    let list: LinkedList<u32> = LinkedList::arbitrary(&mut Gen::new(20));
+    assert!(list.len() <= 20);
+    let mut shrunk = LinkedList::from([7u32, 0, 600, 3]).shrink();
+    // shrinking starts with the empty list and only removes nodes at first
+    assert_eq!(shrunk.next(), Some(LinkedList::new()));
+    assert!(shrunk.next().unwrap().len() < 4);
+
+    // the minimal failing list of a property keeps one offending element
+    fn minimal(list: LinkedList<u32>) -> LinkedList<u32> {
+        match list.shrink().find(|list| list.iter().any(|&n| n >= 500)) {
+            Some(list) => minimal(list),
+            None => list,
+        }
+    }
+    assert_eq!(minimal(LinkedList::from([7, 0, 600, 3])), [500]);
+}